@@ -0,0 +1,759 @@
+//! Calendar items: appointments and the meeting requests, responses and
+//! cancellations sent about them.
+
+use chrono::{DateTime, Datelike, Duration, NaiveDateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    ical::{self, ContentWriter},
+    items::MessageKind,
+    meeting::Meeting,
+    recipients::{Recipient, RecipientType},
+    recurrence::AppointmentRecurrence,
+    timezone::TimeZoneDefinition,
+    EmailMessage, Lid, Tag,
+};
+
+/// PidLidBusyStatus.
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum BusyStatus {
+    Free,
+    Tentative,
+    Busy,
+    OutOfOffice,
+    WorkingElsewhere,
+    Other(i32),
+}
+
+impl BusyStatus {
+    pub fn from_i32(n: i32) -> Self {
+        match n {
+            0 => Self::Free,
+            1 => Self::Tentative,
+            2 => Self::Busy,
+            3 => Self::OutOfOffice,
+            4 => Self::WorkingElsewhere,
+            n => Self::Other(n),
+        }
+    }
+}
+
+/// How an attendee has responded to a meeting request
+/// (PidTagRecipientTrackStatus).
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum ResponseStatus {
+    None,
+    Organized,
+    Tentative,
+    Accepted,
+    Declined,
+    NotResponded,
+    Other(i32),
+}
+
+impl ResponseStatus {
+    pub fn from_i32(n: i32) -> Self {
+        match n {
+            0 => Self::None,
+            1 => Self::Organized,
+            2 => Self::Tentative,
+            3 => Self::Accepted,
+            4 => Self::Declined,
+            5 => Self::NotResponded,
+            n => Self::Other(n),
+        }
+    }
+
+    /// The iCalendar PARTSTAT for the status.
+    pub(crate) fn partstat(self) -> &'static str {
+        match self {
+            Self::Organized | Self::Accepted => "ACCEPTED",
+            Self::Tentative => "TENTATIVE",
+            Self::Declined => "DECLINED",
+            _ => "NEEDS-ACTION",
+        }
+    }
+}
+
+/// Someone taking part in a meeting.
+#[derive(Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq)]
+pub struct Attendee {
+    pub name: Option<String>,
+    pub address: String,
+    pub status: Option<ResponseStatus>,
+}
+
+impl From<&Recipient> for Attendee {
+    fn from(recipient: &Recipient) -> Self {
+        Self {
+            name: recipient.name.clone(),
+            address: recipient.address.clone(),
+            status: recipient
+                .properties
+                .integer32(Tag::RecipientTrackStatus)
+                .map(ResponseStatus::from_i32),
+        }
+    }
+}
+
+impl Attendee {
+    /// The person the message was sent on behalf of, if it has an address.
+    pub(crate) fn sender(message: &EmailMessage, status: Option<ResponseStatus>) -> Option<Self> {
+        let properties = &message.properties;
+        let address = properties
+            .string(Tag::SentRepresentingSmtpAddress)
+            .or_else(|| properties.string(Tag::SentRepresentingEmailAddress))
+            .unwrap_or(&message.sender);
+        if address.is_empty() {
+            return None;
+        }
+        Some(Self {
+            name: properties
+                .string(Tag::SentRepresentingName)
+                .map(str::to_string),
+            address: address.to_string(),
+            status,
+        })
+    }
+
+    /// The attendee as the value of an ORGANIZER or ATTENDEE line, with
+    /// `params` added to the property name.
+    pub(crate) fn write_ical(&self, writer: &mut ContentWriter, name: &str, params: &str) {
+        let mut name = format!("{name}{params}");
+        if let Some(cn) = &self.name {
+            name.push_str(&format!(";CN={}", ical::param_value(cn)));
+        }
+        writer.line(&name, &format!("mailto:{}", self.address));
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq)]
+pub struct Appointment {
+    /// Whether this is an appointment or a meeting request, response or
+    /// cancellation.
+    pub kind: MessageKind,
+    /// The iCalendar UID of the meeting, from its global object ID.
+    pub uid: Option<String>,
+    pub subject: String,
+    pub description: Option<String>,
+    /// PidLidAppointmentStartWhole.
+    pub start: Option<DateTime<Utc>>,
+    /// PidLidAppointmentEndWhole.
+    pub end: Option<DateTime<Utc>>,
+    pub location: Option<String>,
+    pub busy_status: Option<BusyStatus>,
+    /// Whether the appointment lasts for whole days (PidLidAppointmentSubType).
+    pub all_day: bool,
+    /// The organizer, who for a meeting response is the recipient.
+    pub organizer: Option<Attendee>,
+    /// The attendees; for a meeting response, only the sender, with their
+    /// response as the status.
+    pub required_attendees: Vec<Attendee>,
+    pub optional_attendees: Vec<Attendee>,
+    /// Rooms and equipment booked for the meeting.
+    pub resources: Vec<Attendee>,
+    /// PidLidAppointmentSequence, incremented each time the meeting is
+    /// updated.
+    pub sequence: Option<i32>,
+    pub reminder_set: bool,
+    /// How long before the start the reminder is due (PidLidReminderDelta).
+    pub reminder_minutes_before: Option<i32>,
+    pub meeting_workspace_url: Option<String>,
+    /// PidTagLastModificationTime, or PidTagCreationTime if it has not been
+    /// modified.
+    pub last_modified: Option<DateTime<Utc>>,
+    /// The recurrence of a recurring appointment, if it could be decoded.
+    pub recurrence: Option<AppointmentRecurrence>,
+    /// The time zone the start was given in
+    /// (PidLidAppointmentTimeZoneDefinitionStartDisplay).
+    pub start_time_zone: Option<TimeZoneDefinition>,
+    /// The time zone the end was given in
+    /// (PidLidAppointmentTimeZoneDefinitionEndDisplay).
+    pub end_time_zone: Option<TimeZoneDefinition>,
+    /// The time zone the recurrence is in, from
+    /// PidLidAppointmentTimeZoneDefinitionRecur or else PidLidTimeZoneStruct.
+    pub recurrence_time_zone: Option<TimeZoneDefinition>,
+    pub meeting: Meeting,
+}
+
+impl Appointment {
+    pub fn from_message(message: &EmailMessage) -> Self {
+        let properties = &message.properties;
+        let kind = message.kind();
+        let meeting = Meeting::from_message(message);
+        let attendees = |recipient_type| {
+            message
+                .recipients
+                .iter()
+                .filter(|recipient| {
+                    !recipient.is_organizer() && recipient.recipient_type == Some(recipient_type)
+                })
+                .map(Attendee::from)
+                .collect()
+        };
+        // A response is sent by the attendee who responded to the organizer.
+        let (organizer, required_attendees, optional_attendees, resources) =
+            if kind == MessageKind::MeetingResponse {
+                (
+                    message.recipients.first().map(Attendee::from),
+                    Attendee::sender(message, meeting.response)
+                        .into_iter()
+                        .collect(),
+                    Vec::new(),
+                    Vec::new(),
+                )
+            } else {
+                (
+                    message
+                        .recipients
+                        .iter()
+                        .find(|recipient| recipient.is_organizer())
+                        .map(Attendee::from)
+                        .or_else(|| Attendee::sender(message, Some(ResponseStatus::Organized))),
+                    attendees(RecipientType::To),
+                    attendees(RecipientType::Cc),
+                    attendees(RecipientType::Bcc),
+                )
+            };
+        Self {
+            kind,
+            uid: meeting.uid(),
+            subject: message.subject.clone(),
+            description: message.body.clone(),
+            start: properties.time(Lid::AppointmentStartWhole),
+            end: properties.time(Lid::AppointmentEndWhole),
+            location: properties.string(Lid::Location).map(str::to_string),
+            busy_status: properties
+                .integer32(Lid::BusyStatus)
+                .map(BusyStatus::from_i32),
+            all_day: properties.boolean(Lid::AppointmentSubType).unwrap_or(false),
+            organizer,
+            required_attendees,
+            optional_attendees,
+            resources,
+            sequence: properties.integer32(Lid::AppointmentSequence),
+            reminder_set: properties.boolean(Lid::ReminderSet).unwrap_or(false),
+            reminder_minutes_before: properties.integer32(Lid::ReminderDelta),
+            meeting_workspace_url: properties
+                .string(Lid::MeetingWorkspaceUrl)
+                .map(str::to_string),
+            last_modified: properties
+                .time(Tag::LastModificationTime)
+                .or_else(|| properties.time(Tag::CreationTime)),
+            recurrence: properties
+                .binary(Lid::AppointmentRecur)
+                .and_then(|data| AppointmentRecurrence::parse(data).ok()),
+            start_time_zone: properties
+                .binary(Lid::AppointmentTimeZoneDefinitionStartDisplay)
+                .and_then(|data| TimeZoneDefinition::parse(data).ok()),
+            end_time_zone: properties
+                .binary(Lid::AppointmentTimeZoneDefinitionEndDisplay)
+                .and_then(|data| TimeZoneDefinition::parse(data).ok()),
+            recurrence_time_zone: properties
+                .binary(Lid::AppointmentTimeZoneDefinitionRecur)
+                .and_then(|data| TimeZoneDefinition::parse(data).ok())
+                .or_else(|| {
+                    properties
+                        .binary(Lid::TimeZoneStruct)
+                        .and_then(|data| TimeZoneDefinition::parse_time_zone_struct(data).ok())
+                }),
+            meeting,
+        }
+    }
+
+    /// The time zone of the appointment: that of its recurrence if it
+    /// recurs, otherwise that of its start.
+    pub fn time_zone(&self) -> Option<&TimeZoneDefinition> {
+        if self.recurrence.is_some() {
+            self.recurrence_time_zone
+                .as_ref()
+                .or(self.start_time_zone.as_ref())
+        } else {
+            self.start_time_zone
+                .as_ref()
+                .or(self.recurrence_time_zone.as_ref())
+        }
+    }
+
+    /// Convert a time to the appointment's local time, if its time zone is
+    /// known.
+    pub fn to_local(&self, time: DateTime<Utc>) -> Option<NaiveDateTime> {
+        self.time_zone().map(|zone| zone.to_local(time))
+    }
+
+    /// The start in the appointment's local time.
+    pub fn local_start(&self) -> Option<NaiveDateTime> {
+        let zone = self.start_time_zone.as_ref().or_else(|| self.time_zone())?;
+        Some(zone.to_local(self.start?))
+    }
+
+    /// The end in the appointment's local time.
+    pub fn local_end(&self) -> Option<NaiveDateTime> {
+        let zone = self.end_time_zone.as_ref().or_else(|| self.time_zone())?;
+        Some(zone.to_local(self.end?))
+    }
+
+    /// The appointment as an iCalendar (RFC 5545) object with a single
+    /// VEVENT, plus one for each modified occurrence of a recurring
+    /// appointment.
+    pub fn to_ical(&self) -> String {
+        let mut writer = ContentWriter::new();
+        writer.begin("VCALENDAR");
+        writer.line("VERSION", "2.0");
+        writer.line("PRODID", "-//ms-oxmsg//EN");
+        let method = match self.kind {
+            MessageKind::MeetingRequest => "REQUEST",
+            // A reply needs both the organizer and the attendee replying.
+            MessageKind::MeetingResponse
+                if self.organizer.is_some() && !self.required_attendees.is_empty() =>
+            {
+                "REPLY"
+            }
+            MessageKind::MeetingCancel => "CANCEL",
+            _ => "PUBLISH",
+        };
+        writer.line("METHOD", method);
+        let zone = self.time_zone();
+        if let Some(zone) = zone {
+            let year = self.local_start().map(|start| start.year()).unwrap_or(1601);
+            ical::write_vtimezone(&mut writer, zone, year);
+        }
+        // Writes a time in the appointment's time zone if it has one, and
+        // otherwise as UTC.
+        let write_time = |writer: &mut ContentWriter, name: &str, time: DateTime<Utc>| match zone {
+            Some(zone) if self.all_day => writer.line(
+                &format!("{name};VALUE=DATE"),
+                &ical::format_date(zone.to_local(time).date()),
+            ),
+            Some(zone) => writer.line(
+                &format!("{name};TZID={}", ical::param_value(&ical::tzid(zone))),
+                &ical::format_local(zone.to_local(time)),
+            ),
+            None => writer.line(name, &ical::format_utc(time)),
+        };
+        // Writes a time already in the appointment's local time. Without a
+        // time zone, local times are taken to be UTC, so that they match
+        // DTSTART.
+        let to_utc = |time: NaiveDateTime| match zone {
+            Some(zone) => zone.to_utc(time),
+            None => Utc.from_utc_datetime(&time),
+        };
+        let write_local = |writer: &mut ContentWriter, name: &str, time: NaiveDateTime| {
+            write_time(writer, name, to_utc(time))
+        };
+
+        // Every VEVENT needs a UID, even when the message has no global
+        // object ID, and a DTSTAMP that is the same each time the message is
+        // written out.
+        let uid = self
+            .uid
+            .clone()
+            .unwrap_or_else(|| ical::fallback_uid(&self.subject, self.last_modified));
+        let dtstamp = self.last_modified.or(self.start).unwrap_or_default();
+
+        writer.begin("VEVENT");
+        writer.text("UID", &uid);
+        writer.line("DTSTAMP", &ical::format_utc(dtstamp));
+        if let Some(start) = self.start {
+            write_time(&mut writer, "DTSTART", start);
+        }
+        if let Some(end) = self.end {
+            write_time(&mut writer, "DTEND", end);
+        }
+        writer.text("SUMMARY", &self.subject);
+        if let Some(location) = &self.location {
+            writer.text("LOCATION", location);
+        }
+        if let Some(description) = &self.description {
+            writer.text("DESCRIPTION", description);
+        }
+        if let Some(sequence) = self.sequence {
+            writer.line("SEQUENCE", &sequence.to_string());
+        }
+        if self.kind == MessageKind::MeetingCancel {
+            writer.line("STATUS", "CANCELLED");
+        }
+        let transparency = match self.busy_status {
+            Some(BusyStatus::Free) => "TRANSPARENT",
+            _ => "OPAQUE",
+        };
+        writer.line("TRANSP", transparency);
+        if let Some(organizer) = &self.organizer {
+            organizer.write_ical(&mut writer, "ORGANIZER", "");
+        }
+        for (attendees, params) in [
+            (&self.required_attendees, ";ROLE=REQ-PARTICIPANT"),
+            (&self.optional_attendees, ";ROLE=OPT-PARTICIPANT"),
+            (&self.resources, ";CUTYPE=RESOURCE;ROLE=NON-PARTICIPANT"),
+        ]
+        .iter()
+        {
+            for attendee in attendees.iter() {
+                let status = attendee.status.map(ResponseStatus::partstat);
+                let params = format!("{params};PARTSTAT={}", status.unwrap_or("NEEDS-ACTION"));
+                attendee.write_ical(&mut writer, "ATTENDEE", &params);
+            }
+        }
+        if let Some(recurrence) = &self.recurrence {
+            let pattern = &recurrence.pattern;
+            let at_start = |date: chrono::NaiveDate| {
+                date.and_hms_opt(0, 0, 0).unwrap()
+                    + Duration::minutes(recurrence.start_time_offset as i64)
+            };
+            let until = ical::format_utc(to_utc(at_start(pattern.end_date)));
+            if let Some(rrule) = ical::rrule(pattern, &until) {
+                writer.line("RRULE", &rrule);
+            }
+            // The dates of modified occurrences are also in the deleted
+            // dates, but are replaced by their own VEVENT below rather than
+            // excluded.
+            for date in &pattern.deleted_instance_dates {
+                let modified = recurrence
+                    .exceptions
+                    .iter()
+                    .any(|exception| exception.original_start.date() == *date);
+                if !modified {
+                    write_local(&mut writer, "EXDATE", at_start(*date));
+                }
+            }
+        }
+        if self.reminder_set {
+            write_alarm(&mut writer, self.reminder_minutes_before.unwrap_or(15));
+        }
+        writer.end("VEVENT");
+
+        for exception in self.recurrence.iter().flat_map(|r| &r.exceptions) {
+            writer.begin("VEVENT");
+            writer.text("UID", &uid);
+            writer.line("DTSTAMP", &ical::format_utc(dtstamp));
+            write_local(&mut writer, "RECURRENCE-ID", exception.original_start);
+            write_local(&mut writer, "DTSTART", exception.start);
+            write_local(&mut writer, "DTEND", exception.end);
+            writer.text(
+                "SUMMARY",
+                exception.subject.as_deref().unwrap_or(&self.subject),
+            );
+            if let Some(location) = exception.location.as_ref().or(self.location.as_ref()) {
+                writer.text("LOCATION", location);
+            }
+            if exception.reminder_set.unwrap_or(self.reminder_set) {
+                let minutes = exception
+                    .reminder_minutes_before
+                    .map(|minutes| minutes as i32)
+                    .or(self.reminder_minutes_before);
+                write_alarm(&mut writer, minutes.unwrap_or(15));
+            }
+            writer.end("VEVENT");
+        }
+        writer.end("VCALENDAR");
+        writer.finish()
+    }
+}
+
+fn write_alarm(writer: &mut ContentWriter, minutes_before: i32) {
+    writer.begin("VALARM");
+    writer.line("ACTION", "DISPLAY");
+    writer.text("DESCRIPTION", "Reminder");
+    writer.line("TRIGGER", &format!("-PT{minutes_before}M"));
+    writer.end("VALARM");
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+    use crate::{
+        items::Item,
+        named::PropertyName,
+        recurrence::tests::{at, weekly},
+        test_support::{boolean, filetime, global_object_id, integer32, MsgBuilder},
+        timezone::tests::pacific,
+        Name, PropertySet,
+    };
+
+    #[test]
+    fn meeting_request() {
+        let start = Utc.with_ymd_and_hms(2023, 5, 1, 9, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2023, 5, 1, 10, 30, 0).unwrap();
+        let mut builder = MsgBuilder::new();
+        builder.string("/", 0x001A, "IPM.Schedule.Meeting.Request");
+        builder.named(&[
+            (PropertySet::Appointment, PropertyName::Id(0x820D)),
+            (PropertySet::Appointment, PropertyName::Id(0x820E)),
+            (PropertySet::Appointment, PropertyName::Id(0x8208)),
+            (PropertySet::Appointment, PropertyName::Id(0x8205)),
+            (PropertySet::Common, PropertyName::Id(0x8503)),
+            (PropertySet::Common, PropertyName::Id(0x8501)),
+            // Shares its LID with PidLidPromptSendUpdate.
+            (PropertySet::Address, PropertyName::Id(0x8045)),
+            (
+                PropertySet::PublicStrings,
+                PropertyName::String("AppName".to_string()),
+            ),
+        ]);
+        builder.properties(
+            "/",
+            &[
+                (0x0040, 0x8000, filetime(start)),
+                (0x0040, 0x8001, filetime(end)),
+                (0x0003, 0x8003, integer32(2)),
+                (0x000B, 0x8004, boolean(true)),
+                (0x0003, 0x8005, integer32(15)),
+            ],
+        );
+        builder.string("/", 0x8002, "Room 101");
+        builder.string("/", 0x8006, "1 Main St");
+        builder.string("/", 0x8007, "Outlook");
+        for (i, (name, address, recipient_type, flags)) in [
+            ("Olive Organizer", "olive@example.com", 1, 3),
+            ("Rita Required", "rita@example.com", 1, 1),
+            ("Otto Optional", "otto@example.com", 2, 1),
+            ("Room 101", "room101@example.com", 3, 1),
+        ]
+        .iter()
+        .enumerate()
+        {
+            let recipient = format!("/__recip_version1.0_#{i:08X}/");
+            builder.storage(&recipient);
+            builder.properties(
+                &recipient,
+                &[
+                    (0x0003, 0x0C15, integer32(*recipient_type)),
+                    (0x0003, 0x5FFD, integer32(*flags)),
+                ],
+            );
+            builder.string(&recipient, 0x3001, name);
+            builder.string(&recipient, 0x39FE, address);
+        }
+        let msg = EmailMessage::from_bytes(&builder.build()).unwrap();
+
+        assert_eq!(
+            msg.properties.string(Lid::WorkAddressStreet),
+            Some("1 Main St")
+        );
+        assert!(msg.properties.get(Lid::PromptSendUpdate).is_none());
+        assert_eq!(
+            msg.properties.string(Name::ApplicationName),
+            Some("Outlook")
+        );
+        let appointment = match msg.item() {
+            Item::Appointment(appointment) => appointment,
+            item => panic!("not an appointment: {:?}", item),
+        };
+        assert_eq!(appointment.start, Some(start));
+        assert_eq!(appointment.end, Some(end));
+        assert_eq!(appointment.location.as_deref(), Some("Room 101"));
+        assert_eq!(appointment.busy_status, Some(BusyStatus::Busy));
+        assert!(appointment.reminder_set);
+        assert_eq!(appointment.reminder_minutes_before, Some(15));
+        assert_eq!(appointment.organizer.unwrap().address, "olive@example.com");
+        let addresses = |attendees: &[Attendee]| -> Vec<String> {
+            attendees.iter().map(|a| a.address.clone()).collect()
+        };
+        assert_eq!(
+            addresses(&appointment.required_attendees),
+            ["rita@example.com"]
+        );
+        assert_eq!(
+            addresses(&appointment.optional_attendees),
+            ["otto@example.com"]
+        );
+        assert_eq!(addresses(&appointment.resources), ["room101@example.com"]);
+    }
+
+    #[test]
+    fn meeting_response_reply() {
+        let response = |with_recipient: bool| {
+            let mut builder = MsgBuilder::new();
+            builder.message("/", "Accepted: Review", "bob@example.com", "");
+            builder.string("/", 0x001A, "IPM.Schedule.Meeting.Resp.Pos");
+            builder.string("/", 0x0042, "Bob Attendee");
+            if with_recipient {
+                let recipient = "/__recip_version1.0_#00000000/";
+                builder.storage(recipient);
+                builder.properties(recipient, &[(0x0003, 0x0C15, integer32(1))]);
+                builder.string(recipient, 0x3001, "Olive Organizer");
+                builder.string(recipient, 0x39FE, "olive@example.com");
+            }
+            let msg = EmailMessage::from_bytes(&builder.build()).unwrap();
+            Appointment::from_message(&msg)
+        };
+
+        let appointment = response(true);
+        assert_eq!(appointment.kind, MessageKind::MeetingResponse);
+        assert_eq!(
+            appointment.organizer.as_ref().map(|a| a.address.as_str()),
+            Some("olive@example.com")
+        );
+        assert_eq!(
+            appointment.required_attendees,
+            [Attendee {
+                name: Some("Bob Attendee".to_string()),
+                address: "bob@example.com".to_string(),
+                status: Some(ResponseStatus::Accepted),
+            }]
+        );
+        let ical = appointment.to_ical().replace("\r\n ", "");
+        let lines: Vec<&str> = ical.split("\r\n").collect();
+        assert!(lines.contains(&"METHOD:REPLY"), "{}", ical);
+        assert!(
+            lines.contains(&"ORGANIZER;CN=Olive Organizer:mailto:olive@example.com"),
+            "{}",
+            ical
+        );
+        let attendees: Vec<&str> = lines
+            .iter()
+            .copied()
+            .filter(|line| line.starts_with("ATTENDEE"))
+            .collect();
+        assert_eq!(
+            attendees,
+            [
+                "ATTENDEE;ROLE=REQ-PARTICIPANT;PARTSTAT=ACCEPTED;CN=Bob Attendee:mailto:bob@example.com"
+            ],
+            "{}",
+            ical
+        );
+
+        // Without the organizer it can't be a reply.
+        let ical = response(false).to_ical();
+        assert!(ical.contains("METHOD:PUBLISH\r\n"), "{}", ical);
+    }
+
+    #[test]
+    fn ical_without_global_object_id() {
+        let mut builder = MsgBuilder::new();
+        builder.string("/", 0x001A, "IPM.Appointment");
+        let msg = EmailMessage::from_bytes(&builder.build()).unwrap();
+        let appointment = Appointment::from_message(&msg);
+        assert!(appointment.uid.is_none());
+        assert!(appointment.last_modified.is_none());
+
+        let ical = appointment.to_ical();
+        let lines: Vec<&str> = ical.split("\r\n").collect();
+        let uid = format!("UID:{}", ical::fallback_uid("Subject", None));
+        assert!(lines.contains(&uid.as_str()), "{}", ical);
+        assert!(lines.contains(&"DTSTAMP:19700101T000000Z"), "{}", ical);
+        assert_eq!(ical, appointment.to_ical());
+    }
+
+    #[test]
+    fn recurring_ical() {
+        let start = Utc.with_ymd_and_hms(2023, 5, 1, 16, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2023, 5, 1, 17, 0, 0).unwrap();
+        let mut builder = MsgBuilder::new();
+        builder.string("/", 0x001A, "IPM.Schedule.Meeting.Request");
+        builder.string("/", 0x0037, "Weekly sync");
+        builder.named(&[
+            (PropertySet::Appointment, PropertyName::Id(0x820D)),
+            (PropertySet::Appointment, PropertyName::Id(0x820E)),
+            (PropertySet::Appointment, PropertyName::Id(0x8216)),
+            (PropertySet::Appointment, PropertyName::Id(0x8260)),
+            (PropertySet::Common, PropertyName::Id(0x8503)),
+            (PropertySet::Common, PropertyName::Id(0x8501)),
+            (PropertySet::Meeting, PropertyName::Id(0x0003)),
+        ]);
+        builder.properties(
+            "/",
+            &[
+                (0x0040, 0x8000, filetime(start)),
+                (0x0040, 0x8001, filetime(end)),
+                (0x000B, 0x8004, boolean(true)),
+                (0x0003, 0x8005, integer32(10)),
+            ],
+        );
+        builder.binary("/", 0x8002, &weekly());
+        builder.binary("/", 0x8003, &pacific());
+        builder.binary(
+            "/",
+            0x8006,
+            &global_object_id((0, 0, 0), b"vCal-Uid\x01\x00\x00\x00sync@example.com\0"),
+        );
+        let recipient = "/__recip_version1.0_#00000000/";
+        builder.storage(recipient);
+        builder.properties(
+            recipient,
+            &[
+                (0x0003, 0x0C15, integer32(1)),
+                (0x0003, 0x5FFF, integer32(3)),
+            ],
+        );
+        builder.string(recipient, 0x3001, "Rita, Required");
+        builder.string(recipient, 0x39FE, "rita@example.com");
+        let msg = EmailMessage::from_bytes(&builder.build()).unwrap();
+        let appointment = Appointment::from_message(&msg);
+        assert_eq!(appointment.local_start(), Some(at(1, 9)));
+
+        let ical = appointment.to_ical();
+        let lines: Vec<&str> = ical.split("\r\n").collect();
+        for expected in [
+            "PRODID:-//ms-oxmsg//EN",
+            "METHOD:REQUEST",
+            "TZID:Pacific Standard Time",
+            "RRULE:FREQ=YEARLY;BYMONTH=3;BYDAY=2SU",
+            "UID:sync@example.com",
+            "DTSTART;TZID=Pacific Standard Time:20230501T090000",
+            "DTEND;TZID=Pacific Standard Time:20230501T100000",
+            "SUMMARY:Weekly sync",
+            "ATTENDEE;ROLE=REQ-PARTICIPANT;PARTSTAT=ACCEPTED;CN=\"Rita, Required\":mailto:rita@example.com",
+            "RRULE:FREQ=WEEKLY;BYDAY=MO,WE;WKST=MO;COUNT=6",
+            "EXDATE;TZID=Pacific Standard Time:20230503T090000",
+            "TRIGGER:-PT10M",
+            "RECURRENCE-ID;TZID=Pacific Standard Time:20230508T090000",
+            "DTSTART;TZID=Pacific Standard Time:20230509T100000",
+            "SUMMARY:Moved \u{2192}",
+        ]
+        .iter()
+        {
+            let unfolded = ical.replace("\r\n ", "");
+            assert!(
+                unfolded.split("\r\n").any(|line| line == *expected),
+                "missing {:?} in {}",
+                expected,
+                ical
+            );
+        }
+        // The moved occurrence overrides the original one rather than being
+        // excluded and added back.
+        assert!(!lines.iter().any(|line| line.starts_with("RDATE")));
+        assert!(!lines
+            .iter()
+            .any(|line| line.starts_with("EXDATE") && line.ends_with(":20230508T090000")));
+        assert_eq!(lines.first(), Some(&"BEGIN:VCALENDAR"));
+        assert_eq!(
+            lines.iter().filter(|line| **line == "BEGIN:VEVENT").count(),
+            2
+        );
+    }
+
+    #[test]
+    fn recurring_ical_without_time_zone() {
+        let start = Utc.with_ymd_and_hms(2023, 5, 1, 9, 0, 0).unwrap();
+        let mut builder = MsgBuilder::new();
+        builder.string("/", 0x001A, "IPM.Appointment");
+        builder.named(&[
+            (PropertySet::Appointment, PropertyName::Id(0x820D)),
+            (PropertySet::Appointment, PropertyName::Id(0x8216)),
+        ]);
+        builder.properties("/", &[(0x0040, 0x8000, filetime(start))]);
+        builder.binary("/", 0x8001, &weekly());
+        let msg = EmailMessage::from_bytes(&builder.build()).unwrap();
+
+        // Without a time zone, every time is written in UTC like DTSTART.
+        let ical = Appointment::from_message(&msg).to_ical();
+        let lines: Vec<&str> = ical.split("\r\n").collect();
+        for expected in [
+            "DTSTART:20230501T090000Z",
+            "EXDATE:20230503T090000Z",
+            "RECURRENCE-ID:20230508T090000Z",
+        ] {
+            assert!(
+                lines.contains(&expected),
+                "missing {:?} in {}",
+                expected,
+                ical
+            );
+        }
+    }
+}
@@ -1,116 +1,246 @@
 use std::io::{Read, Seek};
 
-use cfb::Entry;
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    oxprops::property_ids::{tags::Tag, Pid},
-    read, PValue,
+    diagnostics::{Diagnostics, ParseOptions},
+    fingerprint::Fingerprint,
+    named::NamedProperties,
+    oxprops::property_ids::tags::Tag,
+    properties::{read_properties_except, PropertyBag, StorageKind},
+    read_stream, EmailMessage,
 };
 
+/// The stream holding PidTagAttachDataBinary, relative to the attachment.
+pub(crate) const DATA_STREAM: &str = "__substg1.0_37010102";
+/// The storage holding an embedded message, relative to the attachment.
+pub(crate) const EMBEDDED_STORAGE: &str = "__substg1.0_3701000D";
+
 #[derive(Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq)]
 pub struct AttachmentData {
     pub bytes: Vec<u8>,
 }
 
+/// How the attachment data is stored (PidTagAttachMethod).
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum AttachMethod {
+    /// The attachment has just been created.
+    None,
+    /// The data is in PidTagAttachDataBinary.
+    ByValue,
+    /// The data is in a file at PidTagAttachLongPathname.
+    ByReference,
+    /// As `ByReference`, but the file is only accessible to the server.
+    ByReferenceOnly,
+    /// The attachment is an embedded message.
+    EmbeddedMessage,
+    /// The data is an OLE storage in PidTagAttachDataObject.
+    Storage,
+    /// The attachment is a link to a file on a web service.
+    ByWebReference,
+    Other(i32),
+}
+
+impl AttachMethod {
+    pub fn from_i32(n: i32) -> Self {
+        match n {
+            0x0 => Self::None,
+            0x1 => Self::ByValue,
+            0x2 => Self::ByReference,
+            0x4 => Self::ByReferenceOnly,
+            0x5 => Self::EmbeddedMessage,
+            0x6 => Self::Storage,
+            0x7 => Self::ByWebReference,
+            n => Self::Other(n),
+        }
+    }
+}
+
+bitflags::bitflags! {
+    /// PidTagAttachFlags.
+    #[derive(Serialize, Deserialize)]
+    pub struct AttachFlags: u32 {
+        const INVISIBLE_IN_HTML = 0x00000001;
+        const INVISIBLE_IN_RTF = 0x00000002;
+        const RENDERED_IN_BODY = 0x00000004;
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq)]
 pub struct Attachment {
     pub cfb_name: String,
     pub name: String,
     pub data: Option<AttachmentData>,
     pub hidden: bool,
+    pub mime_type: Option<String>,
+    /// The extension of the attachment's file name, including the leading dot.
+    pub extension: Option<String>,
+    /// The message stored in this attachment, if it is an embedded message.
+    pub embedded_message: Option<Box<EmailMessage>>,
+    pub method: AttachMethod,
+    pub content_id: Option<String>,
+    pub flags: AttachFlags,
+    /// The 8.3 file name (PidTagAttachFilename).
+    pub filename: Option<String>,
+    /// The full file name (PidTagAttachLongFilename).
+    pub long_filename: Option<String>,
+    /// The character offset in the body where the attachment is rendered, or
+    /// -1 if it isn't rendered in the body.
+    pub rendering_position: Option<i32>,
+    pub creation_time: Option<DateTime<Utc>>,
+    pub last_modification_time: Option<DateTime<Utc>>,
+    /// The size of the whole attachment object, not just the data.
+    pub size: Option<u32>,
+    /// All properties of the attachment, other than the data.
+    pub properties: PropertyBag,
 }
 
 impl Attachment {
-    /// TODO: switch to stream reading.
+    /// `storage_path` is the path of the storage containing the attachment,
+    /// with a trailing separator.
     pub fn from_cfb<F: Seek + Read>(
         comp: &mut cfb::CompoundFile<F>,
+        storage_path: &str,
         cfb_name: &str,
     ) -> Result<Self, Box<dyn std::error::Error>> {
-        let properties_path = format!("/{cfb_name}\\");
-        let properties = crate::parse_property_stream_other(comp, &properties_path);
-        let mut hidden = false;
-        if let Some(properties) = properties {
-            for property in properties.properties {
-                if property.property_id == Pid::Tag(Tag::AttachmentHidden) {
-                    if let PValue::Boolean(b) = property.value {
-                        hidden = b;
-                    }
-                }
-            }
-        }
-        let name1 = {
-            let name_path = format!("/{cfb_name}\\__substg1.0_3707001F");
-            if let Ok(mut name_stream) = comp.open_stream(&name_path) {
-                let buffer = {
-                    let mut buffer = Vec::new();
-                    name_stream.read_to_end(&mut buffer)?;
-                    buffer
-                };
-                Some(read(&buffer)?)
-            } else {
-                None
-            }
-        };
-        let name = if let Some(name) = name1 {
-            name
-        } else {
-            let name_path = format!("/{cfb_name}\\__substg1.0_3001001F");
-            let mut name_stream = comp.open_stream(&name_path)?;
-            let buffer = {
-                let mut buffer = Vec::new();
-                name_stream.read_to_end(&mut buffer)?;
-                buffer
-            };
-            read(&buffer)?
-        };
-
-        let data = {
-            let name_path = format!("{cfb_name}\\__substg1.0_37010102");
-            if let Ok(mut name_stream) = comp.open_stream(&name_path) {
-                let bytes = {
-                    let mut buffer = Vec::new();
-                    name_stream.read_to_end(&mut buffer)?;
-                    buffer
-                };
-                Some(AttachmentData { bytes })
-            } else {
-                None
-            }
-        };
+        // Without the mapping, named properties are left unresolved, as in
+        // `MessageReader`.
+        let names = NamedProperties::read(comp).unwrap_or_default();
+        Self::from_cfb_with_options(
+            comp,
+            storage_path,
+            cfb_name,
+            &names,
+            &ParseOptions::default(),
+            &mut Diagnostics::default(),
+        )
+    }
 
-        let storage_path = format!("/{cfb_name}");
-        #[allow(clippy::needless_collect)]
-        let streams: Vec<Entry> = comp.read_storage(&storage_path).unwrap().collect();
-        for (i, s) in streams.into_iter().enumerate() {
-            if s.name() == "__properties_version1.0" {
-                // These streams have already been read.
-                // println!("  Stream already parsed");
-            } else if s.is_stream() {
-                // Read in all the data from one of the streams in that compound file.
-                let data = {
-                    let mut stream = if let Ok(s) = comp.open_stream(s.path()) {
-                        s
-                    } else {
-                        continue;
-                    };
-                    let mut buffer = Vec::new();
-                    stream.read_to_end(&mut buffer).unwrap();
-                    buffer
-                };
-                // print!("  Stream[{}]({})[{}]", i, data.len(), s.path().display());
-                // if let Ok(recip0) = read(&data) {
-                //     print!(": {}", recip0);
-                // }
-                // println!();
-            }
+    /// Read the attachment, recording any problems with an embedded message
+    /// in `diagnostics`.
+    pub(crate) fn from_cfb_with_options<F: Seek + Read>(
+        comp: &mut cfb::CompoundFile<F>,
+        storage_path: &str,
+        cfb_name: &str,
+        names: &NamedProperties,
+        options: &ParseOptions,
+        diagnostics: &mut Diagnostics,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        let mut attachment =
+            Self::metadata_from_cfb(comp, storage_path, cfb_name, names, options, diagnostics)?;
+        let attachment_path = format!("{storage_path}{cfb_name}");
+        attachment.data = read_stream(comp, &format!("{attachment_path}/{DATA_STREAM}"))
+            .map(|bytes| AttachmentData { bytes });
+        // OLE attachments use the same storage, so only embedded messages
+        // are read as messages.
+        let embedded_path = format!("{attachment_path}/{EMBEDDED_STORAGE}");
+        if attachment.method == AttachMethod::EmbeddedMessage && comp.is_storage(&embedded_path) {
+            attachment.embedded_message = Some(Box::new(EmailMessage::from_cfb(
+                comp,
+                &format!("{embedded_path}/"),
+                names,
+                options,
+                diagnostics,
+            )?));
         }
+        Ok(attachment)
+    }
+
+    /// Read everything about the attachment except for the data and any
+    /// embedded message.
+    pub(crate) fn metadata_from_cfb<F: Seek + Read>(
+        comp: &mut cfb::CompoundFile<F>,
+        storage_path: &str,
+        cfb_name: &str,
+        names: &NamedProperties,
+        options: &ParseOptions,
+        diagnostics: &mut Diagnostics,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        let attachment_path = format!("{storage_path}{cfb_name}");
+        let properties = read_properties_except(
+            comp,
+            &format!("{attachment_path}/"),
+            StorageKind::Other,
+            names,
+            &[Tag::AttachDataBinary.to_u16()],
+            options,
+            diagnostics,
+        )?;
+        let hidden = properties.boolean(Tag::AttachmentHidden).unwrap_or(false);
+        let filename = properties.string(Tag::AttachFilename).map(str::to_string);
+        let long_filename = properties
+            .string(Tag::AttachLongFilename)
+            .map(str::to_string);
+        let name = long_filename
+            .clone()
+            .or_else(|| properties.string(Tag::DisplayName).map(str::to_string))
+            .or_else(|| filename.clone())
+            .ok_or("attachment has no name")?;
+        let mime_type = properties.string(Tag::AttachMimeTag).map(str::to_string);
+        let extension = properties
+            .string(Tag::AttachExtension)
+            .map(str::to_string)
+            .or_else(|| name.rfind('.').map(|i| name[i..].to_string()));
+        let method = properties
+            .integer32(Tag::AttachMethod)
+            .map(AttachMethod::from_i32)
+            .unwrap_or(AttachMethod::None);
+        let content_id = properties.string(Tag::AttachContentId).map(str::to_string);
+        let flags = properties
+            .integer32(Tag::AttachFlags)
+            .map(|flags| AttachFlags::from_bits_truncate(flags as u32))
+            .unwrap_or_else(AttachFlags::empty);
+        let rendering_position = properties.integer32(Tag::RenderingPosition);
+        let creation_time = properties.time(Tag::CreationTime);
+        let last_modification_time = properties.time(Tag::LastModificationTime);
+        let size = properties
+            .integer32(Tag::AttachSize)
+            .map(|size| size as u32);
 
         Ok(Self {
             cfb_name: cfb_name.to_string(),
             name,
-            data,
+            data: None,
             hidden,
+            mime_type,
+            extension,
+            embedded_message: None,
+            method,
+            content_id,
+            flags,
+            filename,
+            long_filename,
+            rendering_position,
+            creation_time,
+            last_modification_time,
+            size,
+            properties,
         })
     }
+
+    /// Whether the attachment is shown as part of the body, such as an image
+    /// referenced from the HTML, rather than as a separate attachment.
+    pub fn is_inline(&self) -> bool {
+        self.hidden
+            || self.flags.contains(AttachFlags::RENDERED_IN_BODY)
+            || (self.content_id.is_some()
+                && self
+                    .mime_type
+                    .as_deref()
+                    .map(|mime_type| mime_type.starts_with("image/"))
+                    .unwrap_or(false))
+    }
+
+    /// Hashes and type information for the attachment data. Returns `None`
+    /// for attachments without any data, such as embedded messages.
+    pub fn fingerprint(&self) -> Option<Fingerprint> {
+        let data = self.data.as_ref()?;
+        Some(Fingerprint::new(
+            self.name.clone(),
+            &data.bytes,
+            self.extension.clone(),
+            self.mime_type.clone(),
+        ))
+    }
 }
@@ -0,0 +1,135 @@
+//! Reading the little-endian binary structures stored in binary properties.
+
+use std::convert::TryInto;
+
+use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use uuid::Uuid;
+
+pub(crate) struct ByteReader<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl<'a> ByteReader<'a> {
+    pub(crate) fn new(data: &'a [u8]) -> Self {
+        Self { data, position: 0 }
+    }
+
+    pub(crate) fn bytes(&mut self, len: usize) -> Result<&'a [u8], &'static str> {
+        let bytes = self
+            .data
+            .get(self.position..self.position + len)
+            .ok_or("unexpected end of data")?;
+        self.position += len;
+        Ok(bytes)
+    }
+
+    /// Take all of the data that hasn't been read yet.
+    pub(crate) fn rest(&mut self) -> &'a [u8] {
+        let rest = &self.data[self.position.min(self.data.len())..];
+        self.position = self.data.len();
+        rest
+    }
+
+    /// Read a string terminated by a null character, in UTF-16 if `wide`
+    /// and otherwise as Latin-1.
+    pub(crate) fn null_terminated(&mut self, wide: bool) -> Result<String, &'static str> {
+        let rest = &self.data[self.position.min(self.data.len())..];
+        if wide {
+            let units: Vec<u16> = rest
+                .chunks_exact(2)
+                .map(|unit| u16::from_le_bytes([unit[0], unit[1]]))
+                .take_while(|unit| *unit != 0)
+                .collect();
+            self.bytes(units.len() * 2 + 2)?;
+            String::from_utf16(&units).map_err(|_| "invalid UTF-16 string")
+        } else {
+            let length = rest
+                .iter()
+                .position(|byte| *byte == 0)
+                .ok_or("unterminated string")?;
+            Ok(self.bytes(length + 1)?[..length]
+                .iter()
+                .map(|byte| *byte as char)
+                .collect())
+        }
+    }
+
+    pub(crate) fn u8(&mut self) -> Result<u8, &'static str> {
+        Ok(self.bytes(1)?[0])
+    }
+
+    pub(crate) fn u16(&mut self) -> Result<u16, &'static str> {
+        Ok(u16::from_le_bytes(self.bytes(2)?.try_into().unwrap()))
+    }
+
+    pub(crate) fn u32(&mut self) -> Result<u32, &'static str> {
+        Ok(u32::from_le_bytes(self.bytes(4)?.try_into().unwrap()))
+    }
+
+    pub(crate) fn u64(&mut self) -> Result<u64, &'static str> {
+        Ok(u64::from_le_bytes(self.bytes(8)?.try_into().unwrap()))
+    }
+
+    pub(crate) fn i32(&mut self) -> Result<i32, &'static str> {
+        Ok(i32::from_le_bytes(self.bytes(4)?.try_into().unwrap()))
+    }
+
+    pub(crate) fn guid(&mut self) -> Result<Uuid, &'static str> {
+        Ok(crate::parse_guid(self.bytes(16)?))
+    }
+
+    /// Skip a block that starts with its size as a u32.
+    pub(crate) fn skip_sized_block(&mut self) -> Result<(), &'static str> {
+        let len = self.u32()? as usize;
+        self.bytes(len)?;
+        Ok(())
+    }
+}
+
+/// A time stored as a number of minutes since the start of 1601.
+pub(crate) fn minutes_to_datetime(minutes: u32) -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(1601, 1, 1)
+        .unwrap()
+        .and_hms_opt(0, 0, 0)
+        .unwrap()
+        + Duration::minutes(minutes as i64)
+}
+
+/// A FILETIME, counting 100 nanosecond intervals since the start of 1601.
+/// Zero is used for no time.
+pub(crate) fn filetime_to_datetime(filetime: u64) -> Option<DateTime<Utc>> {
+    if filetime == 0 {
+        return None;
+    }
+    let origin = NaiveDate::from_ymd_opt(1601, 1, 1)?.and_hms_opt(0, 0, 0)?;
+    let time = origin + Duration::microseconds((filetime / 10) as i64);
+    Some(Utc.from_utc_datetime(&time))
+}
+
+/// Serialize raw bytes as a hex string, for fields that identifiers are
+/// compared by, so they survive a round trip through formats like JSON.
+pub(crate) mod hex {
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    pub(crate) fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        let hex: String = bytes.iter().map(|byte| format!("{byte:02X}")).collect();
+        serializer.serialize_str(&hex)
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<u8>, D::Error> {
+        let hex = String::deserialize(deserializer)?;
+        hex.as_bytes()
+            .chunks(2)
+            .map(|pair| {
+                std::str::from_utf8(pair)
+                    .ok()
+                    .filter(|pair| pair.len() == 2)
+                    .and_then(|pair| u8::from_str_radix(pair, 16).ok())
+                    .ok_or_else(|| D::Error::custom("invalid hex string"))
+            })
+            .collect()
+    }
+}
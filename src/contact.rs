@@ -0,0 +1,550 @@
+//! Contacts (`IPM.Contact`), from the properties in MS-OXOCNTC.
+
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    fingerprint::FileType,
+    ical::{self, ContentWriter},
+    properties::PropertyBag,
+    EmailMessage, Lid, Tag,
+};
+
+#[derive(Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq)]
+pub struct ContactEmail {
+    /// PidLidEmail1DisplayName and so on.
+    pub display_name: Option<String>,
+    pub address: String,
+    /// The address type, usually `SMTP`, or `EX` for an Exchange address.
+    pub address_type: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq)]
+pub struct PostalAddress {
+    pub street: Option<String>,
+    pub city: Option<String>,
+    pub state: Option<String>,
+    pub postal_code: Option<String>,
+    pub country: Option<String>,
+    pub post_office_box: Option<String>,
+}
+
+impl PostalAddress {
+    fn from_properties(properties: &PropertyBag, pids: [crate::Pid; 6]) -> Option<Self> {
+        let [street, city, state, postal_code, country, post_office_box] =
+            pids.map(|pid| properties.string(pid).map(str::to_string));
+        let address = Self {
+            street,
+            city,
+            state,
+            postal_code,
+            country,
+            post_office_box,
+        };
+        if address == Self::default() {
+            None
+        } else {
+            Some(address)
+        }
+    }
+}
+
+/// Which of a contact's phone numbers a number is.
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum PhoneType {
+    Primary,
+    Business,
+    Business2,
+    BusinessFax,
+    CompanyMain,
+    Assistant,
+    Callback,
+    Home,
+    Home2,
+    HomeFax,
+    Mobile,
+    Car,
+    Pager,
+    Radio,
+    PrimaryFax,
+    Tty,
+    Other,
+}
+
+impl PhoneType {
+    const TAGS: [(Self, Tag); 17] = [
+        (Self::Primary, Tag::PrimaryTelephoneNumber),
+        (Self::Business, Tag::BusinessTelephoneNumber),
+        (Self::Business2, Tag::Business2TelephoneNumber),
+        (Self::BusinessFax, Tag::BusinessFaxNumber),
+        (Self::CompanyMain, Tag::CompanyMainTelephoneNumber),
+        (Self::Assistant, Tag::AssistantTelephoneNumber),
+        (Self::Callback, Tag::CallbackTelephoneNumber),
+        (Self::Home, Tag::HomeTelephoneNumber),
+        (Self::Home2, Tag::Home2TelephoneNumber),
+        (Self::HomeFax, Tag::HomeFaxNumber),
+        (Self::Mobile, Tag::MobileTelephoneNumber),
+        (Self::Car, Tag::CarTelephoneNumber),
+        (Self::Pager, Tag::PagerTelephoneNumber),
+        (Self::Radio, Tag::RadioTelephoneNumber),
+        (Self::PrimaryFax, Tag::PrimaryFaxNumber),
+        (
+            Self::Tty,
+            Tag::TelecommunicationsDeviceForDeafTelephoneNumber,
+        ),
+        (Self::Other, Tag::OtherTelephoneNumber),
+    ];
+
+    /// The vCard TYPE parameter values for the number.
+    fn vcard_types(self) -> &'static str {
+        match self {
+            Self::Primary => "voice,pref",
+            Self::Business | Self::Business2 | Self::CompanyMain => "work,voice",
+            Self::BusinessFax => "work,fax",
+            Self::Home | Self::Home2 => "home,voice",
+            Self::HomeFax => "home,fax",
+            Self::Mobile | Self::Car | Self::Radio => "cell",
+            Self::Pager => "pager",
+            Self::PrimaryFax => "fax",
+            Self::Tty => "textphone",
+            Self::Assistant | Self::Callback | Self::Other => "voice",
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq)]
+pub struct PhoneNumber {
+    pub phone_type: PhoneType,
+    pub number: String,
+}
+
+/// The contact's picture, from the attachment with
+/// PidTagAttachmentContactPhoto set.
+#[derive(Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq)]
+pub struct ContactPhoto {
+    pub mime_type: Option<String>,
+    pub data: Vec<u8>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq)]
+pub struct Contact {
+    pub display_name: Option<String>,
+    pub given_name: Option<String>,
+    pub middle_name: Option<String>,
+    pub surname: Option<String>,
+    /// A title such as "Dr." (PidTagDisplayNamePrefix).
+    pub prefix: Option<String>,
+    /// A suffix such as "Jr." (PidTagGeneration).
+    pub suffix: Option<String>,
+    pub nickname: Option<String>,
+    /// The name the contact is sorted by (PidLidFileUnder).
+    pub file_as: Option<String>,
+    pub email_addresses: Vec<ContactEmail>,
+    pub phone_numbers: Vec<PhoneNumber>,
+    pub home_address: Option<PostalAddress>,
+    pub work_address: Option<PostalAddress>,
+    pub other_address: Option<PostalAddress>,
+    pub company: Option<String>,
+    pub department: Option<String>,
+    pub job_title: Option<String>,
+    pub birthday: Option<NaiveDate>,
+    pub instant_messaging_address: Option<String>,
+    /// PidLidHtml, or else the business or personal home page.
+    pub web_page: Option<String>,
+    pub notes: Option<String>,
+    pub photo: Option<ContactPhoto>,
+}
+
+impl Contact {
+    pub fn from_message(message: &EmailMessage) -> Self {
+        let properties = &message.properties;
+        let string = |pid: crate::Pid| properties.string(pid).map(str::to_string);
+        let email_addresses = [
+            (
+                Lid::Email1DisplayName,
+                Lid::Email1EmailAddress,
+                Lid::Email1AddressType,
+            ),
+            (
+                Lid::Email2DisplayName,
+                Lid::Email2EmailAddress,
+                Lid::Email2AddressType,
+            ),
+            (
+                Lid::Email3DisplayName,
+                Lid::Email3EmailAddress,
+                Lid::Email3AddressType,
+            ),
+        ]
+        .iter()
+        .filter_map(|(display_name, address, address_type)| {
+            Some(ContactEmail {
+                display_name: string((*display_name).into()),
+                address: string((*address).into())?,
+                address_type: string((*address_type).into()),
+            })
+        })
+        .collect();
+        let phone_numbers = PhoneType::TAGS
+            .iter()
+            .filter_map(|(phone_type, tag)| {
+                Some(PhoneNumber {
+                    phone_type: *phone_type,
+                    number: string((*tag).into())?,
+                })
+            })
+            .collect();
+        let photo = message
+            .attachments
+            .iter()
+            .find(|attachment| {
+                attachment
+                    .properties
+                    .boolean(Tag::AttachmentContactPhoto)
+                    .unwrap_or(false)
+            })
+            .and_then(|attachment| {
+                let data = attachment.data.as_ref()?.bytes.clone();
+                let mime_type = attachment.mime_type.clone().or_else(|| {
+                    FileType::sniff(&data).map(|file_type| file_type.mime_type().to_string())
+                });
+                Some(ContactPhoto { mime_type, data })
+            });
+        Self {
+            display_name: string(Tag::DisplayName.into())
+                .or_else(|| Some(message.subject.clone()).filter(|subject| !subject.is_empty())),
+            given_name: string(Tag::GivenName.into()),
+            middle_name: string(Tag::MiddleName.into()),
+            surname: string(Tag::Surname.into()),
+            prefix: string(Tag::DisplayNamePrefix.into()),
+            suffix: string(Tag::Generation.into()),
+            nickname: string(Tag::Nickname.into()),
+            file_as: string(Lid::FileUnder.into()),
+            email_addresses,
+            phone_numbers,
+            home_address: PostalAddress::from_properties(
+                properties,
+                [
+                    Tag::HomeAddressStreet.into(),
+                    Tag::HomeAddressCity.into(),
+                    Tag::HomeAddressStateOrProvince.into(),
+                    Tag::HomeAddressPostalCode.into(),
+                    Tag::HomeAddressCountry.into(),
+                    Tag::HomeAddressPostOfficeBox.into(),
+                ],
+            ),
+            work_address: PostalAddress::from_properties(
+                properties,
+                [
+                    Lid::WorkAddressStreet.into(),
+                    Lid::WorkAddressCity.into(),
+                    Lid::WorkAddressState.into(),
+                    Lid::WorkAddressPostalCode.into(),
+                    Lid::WorkAddressCountry.into(),
+                    Lid::WorkAddressPostOfficeBox.into(),
+                ],
+            ),
+            other_address: PostalAddress::from_properties(
+                properties,
+                [
+                    Tag::OtherAddressStreet.into(),
+                    Tag::OtherAddressCity.into(),
+                    Tag::OtherAddressStateOrProvince.into(),
+                    Tag::OtherAddressPostalCode.into(),
+                    Tag::OtherAddressCountry.into(),
+                    Tag::OtherAddressPostOfficeBox.into(),
+                ],
+            ),
+            company: string(Tag::CompanyName.into()),
+            department: string(Tag::DepartmentName.into()),
+            job_title: string(Tag::Title.into()),
+            birthday: properties
+                .time(Lid::BirthdayLocal)
+                .or_else(|| properties.time(Tag::Birthday))
+                .map(birthday_date),
+            instant_messaging_address: string(Lid::InstantMessagingAddress.into()),
+            web_page: string(Lid::Html.into())
+                .or_else(|| string(Tag::BusinessHomePage.into()))
+                .or_else(|| string(Tag::PersonalHomePage.into())),
+            notes: message.body.clone().filter(|body| !body.is_empty()),
+            photo,
+        }
+    }
+
+    /// The name to show for the contact.
+    pub fn formatted_name(&self) -> String {
+        if let Some(name) = self.display_name.as_ref().or(self.file_as.as_ref()) {
+            return name.clone();
+        }
+        [&self.given_name, &self.middle_name, &self.surname]
+            .iter()
+            .filter_map(|part| part.as_deref())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// The contact as a vCard 4.0 (RFC 6350).
+    pub fn to_vcard(&self) -> String {
+        let mut writer = ContentWriter::new();
+        writer.begin("VCARD");
+        writer.line("VERSION", "4.0");
+        writer.text("FN", &self.formatted_name());
+        writer.line(
+            "N",
+            &structured(&[
+                &self.surname,
+                &self.given_name,
+                &self.middle_name,
+                &self.prefix,
+                &self.suffix,
+            ]),
+        );
+        if let Some(nickname) = &self.nickname {
+            writer.text("NICKNAME", nickname);
+        }
+        if let Some(file_as) = &self.file_as {
+            writer.text("X-OUTLOOK-FILE-AS", file_as);
+        }
+        for (i, email) in self
+            .email_addresses
+            .iter()
+            .filter(|email| email.address_type.as_deref() != Some("EX"))
+            .enumerate()
+        {
+            writer.text(&format!("EMAIL;PREF={}", i + 1), &email.address);
+        }
+        for phone in &self.phone_numbers {
+            writer.text(
+                &format!("TEL;TYPE=\"{}\"", phone.phone_type.vcard_types()),
+                &phone.number,
+            );
+        }
+        for (address, kind) in [
+            (&self.home_address, ";TYPE=home"),
+            (&self.work_address, ";TYPE=work"),
+            (&self.other_address, ""),
+        ]
+        .iter()
+        {
+            if let Some(address) = address {
+                writer.line(
+                    &format!("ADR{kind}"),
+                    &structured(&[
+                        &address.post_office_box,
+                        &None,
+                        &address.street,
+                        &address.city,
+                        &address.state,
+                        &address.postal_code,
+                        &address.country,
+                    ]),
+                );
+            }
+        }
+        if self.company.is_some() || self.department.is_some() {
+            writer.line("ORG", &structured(&[&self.company, &self.department]));
+        }
+        if let Some(job_title) = &self.job_title {
+            writer.text("TITLE", job_title);
+        }
+        if let Some(birthday) = self.birthday {
+            writer.line("BDAY", &ical::format_date(birthday));
+        }
+        if let Some(address) = &self.instant_messaging_address {
+            if address.contains(':') {
+                writer.line("IMPP", address);
+            } else {
+                writer.line("IMPP", &format!("sip:{address}"));
+            }
+        }
+        if let Some(web_page) = &self.web_page {
+            writer.line("URL", web_page);
+        }
+        if let Some(notes) = &self.notes {
+            writer.text("NOTE", notes);
+        }
+        if let Some(photo) = &self.photo {
+            let mime_type = photo.mime_type.as_deref().unwrap_or("image/jpeg");
+            writer.line(
+                "PHOTO",
+                &format!("data:{mime_type};base64,{}", ical::base64(&photo.data)),
+            );
+        }
+        writer.end("VCARD");
+        writer.finish()
+    }
+}
+
+/// Birthdays are stored as midnight in some time zone, converted to UTC, so
+/// round to the nearest day.
+fn birthday_date(time: DateTime<Utc>) -> NaiveDate {
+    (time + Duration::hours(12)).date_naive()
+}
+
+/// A structured vCard value with its components separated by semicolons.
+fn structured(components: &[&Option<String>]) -> String {
+    components
+        .iter()
+        .map(|component| {
+            component
+                .as_deref()
+                .map(ical::escape_text)
+                .unwrap_or_default()
+        })
+        .collect::<Vec<_>>()
+        .join(";")
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+    use crate::{
+        items::Item,
+        named::PropertyName,
+        test_support::{boolean, filetime, integer32, MsgBuilder},
+        PropertySet,
+    };
+
+    #[test]
+    fn contact() {
+        let mut builder = MsgBuilder::new();
+        builder.string("/", 0x001A, "IPM.Contact");
+        builder.named(&[
+            (PropertySet::Address, PropertyName::Id(0x8005)),
+            (PropertySet::Address, PropertyName::Id(0x8080)),
+            (PropertySet::Address, PropertyName::Id(0x8083)),
+            (PropertySet::Address, PropertyName::Id(0x8082)),
+            (PropertySet::Address, PropertyName::Id(0x8045)),
+            (PropertySet::Address, PropertyName::Id(0x8046)),
+            (PropertySet::Address, PropertyName::Id(0x8062)),
+        ]);
+        builder.properties(
+            "/",
+            &[
+                // Midnight in UTC+2.
+                (
+                    0x0040,
+                    0x3A42,
+                    filetime(Utc.with_ymd_and_hms(1990, 4, 30, 22, 0, 0).unwrap()),
+                ),
+            ],
+        );
+        builder.string("/", 0x3001, "Ada Lovelace");
+        builder.string("/", 0x3A06, "Ada");
+        builder.string("/", 0x3A11, "Lovelace");
+        builder.string("/", 0x3A16, "Analytical Engines, Ltd");
+        builder.string("/", 0x3A17, "Programmer");
+        builder.string("/", 0x3A08, "+44 20 7946 0000");
+        builder.string("/", 0x3A1C, "+44 7700 900000");
+        builder.string("/", 0x8000, "Lovelace, Ada");
+        builder.string("/", 0x8001, "Ada (ada@example.com)");
+        builder.string("/", 0x8002, "ada@example.com");
+        builder.string("/", 0x8003, "SMTP");
+        builder.string("/", 0x8004, "12 St James's Square");
+        builder.string("/", 0x8005, "London");
+        builder.string("/", 0x8006, "ada@im.example.com");
+        let attachment = "/__attach_version1.0_#00000000/";
+        builder.storage(attachment);
+        builder.properties(
+            attachment,
+            &[
+                (0x000B, 0x7FFF, boolean(true)),
+                (0x0003, 0x3705, integer32(1)),
+            ],
+        );
+        builder.string(attachment, 0x3707, "ContactPicture.jpg");
+        builder.binary(attachment, 0x3701, &[0xFF, 0xD8, 0xFF, 0xE0]);
+        let msg = EmailMessage::from_bytes(&builder.build()).unwrap();
+
+        let contact = match msg.item() {
+            Item::Contact(contact) => contact,
+            item => panic!("not a contact: {:?}", item),
+        };
+        assert_eq!(contact.file_as.as_deref(), Some("Lovelace, Ada"));
+        assert_eq!(contact.email_addresses[0].address, "ada@example.com");
+        assert_eq!(
+            contact.phone_numbers,
+            [
+                PhoneNumber {
+                    phone_type: PhoneType::Business,
+                    number: "+44 20 7946 0000".to_string()
+                },
+                PhoneNumber {
+                    phone_type: PhoneType::Mobile,
+                    number: "+44 7700 900000".to_string()
+                },
+            ]
+        );
+        assert_eq!(
+            contact.work_address.as_ref().unwrap().city.as_deref(),
+            Some("London")
+        );
+        assert_eq!(contact.home_address, None);
+        assert_eq!(contact.birthday, NaiveDate::from_ymd_opt(1990, 5, 1));
+        let photo = contact.photo.as_ref().unwrap();
+        assert_eq!(photo.mime_type.as_deref(), Some("image/jpeg"));
+
+        let vcard = contact.to_vcard();
+        let lines: Vec<&str> = vcard.split("\r\n").collect();
+        for expected in [
+            "BEGIN:VCARD",
+            "VERSION:4.0",
+            "FN:Ada Lovelace",
+            "N:Lovelace;Ada;;;",
+            "EMAIL;PREF=1:ada@example.com",
+            "TEL;TYPE=\"work,voice\":+44 20 7946 0000",
+            "TEL;TYPE=\"cell\":+44 7700 900000",
+            "ADR;TYPE=work:;;12 St James's Square;London;;;",
+            "ORG:Analytical Engines\\, Ltd;",
+            "TITLE:Programmer",
+            "BDAY:19900501",
+            "IMPP:sip:ada@im.example.com",
+            "PHOTO:data:image/jpeg;base64,/9j/4A==",
+            "END:VCARD",
+        ]
+        .iter()
+        {
+            assert!(
+                lines.contains(expected),
+                "missing {:?} in {}",
+                expected,
+                vcard
+            );
+        }
+    }
+
+    #[test]
+    fn sparse_contact() {
+        let mut builder = MsgBuilder::new();
+        builder.message("/", "Someone", "", "");
+        builder.string("/", 0x001A, "IPM.Contact");
+        builder.named(&[(PropertySet::Address, PropertyName::Id(0x8083))]);
+        builder.properties(
+            "/",
+            &[
+                // An address of the wrong type is ignored.
+                (0x0003, 0x8000, integer32(1)),
+            ],
+        );
+        // A photo attachment without any data.
+        let attachment = "/__attach_version1.0_#00000000/";
+        builder.storage(attachment);
+        builder.properties(attachment, &[(0x000B, 0x7FFF, boolean(true))]);
+        let msg = EmailMessage::from_bytes(&builder.build()).unwrap();
+
+        // Contacts have no delivery or submit time.
+        assert_eq!(msg.delivery_time, None);
+        let contact = Contact::from_message(&msg);
+        assert_eq!(contact.display_name.as_deref(), Some("Someone"));
+        assert!(contact.email_addresses.is_empty());
+        assert_eq!(contact.photo, None);
+        assert_eq!(contact.birthday, None);
+        let vcard = contact.to_vcard();
+        let lines: Vec<&str> = vcard.split("\r\n").collect();
+        assert!(lines.contains(&"FN:Someone"), "{}", vcard);
+        assert!(lines.contains(&"N:;;;;"), "{}", vcard);
+        assert!(!lines.iter().any(|line| line.starts_with("EMAIL")));
+    }
+}
@@ -0,0 +1,413 @@
+//! Grouping messages into conversations, from PidTagConversationIndex
+//! (MS-OXOMSG 2.2.1.3) or the `Message-ID`, `In-Reply-To` and `References`
+//! internet headers.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{
+    binary::{filetime_to_datetime, ByteReader},
+    EmailMessage, Tag,
+};
+
+/// The size of the header block of a conversation index.
+const HEADER_LEN: usize = 22;
+
+/// The size of each child block of a conversation index.
+const CHILD_LEN: usize = 5;
+
+/// PidTagConversationIndex, which identifies a conversation and the
+/// position of a message within it.
+#[derive(Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct ConversationIndex {
+    /// When the first message in the conversation was sent.
+    pub time: Option<DateTime<Utc>>,
+    /// Identifies the conversation.
+    pub guid: Uuid,
+    /// One block for each reply or forward between the first message and
+    /// this one.
+    pub children: Vec<ConversationChild>,
+    /// The whole index, which `is_descendant_of` compares.
+    #[serde(with = "crate::binary::hex")]
+    bytes: Vec<u8>,
+}
+
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct ConversationChild {
+    /// When the reply was sent, from the time of the message before it and
+    /// the time difference in the block, which loses some precision.
+    pub time: Option<DateTime<Utc>>,
+    /// The random number and sequence count, which tell apart replies sent
+    /// at the same time.
+    pub random: u8,
+}
+
+impl ConversationIndex {
+    pub fn parse(data: &[u8]) -> Result<Self, &'static str> {
+        let blocks = data
+            .get(HEADER_LEN..)
+            .ok_or("invalid conversation index length")?;
+        if blocks
+            .chunks(CHILD_LEN)
+            .any(|block| block.len() < CHILD_LEN)
+        {
+            return Err("invalid conversation index length");
+        }
+        let mut reader = ByteReader::new(data);
+        // The header starts with the top six bytes of a FILETIME, big-endian.
+        let mut filetime = reader
+            .bytes(6)?
+            .iter()
+            .fold(0, |time, &byte| time << 8 | byte as u64)
+            << 16;
+        let time = filetime_to_datetime(filetime);
+        let guid = reader.guid()?;
+        let mut children = Vec::new();
+        while let Ok(block) = reader.bytes(CHILD_LEN) {
+            let delta = u32::from_be_bytes([block[0], block[1], block[2], block[3]]);
+            // The top bit says how far the rest of the difference is
+            // shifted, trading precision for range.
+            let difference = if delta & 0x8000_0000 == 0 {
+                (delta as u64) << 18
+            } else {
+                ((delta & 0x7FFF_FFFF) as u64) << 23
+            };
+            filetime = filetime
+                .checked_add(difference)
+                .ok_or("conversation index time out of range")?;
+            children.push(ConversationChild {
+                time: filetime_to_datetime(filetime),
+                random: block[4],
+            });
+        }
+        Ok(Self {
+            time,
+            guid,
+            children,
+            bytes: data.to_vec(),
+        })
+    }
+
+    /// How many replies or forwards came before this message.
+    pub fn depth(&self) -> usize {
+        self.children.len()
+    }
+
+    /// Whether this message is a reply to or forward of `other`, directly
+    /// or through other messages.
+    pub fn is_descendant_of(&self, other: &ConversationIndex) -> bool {
+        self.bytes.len() > other.bytes.len() && self.bytes.starts_with(&other.bytes)
+    }
+
+    /// The index of the message this is a reply to or forward of.
+    fn parent_bytes(&self) -> impl Iterator<Item = &[u8]> {
+        (0..self.children.len())
+            .rev()
+            .filter_map(move |depth| self.bytes.get(..HEADER_LEN + depth * CHILD_LEN))
+    }
+}
+
+impl EmailMessage {
+    pub fn conversation_index(&self) -> Option<ConversationIndex> {
+        ConversationIndex::parse(self.properties.binary(Tag::ConversationIndex)?).ok()
+    }
+
+    /// The `Message-ID` of the message, without the angle brackets.
+    pub fn internet_message_id(&self) -> Option<String> {
+        self.properties
+            .string(Tag::InternetMessageId)
+            .map(str::to_string)
+            .or_else(|| self.header("Message-ID"))
+            .and_then(|id| message_ids(&id).into_iter().next())
+    }
+
+    /// The `Message-ID`s of the messages this replies to, from
+    /// `In-Reply-To` and `References`, closest first.
+    pub fn references(&self) -> Vec<String> {
+        let in_reply_to = self
+            .properties
+            .string(Tag::InReplyToId)
+            .map(str::to_string)
+            .or_else(|| self.header("In-Reply-To"));
+        let references = self
+            .properties
+            .string(Tag::InternetReferences)
+            .map(str::to_string)
+            .or_else(|| self.header("References"));
+        let mut ids: Vec<String> = in_reply_to.as_deref().map(message_ids).unwrap_or_default();
+        for id in references
+            .as_deref()
+            .map(message_ids)
+            .unwrap_or_default()
+            .into_iter()
+            .rev()
+        {
+            if !ids.contains(&id) {
+                ids.push(id);
+            }
+        }
+        ids
+    }
+
+    /// The unfolded value of the first header called `name` in the
+    /// transport headers.
+    fn header(&self, name: &str) -> Option<String> {
+        let headers = self.properties.string(Tag::TransportMessageHeaders)?;
+        let mut lines = headers.lines();
+        let mut value = lines.by_ref().find_map(|line| match line.split_once(':') {
+            Some((header, value)) if header.trim().eq_ignore_ascii_case(name) => {
+                Some(value.trim().to_string())
+            }
+            _ => None,
+        })?;
+        for line in lines {
+            if !(line.starts_with(' ') || line.starts_with('\t')) {
+                break;
+            }
+            value.push(' ');
+            value.push_str(line.trim());
+        }
+        Some(value)
+    }
+}
+
+/// The message IDs in a header value, such as `<a@example.com>
+/// <b@example.com>`.
+fn message_ids(value: &str) -> Vec<String> {
+    let ids: Vec<String> = value
+        .split('<')
+        .skip(1)
+        .filter_map(|id| id.split_once('>'))
+        .map(|(id, _)| id.trim().to_string())
+        .filter(|id| !id.is_empty())
+        .collect();
+    if ids.is_empty() && !value.trim().is_empty() {
+        vec![value.trim().to_string()]
+    } else {
+        ids
+    }
+}
+
+/// A message and the replies to it.
+#[derive(Clone, Debug)]
+pub struct Thread<'a> {
+    pub message: &'a EmailMessage,
+    /// The replies and forwards, oldest first.
+    pub replies: Vec<Thread<'a>>,
+}
+
+impl<'a> Thread<'a> {
+    /// The number of messages in the thread.
+    pub fn message_count(&self) -> usize {
+        1 + self
+            .replies
+            .iter()
+            .map(Thread::message_count)
+            .sum::<usize>()
+    }
+
+    /// All of the messages in the thread, each before its replies.
+    pub fn messages(&self) -> Vec<&'a EmailMessage> {
+        let mut messages = vec![self.message];
+        for reply in &self.replies {
+            messages.extend(reply.messages());
+        }
+        messages
+    }
+}
+
+/// Arrange messages into threads, oldest first. A message's parent is the
+/// closest earlier message in its conversation index, or otherwise the
+/// closest message it refers to by `Message-ID`. Messages without a parent
+/// among `messages` start their own thread.
+pub fn threads(messages: &[EmailMessage]) -> Vec<Thread<'_>> {
+    let indexes: Vec<Option<ConversationIndex>> = messages
+        .iter()
+        .map(EmailMessage::conversation_index)
+        .collect();
+    let mut by_index = HashMap::new();
+    for (i, index) in indexes.iter().enumerate() {
+        if let Some(index) = index {
+            by_index.entry(index.bytes.as_slice()).or_insert(i);
+        }
+    }
+    let mut by_message_id = HashMap::new();
+    for (i, message) in messages.iter().enumerate() {
+        if let Some(id) = message.internet_message_id() {
+            by_message_id.entry(id).or_insert(i);
+        }
+    }
+
+    let mut parents: Vec<Option<usize>> = vec![None; messages.len()];
+    for (i, message) in messages.iter().enumerate() {
+        let from_index = indexes[i].as_ref().and_then(|index| {
+            index
+                .parent_bytes()
+                .find_map(|bytes| by_index.get(bytes).copied())
+        });
+        let parent = from_index.or_else(|| {
+            message
+                .references()
+                .iter()
+                .find_map(|id| by_message_id.get(id).copied())
+        });
+        // Ignore links that would make a loop, such as from duplicate
+        // message IDs.
+        let mut ancestor = parent;
+        while let Some(a) = ancestor {
+            if a == i {
+                break;
+            }
+            ancestor = parents[a];
+        }
+        if ancestor.is_none() {
+            parents[i] = parent.filter(|&p| p != i);
+        }
+    }
+
+    let mut children: Vec<Vec<usize>> = vec![Vec::new(); messages.len()];
+    let mut roots = Vec::new();
+    for (i, parent) in parents.iter().enumerate() {
+        match parent {
+            Some(parent) => children[*parent].push(i),
+            None => roots.push(i),
+        }
+    }
+    let by_time = |ids: &mut Vec<usize>| ids.sort_by_key(|&i| messages[i].delivery_time);
+    for ids in children.iter_mut() {
+        by_time(ids);
+    }
+    by_time(&mut roots);
+    roots
+        .into_iter()
+        .map(|root| build_thread(root, messages, &children))
+        .collect()
+}
+
+fn build_thread<'a>(i: usize, messages: &'a [EmailMessage], children: &[Vec<usize>]) -> Thread<'a> {
+    Thread {
+        message: &messages[i],
+        replies: children[i]
+            .iter()
+            .map(|&child| build_thread(child, messages, children))
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, TimeZone};
+
+    use super::*;
+    use crate::test_support::{filetime, MsgBuilder};
+
+    fn message(subject: &str, minutes: i64, index: Option<&[u8]>, headers: &str) -> EmailMessage {
+        let time = Utc.with_ymd_and_hms(2023, 5, 2, 9, 0, 0).unwrap() + Duration::minutes(minutes);
+        let mut builder = MsgBuilder::new();
+        builder.message("/", subject, "", "");
+        builder.properties("/", &[(0x0040, 0x0E06, filetime(time))]);
+        if let Some(index) = index {
+            builder.binary("/", 0x0071, index);
+        }
+        builder.string("/", 0x007D, headers);
+        EmailMessage::from_bytes(&builder.build()).unwrap()
+    }
+
+    fn index(children: &[u32]) -> Vec<u8> {
+        // 2023-05-02T09:00:00Z, less the bits below the top six bytes.
+        let mut data = vec![0x01, 0xD9, 0x7C, 0xD4, 0x79, 0xA6];
+        data.extend([0xAB; 16]);
+        for (i, delta) in children.iter().enumerate() {
+            data.extend(delta.to_be_bytes());
+            data.push(i as u8);
+        }
+        data
+    }
+
+    #[test]
+    fn conversation_index() {
+        let data = index(&[0x0000_1000, 0x8000_0100]);
+        let index = ConversationIndex::parse(&data).unwrap();
+        assert_eq!(index.depth(), 2);
+        let start = index.time.unwrap();
+        let sent = Utc.with_ymd_and_hms(2023, 5, 2, 9, 0, 0).unwrap();
+        assert_eq!((sent - start).num_milliseconds(), 2);
+        let first = index.children[0].time.unwrap();
+        // 0x1000 << 18 is 107.37 seconds.
+        assert_eq!((first - start).num_milliseconds(), 107_374);
+        let second = index.children[1].time.unwrap();
+        assert_eq!((second - first).num_milliseconds(), 214_748);
+        assert_eq!(index.children[1].random, 1);
+        let parent = ConversationIndex::parse(&data[..27]).unwrap();
+        assert!(index.is_descendant_of(&parent));
+        assert!(!parent.is_descendant_of(&index));
+        assert!(ConversationIndex::parse(&data[..25]).is_err());
+    }
+
+    #[test]
+    fn malformed_conversation_index() {
+        assert!(ConversationIndex::parse(&[]).is_err());
+        assert!(ConversationIndex::parse(&index(&[])[..21]).is_err());
+        // The latest header time, followed by a difference that would take
+        // the time past the end of a FILETIME.
+        let mut data = index(&[0x7FFF_FFFF]);
+        data[..6].fill(0xFF);
+        assert_eq!(
+            ConversationIndex::parse(&data),
+            Err("conversation index time out of range")
+        );
+        let msg = message("Broken", 0, Some(&data), "");
+        assert_eq!(msg.conversation_index(), None);
+    }
+
+    #[test]
+    fn conversation_index_serde() {
+        let data = index(&[0x0000_1000]);
+        let index = ConversationIndex::parse(&data).unwrap();
+        let parent = ConversationIndex::parse(&data[..22]).unwrap();
+        let json = serde_json::to_string(&index).unwrap();
+        let round_trip: ConversationIndex = serde_json::from_str(&json).unwrap();
+        assert_eq!(round_trip, index);
+        assert!(round_trip.is_descendant_of(&parent));
+    }
+
+    #[test]
+    fn threading() {
+        let messages = vec![
+            message("Re: Lunch", 30, Some(&index(&[1])), ""),
+            message("Lunch", 0, Some(&index(&[])), ""),
+            message("Re: Re: Lunch", 60, Some(&index(&[1, 2])), ""),
+            message("Fw: Lunch", 45, Some(&index(&[3])), ""),
+            message("Minutes", 10, None, "Message-ID: <minutes@example.com>\r\n"),
+            message(
+                "Re: Minutes",
+                20,
+                None,
+                "Message-ID: <reply@example.com>\r\nReferences: <other@example.com>\r\n\t<minutes@example.com>\r\n",
+            ),
+        ];
+        let threads = threads(&messages);
+        assert_eq!(threads.len(), 2);
+        assert_eq!(threads[0].message.subject, "Lunch");
+        assert_eq!(threads[0].message_count(), 4);
+        let replies: Vec<&str> = threads[0]
+            .replies
+            .iter()
+            .map(|reply| reply.message.subject.as_str())
+            .collect();
+        assert_eq!(replies, ["Re: Lunch", "Fw: Lunch"]);
+        assert_eq!(
+            threads[0].replies[0].replies[0].message.subject,
+            "Re: Re: Lunch"
+        );
+        assert_eq!(threads[1].message.subject, "Minutes");
+        assert_eq!(threads[1].replies[0].message.subject, "Re: Minutes");
+        assert_eq!(
+            messages[5].references(),
+            ["minutes@example.com", "other@example.com"]
+        );
+    }
+}
@@ -0,0 +1,114 @@
+//! Control over how malformed messages are handled, and the problems found
+//! while reading them.
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::Pid;
+
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum ParseMode {
+    /// Fail on the first problem found.
+    Strict,
+    /// Skip over anything that can't be read, recording a [`Diagnostic`] for
+    /// each problem.
+    Lenient,
+}
+
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct ParseOptions {
+    pub mode: ParseMode,
+}
+
+impl Default for ParseOptions {
+    /// Lenient parsing, which is what [`crate::EmailMessage::from_bytes`]
+    /// and friends use. Earlier versions failed on some problems, such as a
+    /// recipient that couldn't be read or a missing delivery time, that are
+    /// now skipped over.
+    fn default() -> Self {
+        Self {
+            mode: ParseMode::Lenient,
+        }
+    }
+}
+
+/// A problem found while reading a message.
+#[derive(Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq)]
+pub struct Diagnostic {
+    /// The path of the stream or storage the problem was found in.
+    pub path: String,
+    /// The property involved, if the problem is with a single property.
+    pub property: Option<Pid>,
+    pub reason: String,
+}
+
+impl Diagnostic {
+    pub(crate) fn new(
+        path: impl Into<String>,
+        property: Option<Pid>,
+        reason: impl fmt::Display,
+    ) -> Self {
+        Self {
+            path: path.into(),
+            property,
+            reason: reason.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.path)?;
+        if let Some(property) = self.property {
+            write!(f, " ({property:?})")?;
+        }
+        write!(f, ": {}", self.reason)
+    }
+}
+
+impl std::error::Error for Diagnostic {}
+
+/// The problems found while reading a message in lenient mode.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq)]
+pub struct Diagnostics {
+    warnings: Vec<Diagnostic>,
+}
+
+impl Diagnostics {
+    pub fn iter(&self) -> impl Iterator<Item = &Diagnostic> {
+        self.warnings.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.warnings.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.warnings.is_empty()
+    }
+
+    /// Record a problem, or in strict mode, return it as an error.
+    pub(crate) fn warn(
+        &mut self,
+        options: &ParseOptions,
+        diagnostic: Diagnostic,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        match options.mode {
+            ParseMode::Strict => Err(Box::new(diagnostic)),
+            ParseMode::Lenient => {
+                self.warnings.push(diagnostic);
+                Ok(())
+            }
+        }
+    }
+}
+
+impl IntoIterator for Diagnostics {
+    type Item = Diagnostic;
+    type IntoIter = std::vec::IntoIter<Diagnostic>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.warnings.into_iter()
+    }
+}
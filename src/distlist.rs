@@ -0,0 +1,253 @@
+//! Personal distribution lists (`IPM.DistList`).
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    binary::ByteReader,
+    entry_id::{EntryId, OneOffEntryId, WrappedEntryId},
+    EmailMessage, Lid,
+};
+
+#[derive(Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq)]
+pub struct DistListMember {
+    pub name: Option<String>,
+    pub address: Option<String>,
+    /// Usually `SMTP`, or `MAPIPDL` for a nested distribution list.
+    pub address_type: Option<String>,
+    /// The contact or list the member links to, or `None` for a one-off
+    /// address.
+    pub link: Option<WrappedEntryId>,
+}
+
+impl DistListMember {
+    /// `entry_id` is the member itself and `one_off` the one-off copy of
+    /// its address that Outlook keeps alongside it.
+    fn new(entry_id: &[u8], one_off: Option<&[u8]>) -> Self {
+        let (one_off_copy, link) = match EntryId::parse(entry_id) {
+            Ok(EntryId::OneOff(one_off)) => (Some(one_off), None),
+            Ok(EntryId::Wrapped(wrapped)) => (None, Some(wrapped)),
+            _ => (None, None),
+        };
+        let one_off = one_off_copy.or_else(|| match EntryId::parse(one_off?) {
+            Ok(EntryId::OneOff(one_off)) => Some(one_off),
+            _ => None,
+        });
+        let (name, address_type, address) = match one_off {
+            Some(OneOffEntryId {
+                display_name,
+                address_type,
+                address,
+                ..
+            }) => (Some(display_name), Some(address_type), Some(address)),
+            None => (None, None, None),
+        };
+        Self {
+            name,
+            address,
+            address_type,
+            link,
+        }
+    }
+
+    pub fn is_one_off(&self) -> bool {
+        self.link.is_none()
+    }
+
+    /// Whether the member is itself a distribution list.
+    pub fn is_list(&self) -> bool {
+        self.address_type.as_deref() == Some("MAPIPDL")
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq)]
+pub struct DistList {
+    pub name: String,
+    pub members: Vec<DistListMember>,
+}
+
+impl DistList {
+    pub fn from_message(message: &EmailMessage) -> Self {
+        let properties = &message.properties;
+        let name = properties
+            .string(Lid::DistributionListName)
+            .map(str::to_string)
+            .unwrap_or_else(|| message.subject.clone());
+        let members = match properties.binaries(Lid::DistributionListMembers) {
+            Some(members) => {
+                let one_offs = properties
+                    .binaries(Lid::DistributionListOneOffMembers)
+                    .unwrap_or_default();
+                members
+                    .iter()
+                    .enumerate()
+                    .map(|(i, member)| {
+                        DistListMember::new(member, one_offs.get(i).map(Vec::as_slice))
+                    })
+                    .collect()
+            }
+            // Large lists are only stored in the stream.
+            None => properties
+                .binary(Lid::DistributionListStream)
+                .and_then(|stream| read_stream(stream).ok())
+                .unwrap_or_default(),
+        };
+        Self { name, members }
+    }
+
+    /// The addresses of the members. Nested lists and members without an
+    /// internet address can't be expanded from the list alone, so are left
+    /// out.
+    pub fn addresses(&self) -> Vec<String> {
+        self.members
+            .iter()
+            .filter(|member| !member.is_list())
+            .filter(|member| {
+                member
+                    .address_type
+                    .as_deref()
+                    .map(|address_type| address_type.eq_ignore_ascii_case("SMTP"))
+                    .unwrap_or(false)
+            })
+            .filter_map(|member| member.address.clone())
+            .collect()
+    }
+}
+
+/// Read the members from PidLidDistributionListStream, which holds a
+/// version and member count followed by the entry ID, one-off entry ID and
+/// extra information of each member, each preceded by its size.
+fn read_stream(data: &[u8]) -> Result<Vec<DistListMember>, &'static str> {
+    let mut reader = ByteReader::new(data);
+    reader.u32()?;
+    let count = reader.u32()?;
+    let mut members = Vec::new();
+    for _ in 0..count {
+        let size = reader.u32()? as usize;
+        let entry_id = reader.bytes(size)?;
+        let size = reader.u32()? as usize;
+        let one_off = reader.bytes(size)?;
+        reader.skip_sized_block()?;
+        members.push(DistListMember::new(entry_id, Some(one_off)));
+    }
+    Ok(members)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{items::Item, named::PropertyName, test_support::MsgBuilder, PropertySet};
+
+    fn one_off(name: &str, address_type: &str, address: &str) -> Vec<u8> {
+        let mut data = vec![0; 4];
+        data.extend(ONE_OFF_PROVIDER_BYTES);
+        data.extend([0, 0, 0x01, 0x90]);
+        for value in [name, address_type, address].iter() {
+            data.extend(value.encode_utf16().chain([0]).flat_map(u16::to_le_bytes));
+        }
+        data
+    }
+
+    const ONE_OFF_PROVIDER_BYTES: [u8; 16] = [
+        0x81, 0x2B, 0x1F, 0xA4, 0xBE, 0xA3, 0x10, 0x19, 0x9D, 0x6E, 0x00, 0xDD, 0x01, 0x0F, 0x54,
+        0x02,
+    ];
+
+    fn wrapped_contact() -> Vec<u8> {
+        let mut data = vec![0; 4];
+        data.extend([
+            0xC0, 0x91, 0xAD, 0xD3, 0x51, 0x9D, 0xCF, 0x11, 0xA4, 0xA9, 0x00, 0xAA, 0x00, 0x47,
+            0xFA, 0xA4,
+        ]);
+        data.push(0x93);
+        data.extend([0xAA; 8]);
+        data
+    }
+
+    /// Write a multi-valued binary property as its length stream and value
+    /// streams.
+    fn binaries(builder: &mut MsgBuilder, id: u16, values: &[Vec<u8>]) {
+        let mut lengths = Vec::new();
+        for (i, value) in values.iter().enumerate() {
+            lengths.extend((value.len() as u32).to_le_bytes());
+            lengths.extend([0; 4]);
+            builder.stream(&format!("/__substg1.0_{id:04X}1102-{i:08X}"), value);
+        }
+        builder.stream(&format!("/__substg1.0_{id:04X}1102"), &lengths);
+    }
+
+    #[test]
+    fn members() {
+        let mut builder = MsgBuilder::new();
+        builder.string("/", 0x001A, "IPM.DistList");
+        builder.named(&[
+            (PropertySet::Address, PropertyName::Id(0x8053)),
+            (PropertySet::Address, PropertyName::Id(0x8055)),
+            (PropertySet::Address, PropertyName::Id(0x8054)),
+        ]);
+        builder.string("/", 0x8000, "Team");
+        let bob = one_off("Bob", "SMTP", "bob@example.com");
+        let carol = one_off("Carol", "SMTP", "carol@example.com");
+        let nested = one_off("Others", "MAPIPDL", "");
+        binaries(
+            &mut builder,
+            0x8001,
+            &[bob.clone(), wrapped_contact(), nested.clone()],
+        );
+        binaries(&mut builder, 0x8002, &[bob, carol, nested]);
+        let msg = EmailMessage::from_bytes(&builder.build()).unwrap();
+
+        let list = match msg.item() {
+            Item::DistList(list) => list,
+            item => panic!("not a distribution list: {:?}", item),
+        };
+        assert_eq!(list.name, "Team");
+        assert_eq!(list.members.len(), 3);
+        assert!(list.members[0].is_one_off());
+        assert!(!list.members[1].is_one_off());
+        assert_eq!(list.members[1].name.as_deref(), Some("Carol"));
+        assert!(list.members[2].is_list());
+        assert_eq!(list.addresses(), ["bob@example.com", "carol@example.com"]);
+    }
+
+    #[test]
+    fn stream() {
+        let mut data = 3_u32.to_le_bytes().to_vec();
+        data.extend(1_u32.to_le_bytes());
+        let contact = wrapped_contact();
+        data.extend((contact.len() as u32).to_le_bytes());
+        data.extend(&contact);
+        let dave = one_off("Dave", "SMTP", "dave@example.com");
+        data.extend((dave.len() as u32).to_le_bytes());
+        data.extend(&dave);
+        data.extend(0_u32.to_le_bytes());
+        let members = read_stream(&data).unwrap();
+        assert_eq!(members[0].address.as_deref(), Some("dave@example.com"));
+        assert!(members[0].link.is_some());
+    }
+
+    #[test]
+    fn malformed_stream() {
+        let mut data = 3_u32.to_le_bytes().to_vec();
+        data.extend(1_u32.to_le_bytes());
+        data.extend(4_u32.to_le_bytes());
+        data.extend([0xFF; 4]);
+        data.extend(3_u32.to_le_bytes());
+        data.extend([0xFF; 3]);
+        data.extend(0_u32.to_le_bytes());
+        // Entry IDs that can't be decoded leave the member empty.
+        let members = read_stream(&data).unwrap();
+        assert_eq!(members[0].address, None);
+        assert!(members[0].is_one_off());
+
+        for end in [0, 6, 12, data.len() - 1] {
+            assert!(read_stream(&data[..end]).is_err());
+        }
+        // A size past the end of the stream.
+        let mut oversized = data.clone();
+        oversized[8..12].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert!(read_stream(&oversized).is_err());
+        let mut too_many = data;
+        too_many[4..8].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert!(read_stream(&too_many).is_err());
+    }
+}
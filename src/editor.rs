@@ -0,0 +1,308 @@
+//! Changing the properties of an existing message in place, without
+//! rewriting the rest of the file.
+
+use std::{
+    fs::{File, OpenOptions},
+    io::{Read, Seek, Write},
+    path::Path,
+};
+
+use crate::{
+    named::{
+        NamedProperties, NamedProperty, PropertyName, ENTRY_STREAM, GUID_STREAM, NAMEID_STORAGE,
+        STRING_STREAM,
+    },
+    read_stream, EmailMessage, PType, PropertySet,
+};
+
+const PROPERTIES_STREAM: &str = "/__properties_version1.0";
+/// The length of the header of the root property stream.
+const ROOT_HEADER_LEN: usize = 32;
+/// Readable and writable.
+const PROPERTY_FLAGS: u32 = 0x0000_0006;
+
+/// The checksum of string names in the named property hash buckets
+/// (MS-OXMSG 2.2.3.1.2.2), which unlike the usual CRC-32 has no initial or
+/// final inversion.
+const NAME_CRC: crc::Algorithm<u32> = crc::Algorithm {
+    width: 32,
+    poly: 0x04C1_1DB7,
+    init: 0,
+    refin: true,
+    refout: true,
+    xorout: 0,
+    check: 0x2DFD_2D88,
+    residue: 0,
+};
+
+/// Edits the top-level properties of a message. Changes may not reach the
+/// underlying file until [`Self::flush`] or [`Self::into_inner`] is called.
+pub struct MessageEditor<F: Read + Write + Seek> {
+    comp: cfb::CompoundFile<F>,
+    names: NamedProperties,
+}
+
+impl MessageEditor<File> {
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, Box<dyn std::error::Error>> {
+        Self::new(OpenOptions::new().read(true).write(true).open(path)?)
+    }
+}
+
+impl<F: Read + Write + Seek> MessageEditor<F> {
+    pub fn new(inner: F) -> Result<Self, Box<dyn std::error::Error>> {
+        let mut comp = cfb::CompoundFile::open(inner)?;
+        let names = NamedProperties::read(&mut comp)?;
+        Ok(Self { comp, names })
+    }
+
+    /// Read the message as it is now, including any changes.
+    pub fn message(&mut self) -> Result<EmailMessage, Box<dyn std::error::Error>> {
+        let options = Default::default();
+        let mut diagnostics = Default::default();
+        EmailMessage::from_cfb(&mut self.comp, "/", &self.names, &options, &mut diagnostics)
+    }
+
+    /// Replace the categories of the message (PidNameKeywords). An empty
+    /// list removes the property.
+    pub fn set_categories<S: AsRef<str>>(
+        &mut self,
+        categories: &[S],
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let name = PropertyName::String("Keywords".to_string());
+        let id = self.named_property_id(PropertySet::PublicStrings, name)?;
+        self.remove_property(id)?;
+        if categories.is_empty() {
+            return Ok(());
+        }
+        let values: Vec<Vec<u8>> = categories
+            .iter()
+            .map(|category| {
+                category
+                    .as_ref()
+                    .encode_utf16()
+                    .chain(Some(0))
+                    .flat_map(u16::to_le_bytes)
+                    .collect()
+            })
+            .collect();
+        let stream_name = format!(
+            "/__substg1.0_{id:04X}{:04X}",
+            PType::MultipleString.to_bits()
+        );
+        let lengths: Vec<u8> = values
+            .iter()
+            .flat_map(|value| (value.len() as u32).to_le_bytes())
+            .collect();
+        for (i, value) in values.iter().enumerate() {
+            self.write_stream(&format!("{stream_name}-{i:08X}"), value)?;
+        }
+        self.write_stream(&stream_name, &lengths)?;
+        let mut value = [0; 8];
+        value[..4].copy_from_slice(&(lengths.len() as u32).to_le_bytes());
+        self.add_property(id, PType::MultipleString, value)
+    }
+
+    pub fn flush(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        Ok(self.comp.flush()?)
+    }
+
+    /// Write any changes and return the underlying file.
+    pub fn into_inner(mut self) -> Result<F, Box<dyn std::error::Error>> {
+        self.flush()?;
+        Ok(self.comp.into_inner())
+    }
+
+    fn write_stream(&mut self, path: &str, data: &[u8]) -> Result<(), Box<dyn std::error::Error>> {
+        self.comp.create_stream(path)?.write_all(data)?;
+        Ok(())
+    }
+
+    /// Add an entry to the root property stream. Any existing entry for the
+    /// same property ID must have been removed first.
+    fn add_property(
+        &mut self,
+        id: u16,
+        p_type: PType,
+        value: [u8; 8],
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let mut data =
+            read_stream(&mut self.comp, PROPERTIES_STREAM).ok_or("no properties stream")?;
+        data.extend(p_type.to_bits().to_le_bytes());
+        data.extend(id.to_le_bytes());
+        data.extend(PROPERTY_FLAGS.to_le_bytes());
+        data.extend(value);
+        self.write_stream(PROPERTIES_STREAM, &data)
+    }
+
+    /// Remove a root property of any type, along with its value streams.
+    fn remove_property(&mut self, id: u16) -> Result<(), Box<dyn std::error::Error>> {
+        let data = read_stream(&mut self.comp, PROPERTIES_STREAM).ok_or("no properties stream")?;
+        let (header, entries) = data.split_at(ROOT_HEADER_LEN.min(data.len()));
+        let mut kept = header.to_vec();
+        for entry in entries.chunks_exact(16) {
+            if u16::from_le_bytes([entry[2], entry[3]]) != id {
+                kept.extend(entry);
+            }
+        }
+        if kept.len() != data.len() {
+            self.write_stream(PROPERTIES_STREAM, &kept)?;
+        }
+        let prefix = format!("__substg1.0_{id:04X}");
+        let streams: Vec<String> = self
+            .comp
+            .read_storage("/")?
+            .filter(|entry| entry.is_stream() && entry.name().starts_with(&prefix))
+            .map(|entry| entry.path().to_string_lossy().into_owned())
+            .collect();
+        for stream in streams {
+            self.comp.remove_stream(stream)?;
+        }
+        Ok(())
+    }
+
+    /// The property ID of a named property, adding it to the named property
+    /// mapping if the file doesn't use it yet.
+    fn named_property_id(
+        &mut self,
+        property_set: PropertySet,
+        name: PropertyName,
+    ) -> Result<u16, Box<dyn std::error::Error>> {
+        if let Some(id) = self.names.id_of(property_set, &name) {
+            return Ok(id);
+        }
+        let id = match self.names.iter().last() {
+            Some((id, _)) => id.checked_add(1).ok_or("no named property IDs left")?,
+            None => 0x8000,
+        };
+        if !self.comp.is_storage(NAMEID_STORAGE) {
+            self.comp.create_storage(NAMEID_STORAGE)?;
+        }
+        let mut guids = read_stream(&mut self.comp, GUID_STREAM).unwrap_or_default();
+        let guid_index = match property_set {
+            PropertySet::PsMapi => 1,
+            PropertySet::PublicStrings => 2,
+            property_set => {
+                let guid = property_set.to_uuid().to_bytes_le();
+                let index = match guids.chunks_exact(16).position(|other| other == guid) {
+                    Some(index) => index,
+                    None => {
+                        guids.extend(guid);
+                        self.write_stream(GUID_STREAM, &guids)?;
+                        guids.len() / 16 - 1
+                    }
+                };
+                index as u32 + 3
+            }
+        };
+        let (name_or_offset, hash, kind) = match &name {
+            PropertyName::Id(lid) => (*lid, *lid, 0),
+            PropertyName::String(name) => {
+                let mut strings = read_stream(&mut self.comp, STRING_STREAM).unwrap_or_default();
+                strings.resize(strings.len().div_ceil(4) * 4, 0);
+                let offset = strings.len() as u32;
+                let data: Vec<u8> = name.encode_utf16().flat_map(u16::to_le_bytes).collect();
+                strings.extend((data.len() as u32).to_le_bytes());
+                strings.extend(&data);
+                self.write_stream(STRING_STREAM, &strings)?;
+                (offset, crc::Crc::<u32>::new(&NAME_CRC).checksum(&data), 1)
+            }
+        };
+        let index_and_kind = (u32::from(id - 0x8000) << 16) | (guid_index << 1) | kind;
+        let mut entries = read_stream(&mut self.comp, ENTRY_STREAM).unwrap_or_default();
+        entries.extend(name_or_offset.to_le_bytes());
+        entries.extend(index_and_kind.to_le_bytes());
+        self.write_stream(ENTRY_STREAM, &entries)?;
+        // Outlook finds names through the hash buckets rather than the entry
+        // stream.
+        let bucket = 0x1000 + (hash ^ (guid_index << 1 | kind)) % 0x1F;
+        let bucket_stream = format!("{NAMEID_STORAGE}/__substg1.0_{bucket:04X}0102");
+        let mut buckets = read_stream(&mut self.comp, &bucket_stream).unwrap_or_default();
+        buckets.extend(hash.to_le_bytes());
+        buckets.extend(index_and_kind.to_le_bytes());
+        self.write_stream(&bucket_stream, &buckets)?;
+        if guids.is_empty() && !self.comp.is_stream(GUID_STREAM) {
+            self.write_stream(GUID_STREAM, &[])?;
+        }
+        self.names.insert(id, NamedProperty { property_set, name });
+        Ok(id)
+    }
+}
+
+impl EmailMessage {
+    /// The Outlook categories of the message (PidNameKeywords).
+    pub fn categories(&self) -> Vec<String> {
+        self.properties
+            .strings(crate::Name::Keywords)
+            .map(<[String]>::to_vec)
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+    use crate::test_support::MsgBuilder;
+
+    fn edit(data: Vec<u8>, categories: &[&str]) -> Vec<u8> {
+        let mut editor = MessageEditor::new(Cursor::new(data)).unwrap();
+        editor.set_categories(categories).unwrap();
+        editor.into_inner().unwrap().into_inner()
+    }
+
+    #[test]
+    fn categories() {
+        let mut builder = MsgBuilder::new();
+        builder.named(&[(PropertySet::Common, PropertyName::Id(0x8530))]);
+        builder.string("/", 0x8000, "Call");
+        let data = builder.build();
+        assert!(EmailMessage::from_bytes(&data)
+            .unwrap()
+            .categories()
+            .is_empty());
+
+        let data = edit(data, &["Retain 7 years", "Finance", "Réunion"]);
+        let msg = EmailMessage::from_bytes(&data).unwrap();
+        assert_eq!(msg.categories(), ["Retain 7 years", "Finance", "Réunion"]);
+        assert_eq!(msg.properties.string(crate::Lid::FlagRequest), Some("Call"));
+
+        // Replacing the categories reuses the mapping and drops old values.
+        let data = edit(data, &["Finance"]);
+        let mut editor = MessageEditor::new(Cursor::new(data)).unwrap();
+        assert_eq!(editor.message().unwrap().categories(), ["Finance"]);
+        assert_eq!(editor.names.iter().count(), 2);
+        assert!(!editor.comp.exists("/__substg1.0_8001101F-00000001"));
+
+        editor.set_categories::<&str>(&[]).unwrap();
+        assert!(editor.message().unwrap().categories().is_empty());
+    }
+
+    #[test]
+    fn categories_without_named_properties() {
+        let data = edit(MsgBuilder::new().build(), &["Personal"]);
+        let msg = EmailMessage::from_bytes(&data).unwrap();
+        assert_eq!(msg.categories(), ["Personal"]);
+    }
+
+    #[test]
+    fn malformed_messages() {
+        assert!(MessageEditor::new(Cursor::new(b"not a message".to_vec())).is_err());
+
+        // A compound file without a property stream can't be edited.
+        let comp = cfb::CompoundFile::create(Cursor::new(Vec::new())).unwrap();
+        let data = comp.into_inner().into_inner();
+        let mut editor = MessageEditor::new(Cursor::new(data)).unwrap();
+        assert!(editor.set_categories(&["Finance"]).is_err());
+
+        // Keywords of the wrong type aren't categories.
+        let mut builder = MsgBuilder::new();
+        builder.named(&[(
+            PropertySet::PublicStrings,
+            PropertyName::String("Keywords".to_string()),
+        )]);
+        builder.string("/", 0x8000, "Finance");
+        let msg = EmailMessage::from_bytes(&builder.build()).unwrap();
+        assert!(msg.categories().is_empty());
+    }
+}
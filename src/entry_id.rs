@@ -0,0 +1,672 @@
+//! Entry IDs, which identify address book entries, folders and messages
+//! (MS-OXCDATA 2.2.4 and 2.2.5).
+
+use std::convert::TryFrom;
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::binary::ByteReader;
+
+/// The provider UID of one-off entry IDs, {812B1FA4-BEA3-1019-9D6E-00DD010F5402}.
+const ONE_OFF_PROVIDER: Uuid = Uuid::from_u128(0x812B1FA4_BEA3_1019_9D6E_00DD010F5402);
+
+/// The provider UID of wrapped entry IDs, {C091ADD3-519D-CF11-A4A9-00AA0047FAA4}.
+const WRAPPED_PROVIDER: Uuid = Uuid::from_u128(0xC091ADD3_519D_CF11_A4A9_00AA0047FAA4);
+
+/// The provider UID of address book entry IDs, {DCA740C8-C042-101A-B4B9-08002B2FE182}.
+const ADDRESS_BOOK_PROVIDER: Uuid = Uuid::from_u128(0xDCA740C8_C042_101A_B4B9_08002B2FE182);
+
+/// The provider UID of contact address entry IDs, {FE42AA0A-18C7-1A10-E885-0B651C240000}.
+const CONTACT_ADDRESS_PROVIDER: Uuid = Uuid::from_u128(0xFE42AA0A_18C7_1A10_E885_0B651C240000);
+
+/// The provider UID of store entry IDs, {38A1BB10-05E5-101A-A1BB-08002B2A56C2}.
+const STORE_PROVIDER: Uuid = Uuid::from_u128(0x38A1BB10_05E5_101A_A1BB_08002B2A56C2);
+
+/// MAPI_UNICODE in the flags of a one-off entry ID.
+const ONE_OFF_UNICODE: u16 = 0x8000;
+
+/// MAPI_SEND_NO_RICH_INFO in the flags of a one-off entry ID.
+const ONE_OFF_NO_RICH_INFO: u16 = 0x0001;
+
+/// Set in the type of a wrapped entry ID when the embedded entry ID is a
+/// message entry ID.
+const WRAPPED_MESSAGE: u8 = 0x80;
+
+#[derive(Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum EntryId {
+    /// An address that isn't in any address book.
+    OneOff(OneOffEntryId),
+    /// An entry in the Exchange address book.
+    AddressBook(AddressBookEntryId),
+    /// An email address of a contact, from the contact address book.
+    ContactAddress(ContactAddressEntryId),
+    /// A mailbox or public folder store.
+    Store(StoreEntryId),
+    Folder(FolderEntryId),
+    Message(MessageEntryId),
+    /// A link to a contact or distribution list in a mailbox.
+    Wrapped(WrappedEntryId),
+    /// An entry ID from any other provider.
+    Other {
+        provider: Uuid,
+        data: Vec<u8>,
+    },
+}
+
+impl EntryId {
+    pub fn parse(data: &[u8]) -> Result<Self, &'static str> {
+        let mut reader = ByteReader::new(data);
+        reader.u32()?;
+        // Provider UIDs are stored in byte order, unlike other GUIDs.
+        let provider = Uuid::from_slice(reader.bytes(16)?).unwrap();
+        let entry_id = match provider {
+            ONE_OFF_PROVIDER => Self::OneOff(OneOffEntryId::read(&mut reader)?),
+            WRAPPED_PROVIDER => Self::Wrapped(WrappedEntryId::read(&mut reader)?),
+            ADDRESS_BOOK_PROVIDER => Self::AddressBook(AddressBookEntryId::read(&mut reader)?),
+            CONTACT_ADDRESS_PROVIDER => {
+                Self::ContactAddress(ContactAddressEntryId::read(&mut reader)?)
+            }
+            STORE_PROVIDER => Self::Store(StoreEntryId::read(&mut reader)?),
+            // Folder and message entry IDs use the GUID of their store as the
+            // provider UID, so can only be told apart by their size and type.
+            provider => match (data.len(), reader.u16().map(FolderType::from_u16)) {
+                (46, Ok(folder_type)) if folder_type.is_folder() => Self::Folder(FolderEntryId {
+                    store: provider,
+                    folder_type,
+                    folder: GlobalId::read(&mut reader)?,
+                }),
+                (70, Ok(message_type)) if !message_type.is_folder() => {
+                    Self::Message(MessageEntryId {
+                        store: provider,
+                        message_type,
+                        folder: GlobalId::read(&mut reader)?,
+                        message: GlobalId::read(&mut reader)?,
+                    })
+                }
+                _ => Self::Other {
+                    provider,
+                    data: data[20..].to_vec(),
+                },
+            },
+        };
+        Ok(entry_id)
+    }
+
+    /// The entry ID in its binary form. The flags are always zero, which
+    /// marks the entry ID as long-term.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let provider = match self {
+            Self::OneOff(_) => ONE_OFF_PROVIDER,
+            Self::AddressBook(_) => ADDRESS_BOOK_PROVIDER,
+            Self::ContactAddress(_) => CONTACT_ADDRESS_PROVIDER,
+            Self::Store(_) => STORE_PROVIDER,
+            Self::Folder(FolderEntryId { store, .. })
+            | Self::Message(MessageEntryId { store, .. }) => *store,
+            Self::Wrapped(_) => WRAPPED_PROVIDER,
+            Self::Other { provider, .. } => *provider,
+        };
+        let mut data = vec![0; 4];
+        data.extend(provider.as_bytes());
+        match self {
+            Self::OneOff(one_off) => one_off.write(&mut data),
+            Self::AddressBook(address_book) => address_book.write(&mut data),
+            Self::ContactAddress(contact) => contact.write(&mut data),
+            Self::Store(store) => store.write(&mut data),
+            Self::Folder(folder) => {
+                data.extend(folder.folder_type.to_u16().to_le_bytes());
+                folder.folder.write(&mut data);
+            }
+            Self::Message(message) => {
+                data.extend(message.message_type.to_u16().to_le_bytes());
+                message.folder.write(&mut data);
+                message.message.write(&mut data);
+            }
+            Self::Wrapped(wrapped) => wrapped.write(&mut data),
+            Self::Other { data: rest, .. } => data.extend(rest),
+        }
+        data
+    }
+}
+
+/// Read a list of entry IDs, such as PidTagReplyRecipientEntries
+/// (FlatEntryList, MS-OXCDATA 2.3.3).
+pub fn parse_entry_list(data: &[u8]) -> Result<Vec<EntryId>, &'static str> {
+    let mut reader = ByteReader::new(data);
+    let count = reader.u32()?;
+    reader.u32()?;
+    let mut entries = Vec::new();
+    for i in 0..count {
+        let len = reader.u32()? as usize;
+        entries.push(EntryId::parse(reader.bytes(len)?)?);
+        // Every entry but the last is padded to a multiple of 4 bytes.
+        if i + 1 < count {
+            reader.bytes(len.next_multiple_of(4) - len)?;
+        }
+    }
+    Ok(entries)
+}
+
+/// Write a string followed by a null character, in UTF-16 if `wide` and
+/// otherwise as Latin-1, replacing characters that don't fit with `?`.
+fn write_null_terminated(data: &mut Vec<u8>, value: &str, wide: bool) {
+    if wide {
+        data.extend(value.encode_utf16().chain([0]).flat_map(u16::to_le_bytes));
+    } else {
+        data.extend(value.chars().map(|c| u8::try_from(c).unwrap_or(b'?')));
+        data.push(0);
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct OneOffEntryId {
+    pub display_name: String,
+    /// Usually `SMTP`.
+    pub address_type: String,
+    pub address: String,
+    /// Whether the strings are stored in UTF-16 rather than the code page.
+    pub unicode: bool,
+    /// Whether the recipient should only be sent plain text.
+    pub no_rich_info: bool,
+}
+
+impl OneOffEntryId {
+    fn read(reader: &mut ByteReader) -> Result<Self, &'static str> {
+        reader.u16()?;
+        let flags = reader.u16()?;
+        let wide = flags & ONE_OFF_UNICODE != 0;
+        Ok(Self {
+            display_name: reader.null_terminated(wide)?,
+            address_type: reader.null_terminated(wide)?,
+            address: reader.null_terminated(wide)?,
+            unicode: wide,
+            no_rich_info: flags & ONE_OFF_NO_RICH_INFO != 0,
+        })
+    }
+
+    fn write(&self, data: &mut Vec<u8>) {
+        let mut flags = 0;
+        if self.unicode {
+            flags |= ONE_OFF_UNICODE;
+        }
+        if self.no_rich_info {
+            flags |= ONE_OFF_NO_RICH_INFO;
+        }
+        data.extend(0_u16.to_le_bytes());
+        data.extend(flags.to_le_bytes());
+        for value in [&self.display_name, &self.address_type, &self.address].iter() {
+            write_null_terminated(data, value, self.unicode);
+        }
+    }
+}
+
+/// The type of object an address book entry ID refers to.
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum AddressBookType {
+    MailUser,
+    DistList,
+    /// A public folder.
+    BulletinBoard,
+    AutomatedMailbox,
+    OrganizationalMailbox,
+    PrivateDistList,
+    RemoteMailUser,
+    Container,
+    Template,
+    OneOffUser,
+    Search,
+    Other(u32),
+}
+
+impl AddressBookType {
+    pub fn from_u32(n: u32) -> Self {
+        match n {
+            0x000 => Self::MailUser,
+            0x001 => Self::DistList,
+            0x002 => Self::BulletinBoard,
+            0x003 => Self::AutomatedMailbox,
+            0x004 => Self::OrganizationalMailbox,
+            0x005 => Self::PrivateDistList,
+            0x006 => Self::RemoteMailUser,
+            0x100 => Self::Container,
+            0x101 => Self::Template,
+            0x102 => Self::OneOffUser,
+            0x200 => Self::Search,
+            n => Self::Other(n),
+        }
+    }
+
+    pub fn to_u32(self) -> u32 {
+        match self {
+            Self::MailUser => 0x000,
+            Self::DistList => 0x001,
+            Self::BulletinBoard => 0x002,
+            Self::AutomatedMailbox => 0x003,
+            Self::OrganizationalMailbox => 0x004,
+            Self::PrivateDistList => 0x005,
+            Self::RemoteMailUser => 0x006,
+            Self::Container => 0x100,
+            Self::Template => 0x101,
+            Self::OneOffUser => 0x102,
+            Self::Search => 0x200,
+            Self::Other(n) => n,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct AddressBookEntryId {
+    pub object_type: AddressBookType,
+    /// The X500 distinguished name of the entry, such as
+    /// `/o=Example/ou=Exchange Administrative Group/cn=Recipients/cn=alice`.
+    pub x500_dn: String,
+}
+
+impl AddressBookEntryId {
+    fn read(reader: &mut ByteReader) -> Result<Self, &'static str> {
+        reader.u32()?;
+        Ok(Self {
+            object_type: AddressBookType::from_u32(reader.u32()?),
+            x500_dn: reader.null_terminated(false)?,
+        })
+    }
+
+    fn write(&self, data: &mut Vec<u8>) {
+        data.extend(1_u32.to_le_bytes());
+        data.extend(self.object_type.to_u32().to_le_bytes());
+        write_null_terminated(data, &self.x500_dn, false);
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct ContactAddressEntryId {
+    /// Whether the entry is a personal distribution list rather than a
+    /// contact.
+    pub dist_list: bool,
+    /// Which address of the contact the entry is for: 0 to 2 for Email1 to
+    /// Email3, and 3 to 5 for the business, home and primary fax numbers.
+    pub index: u32,
+    /// The entry ID of the contact or distribution list message.
+    pub entry_id: Vec<u8>,
+}
+
+impl ContactAddressEntryId {
+    fn read(reader: &mut ByteReader) -> Result<Self, &'static str> {
+        reader.u32()?;
+        let dist_list = reader.u32()? == 0x05;
+        let index = reader.u32()?;
+        let size = reader.u32()? as usize;
+        Ok(Self {
+            dist_list,
+            index,
+            entry_id: reader.bytes(size)?.to_vec(),
+        })
+    }
+
+    fn write(&self, data: &mut Vec<u8>) {
+        data.extend(3_u32.to_le_bytes());
+        data.extend(if self.dist_list { 0x05_u32 } else { 0x04 }.to_le_bytes());
+        data.extend(self.index.to_le_bytes());
+        data.extend((self.entry_id.len() as u32).to_le_bytes());
+        data.extend(&self.entry_id);
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct StoreEntryId {
+    /// The store provider, usually `emsmdb.dll`.
+    pub dll_name: String,
+    /// Identifies a mailbox or public folder store.
+    pub wrapped_provider: Uuid,
+    /// 0x0C for a mailbox store and 0x06 for a public folder store.
+    pub wrapped_type: u32,
+    /// The short name of the server holding the store.
+    pub server: String,
+    /// The X500 distinguished name of the mailbox, which public folder
+    /// stores don't have.
+    pub mailbox_dn: Option<String>,
+}
+
+impl StoreEntryId {
+    fn read(reader: &mut ByteReader) -> Result<Self, &'static str> {
+        reader.bytes(2)?;
+        let dll_name = reader.bytes(14)?;
+        let end = dll_name.iter().position(|&c| c == 0).unwrap_or(14);
+        let dll_name = dll_name[..end].iter().map(|&c| c as char).collect();
+        reader.u32()?;
+        let wrapped_provider = Uuid::from_slice(reader.bytes(16)?).unwrap();
+        let wrapped_type = reader.u32()?;
+        let server = reader.null_terminated(false)?;
+        // Later versions add more fields after the mailbox DN.
+        let mailbox_dn = reader
+            .null_terminated(false)
+            .ok()
+            .filter(|dn| !dn.is_empty());
+        Ok(Self {
+            dll_name,
+            wrapped_provider,
+            wrapped_type,
+            server,
+            mailbox_dn,
+        })
+    }
+
+    fn write(&self, data: &mut Vec<u8>) {
+        data.extend([0, 0]);
+        let mut dll_name = [0; 14];
+        for (byte, c) in dll_name.iter_mut().zip(self.dll_name.bytes().take(13)) {
+            *byte = c;
+        }
+        data.extend(dll_name);
+        data.extend(0_u32.to_le_bytes());
+        data.extend(self.wrapped_provider.as_bytes());
+        data.extend(self.wrapped_type.to_le_bytes());
+        write_null_terminated(data, &self.server, false);
+        if let Some(mailbox_dn) = &self.mailbox_dn {
+            write_null_terminated(data, mailbox_dn, false);
+        }
+    }
+
+    pub fn is_public(&self) -> bool {
+        self.mailbox_dn.is_none()
+    }
+}
+
+/// The type of a folder or message entry ID.
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum FolderType {
+    PrivateFolder,
+    PublicFolder,
+    MappedPublicFolder,
+    PrivateMessage,
+    PublicMessage,
+    MappedPublicMessage,
+    PublicNewsgroupFolder,
+    Other(u16),
+}
+
+impl FolderType {
+    pub fn from_u16(n: u16) -> Self {
+        match n {
+            0x0001 => Self::PrivateFolder,
+            0x0003 => Self::PublicFolder,
+            0x0005 => Self::MappedPublicFolder,
+            0x0007 => Self::PrivateMessage,
+            0x0009 => Self::PublicMessage,
+            0x000B => Self::MappedPublicMessage,
+            0x000C => Self::PublicNewsgroupFolder,
+            n => Self::Other(n),
+        }
+    }
+
+    pub fn to_u16(self) -> u16 {
+        match self {
+            Self::PrivateFolder => 0x0001,
+            Self::PublicFolder => 0x0003,
+            Self::MappedPublicFolder => 0x0005,
+            Self::PrivateMessage => 0x0007,
+            Self::PublicMessage => 0x0009,
+            Self::MappedPublicMessage => 0x000B,
+            Self::PublicNewsgroupFolder => 0x000C,
+            Self::Other(n) => n,
+        }
+    }
+
+    fn is_folder(self) -> bool {
+        matches!(
+            self,
+            Self::PrivateFolder
+                | Self::PublicFolder
+                | Self::MappedPublicFolder
+                | Self::PublicNewsgroupFolder
+        )
+    }
+}
+
+/// Identifies a folder or message within a store (MS-OXCDATA 2.2.1.3.1).
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct GlobalId {
+    /// The replica of the store the object was created in.
+    pub database_guid: Uuid,
+    /// A 48-bit counter, unique within the replica.
+    pub counter: u64,
+}
+
+impl GlobalId {
+    fn read(reader: &mut ByteReader) -> Result<Self, &'static str> {
+        let database_guid = reader.guid()?;
+        // The counter is big-endian, unlike the rest of the entry ID.
+        let counter = reader
+            .bytes(6)?
+            .iter()
+            .fold(0, |counter, &byte| counter << 8 | byte as u64);
+        reader.bytes(2)?;
+        Ok(Self {
+            database_guid,
+            counter,
+        })
+    }
+
+    fn write(&self, data: &mut Vec<u8>) {
+        data.extend(self.database_guid.to_bytes_le());
+        data.extend(&self.counter.to_be_bytes()[2..]);
+        data.extend([0, 0]);
+    }
+}
+
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct FolderEntryId {
+    /// The GUID of the store the folder is in.
+    pub store: Uuid,
+    pub folder_type: FolderType,
+    pub folder: GlobalId,
+}
+
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct MessageEntryId {
+    /// The GUID of the store the message is in.
+    pub store: Uuid,
+    pub message_type: FolderType,
+    pub folder: GlobalId,
+    pub message: GlobalId,
+}
+
+/// What a wrapped entry ID links to.
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum WrappedType {
+    OneOff,
+    /// One of the email addresses of a contact, from 0 for Email1.
+    Contact {
+        email_index: u8,
+    },
+    PersonalDistList,
+    /// A user in the global address list.
+    AddressBookUser,
+    /// A distribution list in the global address list.
+    AddressBookDistList,
+    Other(u8),
+}
+
+impl WrappedType {
+    fn to_u8(self) -> u8 {
+        match self {
+            Self::OneOff => 0x00,
+            Self::Contact { email_index } => WRAPPED_MESSAGE | (email_index & 0x07) << 4 | 0x03,
+            Self::PersonalDistList => WRAPPED_MESSAGE | 0x04,
+            Self::AddressBookUser => 0x05,
+            Self::AddressBookDistList => 0x06,
+            Self::Other(n) => n,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct WrappedEntryId {
+    pub wrapped_type: WrappedType,
+    /// The entry ID of the contact, list or address book entry.
+    pub embedded: Vec<u8>,
+}
+
+impl WrappedEntryId {
+    fn read(reader: &mut ByteReader) -> Result<Self, &'static str> {
+        let wrapped_type = reader.u8()?;
+        let wrapped_type = match wrapped_type & 0x0F {
+            0x00 => WrappedType::OneOff,
+            0x03 => WrappedType::Contact {
+                email_index: (wrapped_type >> 4) & 0x07,
+            },
+            0x04 => WrappedType::PersonalDistList,
+            0x05 => WrappedType::AddressBookUser,
+            0x06 => WrappedType::AddressBookDistList,
+            _ => WrappedType::Other(wrapped_type),
+        };
+        Ok(Self {
+            wrapped_type,
+            embedded: reader.rest().to_vec(),
+        })
+    }
+
+    fn write(&self, data: &mut Vec<u8>) {
+        data.push(self.wrapped_type.to_u8());
+        data.extend(&self.embedded);
+    }
+
+    /// The embedded entry ID, which is a message entry ID for contacts and
+    /// personal distribution lists.
+    pub fn entry_id(&self) -> Result<EntryId, &'static str> {
+        EntryId::parse(&self.embedded)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip(entry_id: EntryId) {
+        let data = entry_id.to_bytes();
+        assert_eq!(EntryId::parse(&data).unwrap(), entry_id);
+    }
+
+    #[test]
+    fn address_book() {
+        let mut data = vec![0; 4];
+        data.extend(ADDRESS_BOOK_PROVIDER.as_bytes());
+        data.extend(1_u32.to_le_bytes());
+        data.extend(0_u32.to_le_bytes());
+        data.extend(b"/o=Example/ou=First Administrative Group/cn=Recipients/cn=alice\0");
+        match EntryId::parse(&data).unwrap() {
+            EntryId::AddressBook(entry_id) => {
+                assert_eq!(entry_id.object_type, AddressBookType::MailUser);
+                assert!(entry_id.x500_dn.ends_with("cn=alice"));
+            }
+            entry_id => panic!("not an address book entry ID: {:?}", entry_id),
+        }
+        assert_eq!(EntryId::parse(&data).unwrap().to_bytes(), data);
+    }
+
+    #[test]
+    fn round_trips() {
+        let store = Uuid::from_u128(0x0123_4567_89AB_CDEF_0123_4567_89AB_CDEF);
+        let folder = GlobalId {
+            database_guid: Uuid::from_u128(0xFEDC_BA98_7654_3210_FEDC_BA98_7654_3210),
+            counter: 0x0000_0102_0304_0506,
+        };
+        let message = GlobalId {
+            counter: 0x42,
+            ..folder
+        };
+        let message_entry_id = EntryId::Message(MessageEntryId {
+            store,
+            message_type: FolderType::PrivateMessage,
+            folder,
+            message,
+        });
+        assert_eq!(message_entry_id.to_bytes().len(), 70);
+        for entry_id in [
+            EntryId::OneOff(OneOffEntryId {
+                display_name: "Zoë".to_string(),
+                address_type: "SMTP".to_string(),
+                address: "zoe@example.com".to_string(),
+                unicode: true,
+                no_rich_info: true,
+            }),
+            EntryId::OneOff(OneOffEntryId {
+                display_name: "Bob".to_string(),
+                address_type: "SMTP".to_string(),
+                address: "bob@example.com".to_string(),
+                unicode: false,
+                no_rich_info: false,
+            }),
+            EntryId::Folder(FolderEntryId {
+                store,
+                folder_type: FolderType::PrivateFolder,
+                folder,
+            }),
+            EntryId::Store(StoreEntryId {
+                dll_name: "emsmdb.dll".to_string(),
+                wrapped_provider: store,
+                wrapped_type: 0x0C,
+                server: "mail.example.com".to_string(),
+                mailbox_dn: Some("/o=Example/cn=alice".to_string()),
+            }),
+            EntryId::ContactAddress(ContactAddressEntryId {
+                dist_list: false,
+                index: 1,
+                entry_id: message_entry_id.to_bytes(),
+            }),
+            EntryId::Wrapped(WrappedEntryId {
+                wrapped_type: WrappedType::Contact { email_index: 2 },
+                embedded: message_entry_id.to_bytes(),
+            }),
+            message_entry_id,
+        ]
+        .iter()
+        {
+            round_trip(entry_id.clone());
+        }
+    }
+
+    #[test]
+    fn malformed() {
+        let one_off = EntryId::OneOff(OneOffEntryId {
+            display_name: "Bob".to_string(),
+            address_type: "SMTP".to_string(),
+            address: "bob@example.com".to_string(),
+            unicode: true,
+            no_rich_info: false,
+        })
+        .to_bytes();
+        // Missing the provider, the flags or the null terminator of the
+        // address.
+        for end in [0, 10, 22, one_off.len() - 2] {
+            assert!(EntryId::parse(&one_off[..end]).is_err());
+        }
+        let mut wrapped = vec![0; 4];
+        wrapped.extend(WRAPPED_PROVIDER.as_bytes());
+        assert!(EntryId::parse(&wrapped).is_err());
+
+        // Entry IDs from unknown providers are kept as they are.
+        let mut unknown = vec![0; 4];
+        unknown.extend([0x42; 16]);
+        unknown.extend([1, 2, 3]);
+        assert_eq!(
+            EntryId::parse(&unknown).unwrap(),
+            EntryId::Other {
+                provider: Uuid::from_bytes([0x42; 16]),
+                data: vec![1, 2, 3],
+            }
+        );
+
+        let mut list = 2_u32.to_le_bytes().to_vec();
+        list.extend(0_u32.to_le_bytes());
+        list.extend((one_off.len() as u32).to_le_bytes());
+        list.extend(&one_off);
+        list.resize(list.len().next_multiple_of(4), 0);
+        list.extend(u32::MAX.to_le_bytes());
+        assert!(parse_entry_list(&list).is_err());
+        list[0..4].copy_from_slice(&1_u32.to_le_bytes());
+        assert_eq!(parse_entry_list(&list).unwrap().len(), 1);
+        list[8..12].copy_from_slice(&3_u32.to_le_bytes());
+        assert!(parse_entry_list(&list).is_err());
+    }
+}
@@ -0,0 +1,210 @@
+//! Hashes and file type sniffing for attachment data.
+
+use md5::Md5;
+use serde::{Deserialize, Serialize};
+use sha1::Sha1;
+use sha2::{Digest, Sha256};
+
+/// A file type recognised from the leading bytes of its content.
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum FileType {
+    Pdf,
+    /// Zip archives, including the Office Open XML and OpenDocument formats.
+    Zip,
+    /// OLE compound files, including legacy Office documents and .msg files.
+    Cfb,
+    Rar,
+    SevenZip,
+    Gzip,
+    Png,
+    Jpeg,
+    Gif,
+    Bmp,
+    Tiff,
+    Ico,
+    Rtf,
+    /// Windows executables and DLLs.
+    Executable,
+    Elf,
+    MachO,
+}
+
+impl FileType {
+    pub fn sniff(data: &[u8]) -> Option<Self> {
+        let file_type = if data.starts_with(b"%PDF-") {
+            Self::Pdf
+        } else if data.starts_with(b"PK\x03\x04")
+            || data.starts_with(b"PK\x05\x06")
+            || data.starts_with(b"PK\x07\x08")
+        {
+            Self::Zip
+        } else if data.starts_with(&[0xD0, 0xCF, 0x11, 0xE0, 0xA1, 0xB1, 0x1A, 0xE1]) {
+            Self::Cfb
+        } else if data.starts_with(b"Rar!\x1A\x07") {
+            Self::Rar
+        } else if data.starts_with(&[b'7', b'z', 0xBC, 0xAF, 0x27, 0x1C]) {
+            Self::SevenZip
+        } else if data.starts_with(&[0x1F, 0x8B]) {
+            Self::Gzip
+        } else if data.starts_with(b"\x89PNG\r\n\x1A\n") {
+            Self::Png
+        } else if data.starts_with(&[0xFF, 0xD8, 0xFF]) {
+            Self::Jpeg
+        } else if data.starts_with(b"GIF87a") || data.starts_with(b"GIF89a") {
+            Self::Gif
+        } else if data.starts_with(b"BM") && data.len() > 14 {
+            Self::Bmp
+        } else if data.starts_with(b"II*\0") || data.starts_with(b"MM\0*") {
+            Self::Tiff
+        } else if data.starts_with(&[0x00, 0x00, 0x01, 0x00]) {
+            Self::Ico
+        } else if data.starts_with(b"{\\rtf") {
+            Self::Rtf
+        } else if data.starts_with(b"MZ") {
+            Self::Executable
+        } else if data.starts_with(b"\x7FELF") {
+            Self::Elf
+        } else if data.starts_with(&[0xFE, 0xED, 0xFA, 0xCE])
+            || data.starts_with(&[0xFE, 0xED, 0xFA, 0xCF])
+            || data.starts_with(&[0xCE, 0xFA, 0xED, 0xFE])
+            || data.starts_with(&[0xCF, 0xFA, 0xED, 0xFE])
+        {
+            Self::MachO
+        } else {
+            return None;
+        };
+        Some(file_type)
+    }
+
+    /// The file extensions that are expected for content of this type.
+    pub fn extensions(self) -> &'static [&'static str] {
+        match self {
+            Self::Pdf => &["pdf"],
+            Self::Zip => &[
+                "zip", "docx", "docm", "dotx", "dotm", "xlsx", "xlsm", "xltx", "xltm", "pptx",
+                "pptm", "potx", "ppsx", "vsdx", "odt", "ods", "odp", "odg", "epub", "jar", "apk",
+                "xps", "oxps", "kmz",
+            ],
+            Self::Cfb => &[
+                "doc", "dot", "xls", "xlt", "ppt", "pot", "pps", "msg", "msi", "vsd", "pub", "mpp",
+                "oft",
+            ],
+            Self::Rar => &["rar"],
+            Self::SevenZip => &["7z"],
+            Self::Gzip => &["gz", "tgz"],
+            Self::Png => &["png"],
+            Self::Jpeg => &["jpg", "jpeg", "jpe", "jfif"],
+            Self::Gif => &["gif"],
+            Self::Bmp => &["bmp", "dib"],
+            Self::Tiff => &["tif", "tiff"],
+            Self::Ico => &["ico", "cur"],
+            Self::Rtf => &["rtf", "doc"],
+            Self::Executable => &["exe", "dll", "sys", "scr", "cpl", "ocx", "com", "efi"],
+            Self::Elf => &["so", "elf", "o", "bin"],
+            Self::MachO => &["dylib", "bundle", "o"],
+        }
+    }
+
+    pub fn mime_type(self) -> &'static str {
+        match self {
+            Self::Pdf => "application/pdf",
+            Self::Zip => "application/zip",
+            Self::Cfb => "application/x-ole-storage",
+            Self::Rar => "application/vnd.rar",
+            Self::SevenZip => "application/x-7z-compressed",
+            Self::Gzip => "application/gzip",
+            Self::Png => "image/png",
+            Self::Jpeg => "image/jpeg",
+            Self::Gif => "image/gif",
+            Self::Bmp => "image/bmp",
+            Self::Tiff => "image/tiff",
+            Self::Ico => "image/vnd.microsoft.icon",
+            Self::Rtf => "application/rtf",
+            Self::Executable => "application/vnd.microsoft.portable-executable",
+            Self::Elf => "application/x-elf",
+            Self::MachO => "application/x-mach-binary",
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct Fingerprint {
+    pub name: String,
+    pub size: u64,
+    /// Lowercase hex digests of the attachment data.
+    pub md5: String,
+    pub sha1: String,
+    pub sha256: String,
+    pub sniffed_type: Option<FileType>,
+    /// The extension declared by the attachment, without the leading dot.
+    pub extension: Option<String>,
+    pub mime_type: Option<String>,
+    /// The sniffed type is not one that is expected for the declared extension.
+    pub type_mismatch: bool,
+}
+
+impl Fingerprint {
+    pub(crate) fn new(
+        name: String,
+        data: &[u8],
+        extension: Option<String>,
+        mime_type: Option<String>,
+    ) -> Self {
+        let sniffed_type = FileType::sniff(data);
+        let extension = extension
+            .map(|extension| extension.trim_start_matches('.').to_lowercase())
+            .filter(|extension| !extension.is_empty());
+        let type_mismatch = match (sniffed_type, &extension) {
+            (Some(sniffed_type), Some(extension)) => {
+                !sniffed_type.extensions().contains(&extension.as_str())
+            }
+            _ => false,
+        };
+        Self {
+            name,
+            size: data.len() as u64,
+            md5: hex(&Md5::digest(data)),
+            sha1: hex(&Sha1::digest(data)),
+            sha256: hex(&Sha256::digest(data)),
+            sniffed_type,
+            extension,
+            mime_type,
+            type_mismatch,
+        }
+    }
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fingerprint_hashes() {
+        let fingerprint =
+            Fingerprint::new("a.txt".to_string(), b"abc", Some(".TXT".to_string()), None);
+        assert_eq!(fingerprint.md5, "900150983cd24fb0d6963f7d28e17f72");
+        assert_eq!(fingerprint.sha1, "a9993e364706816aba3e25717850c26c9cd0d89d");
+        assert_eq!(
+            fingerprint.sha256,
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        assert_eq!(fingerprint.extension.as_deref(), Some("txt"));
+        assert!(!fingerprint.type_mismatch);
+    }
+
+    #[test]
+    fn extension_mismatch() {
+        let fingerprint = Fingerprint::new(
+            "invoice.pdf".to_string(),
+            b"MZ\x90\x00\x03",
+            Some("pdf".to_string()),
+            Some("application/pdf".to_string()),
+        );
+        assert_eq!(fingerprint.sniffed_type, Some(FileType::Executable));
+        assert!(fingerprint.type_mismatch);
+    }
+}
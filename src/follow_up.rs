@@ -0,0 +1,181 @@
+//! Follow-up flags and reminders on messages, from the properties in
+//! MS-OXOFLAG and MS-OXORMDR.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{state::FlagStatus, EmailMessage, Lid, Tag};
+
+#[derive(Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq)]
+pub struct Reminder {
+    /// When the reminder is due (PidLidReminderTime).
+    pub time: Option<DateTime<Utc>>,
+    /// When the reminder will next be shown, which is later than `time` if
+    /// it has been snoozed (PidLidReminderSignalTime).
+    pub signal_time: Option<DateTime<Utc>>,
+    /// How long before the start of an appointment the reminder is shown
+    /// (PidLidReminderDelta).
+    pub minutes_before: Option<i32>,
+    /// Whether `play_sound` and `sound_file` replace the user's defaults.
+    pub override_default: bool,
+    pub play_sound: bool,
+    pub sound_file: Option<String>,
+}
+
+impl Reminder {
+    /// The reminder on a message, if one is set.
+    pub fn from_message(message: &EmailMessage) -> Option<Self> {
+        let properties = &message.properties;
+        if !properties.boolean(Lid::ReminderSet).unwrap_or(false) {
+            return None;
+        }
+        Some(Self {
+            time: properties.time(Lid::ReminderTime),
+            signal_time: properties.time(Lid::ReminderSignalTime),
+            minutes_before: properties.integer32(Lid::ReminderDelta),
+            override_default: properties.boolean(Lid::ReminderOverride).unwrap_or(false),
+            play_sound: properties.boolean(Lid::ReminderPlaySound).unwrap_or(false),
+            sound_file: properties
+                .string(Lid::ReminderFileParameter)
+                .map(str::to_string),
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq)]
+pub struct FollowUp {
+    pub status: FlagStatus,
+    /// The text of the flag, such as `Follow up` or `Call`
+    /// (PidLidFlagRequest).
+    pub flag_text: Option<String>,
+    /// The title shown in the to-do list, usually the subject
+    /// (PidLidToDoTitle).
+    pub title: Option<String>,
+    /// The start and due dates are dates in the user's time zone.
+    pub start_date: Option<NaiveDate>,
+    pub due_date: Option<NaiveDate>,
+    /// When the flag was marked complete.
+    pub completed: Option<DateTime<Utc>>,
+    pub reminder: Option<Reminder>,
+}
+
+impl FollowUp {
+    /// The follow-up flag on a message, if it has been flagged.
+    pub fn from_message(message: &EmailMessage) -> Option<Self> {
+        let properties = &message.properties;
+        let status = properties
+            .integer32(Tag::FlagStatus)
+            .map(FlagStatus::from_i32)
+            .filter(|status| *status != FlagStatus::Unflagged)?;
+        let date = |lid: Lid| properties.time(lid).map(|time| time.date_naive());
+        let completed = match status {
+            FlagStatus::Complete => properties
+                .time(Tag::FlagCompleteTime)
+                .or_else(|| properties.time(Lid::TaskDateCompleted)),
+            _ => None,
+        };
+        Some(Self {
+            status,
+            flag_text: properties.string(Lid::FlagRequest).map(str::to_string),
+            title: properties.string(Lid::ToDoTitle).map(str::to_string),
+            start_date: date(Lid::TaskStartDate),
+            due_date: date(Lid::TaskDueDate),
+            completed,
+            reminder: Reminder::from_message(message),
+        })
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.status == FlagStatus::Complete
+    }
+}
+
+impl EmailMessage {
+    pub fn follow_up(&self) -> Option<FollowUp> {
+        FollowUp::from_message(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+    use crate::{
+        named::PropertyName,
+        test_support::{boolean, filetime, integer32, MsgBuilder},
+        PropertySet,
+    };
+
+    #[test]
+    fn follow_up() {
+        let due = Utc.with_ymd_and_hms(2023, 5, 12, 0, 0, 0).unwrap();
+        let reminder = Utc.with_ymd_and_hms(2023, 5, 11, 16, 0, 0).unwrap();
+        let mut builder = MsgBuilder::new();
+        builder.named(&[
+            (PropertySet::Common, PropertyName::Id(0x8530)),
+            (PropertySet::Task, PropertyName::Id(0x8105)),
+            (PropertySet::Common, PropertyName::Id(0x8503)),
+            (PropertySet::Common, PropertyName::Id(0x8502)),
+            (PropertySet::Common, PropertyName::Id(0x8560)),
+            (PropertySet::Common, PropertyName::Id(0x851E)),
+        ]);
+        builder.string("/", 0x8000, "Call");
+        builder.properties(
+            "/",
+            &[
+                (0x0003, 0x1090, integer32(2)),
+                (0x0040, 0x8001, filetime(due)),
+                (0x000B, 0x8002, boolean(true)),
+                (0x0040, 0x8003, filetime(reminder)),
+                (0x0040, 0x8004, filetime(reminder)),
+                (0x000B, 0x8005, boolean(true)),
+            ],
+        );
+        let msg = EmailMessage::from_bytes(&builder.build()).unwrap();
+
+        let follow_up = msg.follow_up().unwrap();
+        assert_eq!(follow_up.status, FlagStatus::Flagged);
+        assert!(!follow_up.is_complete());
+        assert_eq!(follow_up.flag_text.as_deref(), Some("Call"));
+        assert_eq!(follow_up.due_date, NaiveDate::from_ymd_opt(2023, 5, 12));
+        assert_eq!(follow_up.completed, None);
+        let reminder_state = follow_up.reminder.unwrap();
+        assert_eq!(reminder_state.time, Some(reminder));
+        assert!(reminder_state.play_sound);
+
+        let msg = EmailMessage::from_bytes(&MsgBuilder::new().build()).unwrap();
+        assert_eq!(msg.follow_up(), None);
+    }
+
+    #[test]
+    fn completed_follow_up() {
+        let completed = Utc.with_ymd_and_hms(2023, 5, 10, 12, 0, 0).unwrap();
+        let follow_up = |flag_status: i32| {
+            let mut builder = MsgBuilder::new();
+            builder.named(&[
+                (PropertySet::Task, PropertyName::Id(0x810F)),
+                (PropertySet::Common, PropertyName::Id(0x8503)),
+            ]);
+            builder.properties(
+                "/",
+                &[
+                    (0x0003, 0x1090, integer32(flag_status)),
+                    (0x0040, 0x8000, filetime(completed)),
+                    // A reminder flag of the wrong type is ignored.
+                    (0x0003, 0x8001, integer32(1)),
+                ],
+            );
+            let msg = EmailMessage::from_bytes(&builder.build()).unwrap();
+            msg.follow_up()
+        };
+
+        let complete = follow_up(1).unwrap();
+        assert!(complete.is_complete());
+        assert_eq!(complete.completed, Some(completed));
+        assert_eq!(complete.flag_text, None);
+        assert_eq!(complete.reminder, None);
+        assert_eq!(follow_up(0), None);
+        assert_eq!(follow_up(7).unwrap().status, FlagStatus::Other(7));
+    }
+}
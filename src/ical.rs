@@ -0,0 +1,316 @@
+//! Writing the content lines of iCalendar (RFC 5545) and vCard (RFC 6350)
+//! objects.
+
+use chrono::{DateTime, Datelike, NaiveDate, NaiveDateTime, Utc};
+use sha2::{Digest, Sha256};
+
+use crate::{
+    recurrence::{DaysOfWeek, EndType, PatternType, RecurFrequency, RecurrencePattern},
+    timezone::{TimeZoneDefinition, TimeZoneRule, TransitionDate},
+};
+
+/// Lines longer than this many octets are folded.
+const LINE_LENGTH: usize = 75;
+
+const WEEKDAYS: [&str; 7] = ["SU", "MO", "TU", "WE", "TH", "FR", "SA"];
+
+#[derive(Default)]
+pub(crate) struct ContentWriter {
+    out: String,
+}
+
+impl ContentWriter {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Write a line whose value is already in its final form.
+    pub(crate) fn line(&mut self, name: &str, value: &str) {
+        let line = format!("{name}:{value}");
+        let mut length = 0;
+        for c in line.chars() {
+            if length + c.len_utf8() > LINE_LENGTH {
+                self.out.push_str("\r\n ");
+                length = 1;
+            }
+            self.out.push(c);
+            length += c.len_utf8();
+        }
+        self.out.push_str("\r\n");
+    }
+
+    /// Write a line with a text value, which is escaped.
+    pub(crate) fn text(&mut self, name: &str, value: &str) {
+        self.line(name, &escape_text(value));
+    }
+
+    pub(crate) fn begin(&mut self, component: &str) {
+        self.line("BEGIN", component);
+    }
+
+    pub(crate) fn end(&mut self, component: &str) {
+        self.line("END", component);
+    }
+
+    pub(crate) fn finish(self) -> String {
+        self.out
+    }
+}
+
+pub(crate) fn escape_text(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' | ';' | ',' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// A parameter value, quoted if it has characters that need it.
+pub(crate) fn param_value(value: &str) -> String {
+    let value = value.replace('"', "");
+    if value.contains([':', ';', ',']) {
+        format!("\"{value}\"")
+    } else {
+        value
+    }
+}
+
+pub(crate) fn format_utc(time: DateTime<Utc>) -> String {
+    time.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+pub(crate) fn format_local(time: NaiveDateTime) -> String {
+    time.format("%Y%m%dT%H%M%S").to_string()
+}
+
+pub(crate) fn format_date(date: NaiveDate) -> String {
+    date.format("%Y%m%d").to_string()
+}
+
+/// A UID for an item whose message has no ID of its own, made from the
+/// subject and the time the item was created or last changed so that it is
+/// the same each time the message is read.
+pub(crate) fn fallback_uid(subject: &str, time: Option<DateTime<Utc>>) -> String {
+    let time = time.map(format_utc).unwrap_or_default();
+    let digest = Sha256::digest(format!("{subject}\n{time}"));
+    digest[..16]
+        .iter()
+        .map(|byte| format!("{byte:02X}"))
+        .collect()
+}
+
+/// Standard base64 with padding, for inline binary values.
+pub(crate) fn base64(data: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut encoded = String::with_capacity(data.len().div_ceil(3) * 4);
+    for chunk in data.chunks(3) {
+        let n = chunk
+            .iter()
+            .enumerate()
+            .fold(0_u32, |n, (i, byte)| n | (*byte as u32) << (16 - 8 * i));
+        for i in 0..4 {
+            if i <= chunk.len() {
+                encoded.push(ALPHABET[(n >> (18 - 6 * i)) as usize & 0x3F] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+/// A UTC offset in minutes as `+HHMM` or `-HHMM`.
+fn format_offset(minutes: i32) -> String {
+    let sign = if minutes < 0 { '-' } else { '+' };
+    let minutes = minutes.abs();
+    format!("{sign}{:02}{:02}", minutes / 60, minutes % 60)
+}
+
+/// The name used for a time zone as its TZID.
+pub(crate) fn tzid(zone: &TimeZoneDefinition) -> String {
+    zone.key_name
+        .clone()
+        .unwrap_or_else(|| "Custom Time Zone".to_string())
+}
+
+/// Write a VTIMEZONE with the rule in force during `year`.
+pub(crate) fn write_vtimezone(writer: &mut ContentWriter, zone: &TimeZoneDefinition, year: i32) {
+    let rule = match zone.rule_for(year) {
+        Some(rule) => rule,
+        None => return,
+    };
+    let standard = -(rule.bias + rule.standard_bias);
+    let daylight = -(rule.bias + rule.daylight_bias);
+    writer.begin("VTIMEZONE");
+    writer.text("TZID", &tzid(zone));
+    if rule.observes_daylight_time() {
+        write_observance(
+            writer,
+            "STANDARD",
+            rule,
+            &rule.standard_date,
+            daylight,
+            standard,
+        );
+        write_observance(
+            writer,
+            "DAYLIGHT",
+            rule,
+            &rule.daylight_date,
+            standard,
+            daylight,
+        );
+    } else {
+        writer.begin("STANDARD");
+        writer.line("DTSTART", "16010101T000000");
+        writer.line("TZOFFSETFROM", &format_offset(standard));
+        writer.line("TZOFFSETTO", &format_offset(standard));
+        writer.end("STANDARD");
+    }
+    writer.end("VTIMEZONE");
+}
+
+fn write_observance(
+    writer: &mut ContentWriter,
+    component: &str,
+    rule: &TimeZoneRule,
+    transition: &TransitionDate,
+    from: i32,
+    to: i32,
+) {
+    let year = match transition.year {
+        0 => (rule.start_year as i32).max(1601),
+        year => year as i32,
+    };
+    let start = match transition.in_year(year) {
+        Some(start) => start,
+        None => return,
+    };
+    writer.begin(component);
+    writer.line("DTSTART", &format_local(start));
+    if transition.year == 0 {
+        let week = if transition.day >= 5 {
+            -1
+        } else {
+            transition.day as i32
+        };
+        let weekday = WEEKDAYS[transition.day_of_week as usize % 7];
+        writer.line(
+            "RRULE",
+            &format!(
+                "FREQ=YEARLY;BYMONTH={};BYDAY={week}{weekday}",
+                transition.month
+            ),
+        );
+    }
+    writer.line("TZOFFSETFROM", &format_offset(from));
+    writer.line("TZOFFSETTO", &format_offset(to));
+    writer.end(component);
+}
+
+fn weekdays(days: DaysOfWeek) -> String {
+    WEEKDAYS
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| days.bits() & (1 << i) != 0)
+        .map(|(_, day)| *day)
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// The RRULE value for a recurrence pattern. `until` is the value to use
+/// for UNTIL if the recurrence ends on a date. Patterns on the Hijri
+/// calendar can't be expressed and give `None`.
+pub(crate) fn rrule(pattern: &RecurrencePattern, until: &str) -> Option<String> {
+    let yearly = pattern.frequency == RecurFrequency::Yearly;
+    let monthly = |by: String| {
+        let (frequency, interval) = if yearly {
+            ("YEARLY", pattern.period / 12)
+        } else {
+            ("MONTHLY", pattern.period)
+        };
+        let mut rule = format!("FREQ={frequency}");
+        if interval > 1 {
+            rule.push_str(&format!(";INTERVAL={interval}"));
+        }
+        if yearly {
+            rule.push_str(&format!(";BYMONTH={}", pattern.start_date.month()));
+        }
+        rule.push_str(&by);
+        rule
+    };
+    let mut rule = match pattern.pattern_type {
+        PatternType::Day => {
+            let interval = pattern.period / 1440;
+            if interval > 1 {
+                format!("FREQ=DAILY;INTERVAL={interval}")
+            } else {
+                "FREQ=DAILY".to_string()
+            }
+        }
+        PatternType::Week { days } => {
+            let mut rule = "FREQ=WEEKLY".to_string();
+            if pattern.period > 1 {
+                rule.push_str(&format!(";INTERVAL={}", pattern.period));
+            }
+            rule.push_str(&format!(
+                ";BYDAY={};WKST={}",
+                weekdays(days),
+                WEEKDAYS[pattern.first_day_of_week as usize % 7]
+            ));
+            rule
+        }
+        PatternType::Month { day } => monthly(format!(";BYMONTHDAY={day}")),
+        PatternType::MonthNth { days, n } => {
+            let position = if n >= 5 { -1 } else { n as i32 };
+            monthly(format!(";BYDAY={};BYSETPOS={position}", weekdays(days)))
+        }
+        PatternType::MonthEnd => monthly(";BYMONTHDAY=-1".to_string()),
+        _ => return None,
+    };
+    match pattern.end_type {
+        EndType::AfterOccurrences => rule.push_str(&format!(";COUNT={}", pattern.occurrence_count)),
+        EndType::AfterDate => rule.push_str(&format!(";UNTIL={until}")),
+        EndType::Never => {}
+    }
+    Some(rule)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn base64_padding() {
+        assert_eq!(base64(b""), "");
+        assert_eq!(base64(b"f"), "Zg==");
+        assert_eq!(base64(b"fo"), "Zm8=");
+        assert_eq!(base64(b"foobar"), "Zm9vYmFy");
+    }
+
+    #[test]
+    fn folding_and_escaping() {
+        let mut writer = ContentWriter::new();
+        writer.text(
+            "SUMMARY",
+            &format!("Lunch; bring a salad, {}", "é".repeat(40)),
+        );
+        let out = writer.finish();
+        let lines: Vec<&str> = out.split("\r\n").collect();
+        assert!(lines[0].starts_with("SUMMARY:Lunch\\; bring a salad\\, é"));
+        assert!(lines.iter().all(|line| line.len() <= LINE_LENGTH));
+        assert!(lines[1].starts_with(' '));
+        assert_eq!(
+            out.replace("\r\n ", ""),
+            format!("SUMMARY:Lunch\\; bring a salad\\, {}\r\n", "é".repeat(40))
+        );
+    }
+}
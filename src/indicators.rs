@@ -0,0 +1,56 @@
+//! A summary of the parts of a message that are of interest when responding
+//! to a security incident.
+
+use serde::{Deserialize, Serialize};
+
+use crate::{attachments::Attachment, fingerprint::Fingerprint, links::Link, EmailMessage};
+
+#[derive(Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq)]
+pub struct AttachmentIndicator {
+    /// The names of the embedded message attachments this attachment is
+    /// nested within, outermost first. Empty for top level attachments.
+    pub container: Vec<String>,
+    #[serde(flatten)]
+    pub fingerprint: Fingerprint,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq)]
+pub struct Indicators {
+    pub subject: String,
+    pub sender: String,
+    pub reply_to: Vec<String>,
+    /// Links from this message and any embedded messages.
+    pub urls: Vec<Link>,
+    pub attachments: Vec<AttachmentIndicator>,
+}
+
+impl EmailMessage {
+    pub fn indicators(&self) -> Indicators {
+        let mut indicators = Indicators {
+            subject: self.subject.clone(),
+            sender: self.sender.clone(),
+            reply_to: self.reply_to.clone(),
+            urls: self.links(),
+            attachments: Vec::new(),
+        };
+        collect(&self.attachments, &mut Vec::new(), &mut indicators);
+        indicators
+    }
+}
+
+fn collect(attachments: &[Attachment], container: &mut Vec<String>, indicators: &mut Indicators) {
+    for attachment in attachments {
+        if let Some(fingerprint) = attachment.fingerprint() {
+            indicators.attachments.push(AttachmentIndicator {
+                container: container.clone(),
+                fingerprint,
+            });
+        }
+        if let Some(message) = &attachment.embedded_message {
+            indicators.urls.extend(message.links());
+            container.push(attachment.name.clone());
+            collect(&message.attachments, container, indicators);
+            container.pop();
+        }
+    }
+}
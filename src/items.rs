@@ -0,0 +1,147 @@
+//! What kind of Outlook item a message is, based on its message class.
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    appointment::Appointment,
+    contact::Contact,
+    distlist::DistList,
+    journal::JournalEntry,
+    note::StickyNote,
+    report::Report,
+    task::{Task, TaskRequest},
+    EmailMessage,
+};
+
+/// The kind of item, from PidTagMessageClass. Classes derived from a known
+/// class, such as `IPM.Note.MyApp`, are treated as that class.
+#[derive(Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum MessageKind {
+    /// An email (`IPM.Note`). Messages without a class are also treated as
+    /// notes.
+    Note,
+    Appointment,
+    MeetingRequest,
+    MeetingResponse,
+    MeetingCancel,
+    Contact,
+    DistList,
+    Task,
+    TaskRequest,
+    StickyNote,
+    /// A journal entry (`IPM.Activity`).
+    Journal,
+    Post,
+    /// A delivery or non-delivery report.
+    Report,
+    /// A read or not-read notification.
+    ReadReceipt,
+    /// An S/MIME signed or encrypted email.
+    Smime,
+    Other(String),
+}
+
+impl MessageKind {
+    pub fn from_class(class: &str) -> Self {
+        let segments: Vec<String> = class.split('.').map(str::to_ascii_lowercase).collect();
+        let segments: Vec<&str> = segments.iter().map(String::as_str).collect();
+        match segments.as_slice() {
+            [] | [""] => Self::Note,
+            ["report", .., last] if *last == "ipnrn" || *last == "ipnnrn" => Self::ReadReceipt,
+            ["report", ..] => Self::Report,
+            ["ipm", "note", "smime", ..] => Self::Smime,
+            ["ipm"] | ["ipm", "note", ..] => Self::Note,
+            ["ipm", "appointment", ..] => Self::Appointment,
+            ["ipm", "schedule", "meeting", "request", ..] => Self::MeetingRequest,
+            ["ipm", "schedule", "meeting", "resp", ..] => Self::MeetingResponse,
+            ["ipm", "schedule", "meeting", "canceled", ..] => Self::MeetingCancel,
+            ["ipm", "contact", ..] => Self::Contact,
+            ["ipm", "distlist", ..] => Self::DistList,
+            ["ipm", "task", ..] => Self::Task,
+            ["ipm", "taskrequest", ..] => Self::TaskRequest,
+            ["ipm", "stickynote", ..] => Self::StickyNote,
+            ["ipm", "activity", ..] => Self::Journal,
+            ["ipm", "post", ..] => Self::Post,
+            _ => Self::Other(class.to_string()),
+        }
+    }
+}
+
+/// A message, typed according to its [`MessageKind`].
+#[derive(Clone, Debug)]
+pub enum Item<'a> {
+    /// An appointment, or a meeting request, response or cancellation.
+    Appointment(Box<Appointment>),
+    Contact(Box<Contact>),
+    DistList(Box<DistList>),
+    Journal(Box<JournalEntry>),
+    /// A delivery, non-delivery, read or not-read report.
+    Report(Box<Report>),
+    StickyNote(Box<StickyNote>),
+    Task(Box<Task>),
+    TaskRequest(Box<TaskRequest>),
+    /// Any kind of item without a more specific type.
+    Message(&'a EmailMessage),
+}
+
+impl EmailMessage {
+    pub fn kind(&self) -> MessageKind {
+        MessageKind::from_class(self.message_class.as_deref().unwrap_or_default())
+    }
+
+    /// The message as the item type for its kind.
+    pub fn item(&self) -> Item<'_> {
+        match self.kind() {
+            MessageKind::Appointment
+            | MessageKind::MeetingRequest
+            | MessageKind::MeetingResponse
+            | MessageKind::MeetingCancel => {
+                Item::Appointment(Box::new(Appointment::from_message(self)))
+            }
+            MessageKind::Contact => Item::Contact(Box::new(Contact::from_message(self))),
+            MessageKind::DistList => Item::DistList(Box::new(DistList::from_message(self))),
+            MessageKind::Journal => Item::Journal(Box::new(JournalEntry::from_message(self))),
+            MessageKind::Report | MessageKind::ReadReceipt => {
+                Item::Report(Box::new(Report::from_message(self)))
+            }
+            MessageKind::StickyNote => Item::StickyNote(Box::new(StickyNote::from_message(self))),
+            MessageKind::Task => Item::Task(Box::new(Task::from_message(self))),
+            MessageKind::TaskRequest => {
+                Item::TaskRequest(Box::new(TaskRequest::from_message(self)))
+            }
+            _ => Item::Message(self),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn message_classes() {
+        for (class, kind) in [
+            ("IPM.Note", MessageKind::Note),
+            ("IPM.Note.MyApp.Custom", MessageKind::Note),
+            ("ipm.appointment", MessageKind::Appointment),
+            ("IPM.Schedule.Meeting.Request", MessageKind::MeetingRequest),
+            (
+                "IPM.Schedule.Meeting.Resp.Tent",
+                MessageKind::MeetingResponse,
+            ),
+            ("IPM.Schedule.Meeting.Canceled", MessageKind::MeetingCancel),
+            ("IPM.TaskRequest.Accept", MessageKind::TaskRequest),
+            ("IPM.Activity", MessageKind::Journal),
+            ("REPORT.IPM.Note.NDR", MessageKind::Report),
+            ("REPORT.IPM.Note.IPNRN", MessageKind::ReadReceipt),
+            ("IPM.Note.SMIME.MultipartSigned", MessageKind::Smime),
+            ("", MessageKind::Note),
+            (
+                "IPM.InfoPathForm.X",
+                MessageKind::Other("IPM.InfoPathForm.X".to_string()),
+            ),
+        ] {
+            assert_eq!(MessageKind::from_class(class), kind, "{class}");
+        }
+    }
+}
@@ -0,0 +1,173 @@
+//! Journal entries (`IPM.Activity`), from the properties in MS-OXOJRNL.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{attachments::Attachment, EmailMessage, Lid};
+
+#[derive(Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq)]
+pub struct JournalEntry {
+    pub subject: String,
+    /// What kind of activity was journaled, such as `Phone call` or
+    /// `Microsoft Word` (PidLidLogType).
+    pub entry_type: Option<String>,
+    /// The localized description of the entry type (PidLidLogTypeDesc).
+    pub type_description: Option<String>,
+    pub start: Option<DateTime<Utc>>,
+    pub end: Option<DateTime<Utc>>,
+    /// PidLidLogDuration, in minutes.
+    pub duration_minutes: Option<i32>,
+    /// PidLidLogFlags.
+    pub flags: i32,
+    pub document_printed: bool,
+    pub document_saved: bool,
+    pub document_routed: bool,
+    pub document_posted: bool,
+    /// The names of the contacts the entry is linked to (PidLidContacts).
+    pub contacts: Vec<String>,
+    pub notes: Option<String>,
+    /// The document or message that was journaled, which Outlook attaches
+    /// as a shortcut or an embedded message.
+    pub attachment: Option<Attachment>,
+}
+
+impl JournalEntry {
+    pub fn from_message(message: &EmailMessage) -> Self {
+        let properties = &message.properties;
+        let flag = |lid: Lid| properties.boolean(lid).unwrap_or(false);
+        let contacts = match properties.strings(Lid::Contacts) {
+            Some(contacts) => contacts.to_vec(),
+            // Older entries only have the names joined together.
+            None => properties
+                .string(Lid::ContactLinkName)
+                .map(|names| {
+                    names
+                        .split(';')
+                        .map(str::trim)
+                        .filter(|name| !name.is_empty())
+                        .map(str::to_string)
+                        .collect()
+                })
+                .unwrap_or_default(),
+        };
+        let attachment = message
+            .attachments
+            .iter()
+            .find(|attachment| !attachment.hidden)
+            .cloned();
+        Self {
+            subject: message.subject.clone(),
+            entry_type: properties.string(Lid::LogType).map(str::to_string),
+            type_description: properties.string(Lid::LogTypeDesc).map(str::to_string),
+            start: properties.time(Lid::LogStart),
+            end: properties.time(Lid::LogEnd),
+            duration_minutes: properties.integer32(Lid::LogDuration),
+            flags: properties.integer32(Lid::LogFlags).unwrap_or(0),
+            document_printed: flag(Lid::LogDocumentPrinted),
+            document_saved: flag(Lid::LogDocumentSaved),
+            document_routed: flag(Lid::LogDocumentRouted),
+            document_posted: flag(Lid::LogDocumentPosted),
+            contacts,
+            notes: message.body.clone().filter(|body| !body.is_empty()),
+            attachment,
+        }
+    }
+
+    /// The journaled message, if the entry is for an email rather than a
+    /// document.
+    pub fn journaled_message(&self) -> Option<&EmailMessage> {
+        self.attachment.as_ref()?.embedded_message.as_deref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+    use crate::{
+        items::Item,
+        named::PropertyName,
+        test_support::{boolean, filetime, integer32, MsgBuilder},
+        PropertySet,
+    };
+
+    #[test]
+    fn journal_entry() {
+        let start = Utc.with_ymd_and_hms(2023, 5, 2, 9, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2023, 5, 2, 9, 30, 0).unwrap();
+        let mut builder = MsgBuilder::new();
+        builder.string("/", 0x001A, "IPM.Activity");
+        builder.named(&[
+            (PropertySet::Log, PropertyName::Id(0x8700)),
+            (PropertySet::Log, PropertyName::Id(0x8706)),
+            (PropertySet::Log, PropertyName::Id(0x8708)),
+            (PropertySet::Log, PropertyName::Id(0x8707)),
+            (PropertySet::Log, PropertyName::Id(0x870F)),
+            (PropertySet::Common, PropertyName::Id(0x853A)),
+        ]);
+        builder.properties(
+            "/",
+            &[
+                (0x0040, 0x8001, filetime(start)),
+                (0x0040, 0x8002, filetime(end)),
+                (0x0003, 0x8003, integer32(30)),
+                (0x000B, 0x8004, boolean(true)),
+            ],
+        );
+        builder.string("/", 0x8000, "E-mail Message");
+        let mut lengths = Vec::new();
+        for (i, name) in ["Alice", "Bob"].iter().enumerate() {
+            let value: Vec<u8> = name.encode_utf16().flat_map(u16::to_le_bytes).collect();
+            lengths.extend((value.len() as u32).to_le_bytes());
+            builder.stream(&format!("/__substg1.0_8005101F-{i:08X}"), &value);
+        }
+        builder.stream("/__substg1.0_8005101F", &lengths);
+        let attachment = "/__attach_version1.0_#00000000/";
+        builder.storage(attachment);
+        builder.properties(attachment, &[(0x0003, 0x3705, integer32(5))]);
+        builder.string(attachment, 0x3001, "Quarterly numbers");
+        let message = format!("{attachment}__substg1.0_3701000D/");
+        builder.storage(&message);
+        builder.message(&message, "Quarterly numbers", "", "See attached");
+        let msg = EmailMessage::from_bytes(&builder.build()).unwrap();
+
+        let entry = match msg.item() {
+            Item::Journal(entry) => entry,
+            item => panic!("not a journal entry: {:?}", item),
+        };
+        assert_eq!(entry.entry_type.as_deref(), Some("E-mail Message"));
+        assert_eq!(entry.start, Some(start));
+        assert_eq!(entry.end, Some(end));
+        assert_eq!(entry.duration_minutes, Some(30));
+        assert!(entry.document_saved);
+        assert!(!entry.document_printed);
+        assert_eq!(entry.contacts, ["Alice", "Bob"]);
+        assert_eq!(
+            entry.journaled_message().unwrap().subject,
+            "Quarterly numbers"
+        );
+    }
+
+    #[test]
+    fn sparse_journal_entry() {
+        let mut builder = MsgBuilder::new();
+        builder.message("/", "Phone call", "", "");
+        builder.string("/", 0x001A, "IPM.Activity");
+        builder.named(&[
+            (PropertySet::Log, PropertyName::Id(0x8706)),
+            (PropertySet::Common, PropertyName::Id(0x8586)),
+        ]);
+        // A start time of the wrong type is ignored.
+        builder.string("/", 0x8000, "yesterday");
+        builder.string("/", 0x8001, "Alice; Bob ;");
+        let msg = EmailMessage::from_bytes(&builder.build()).unwrap();
+
+        let entry = JournalEntry::from_message(&msg);
+        assert_eq!(entry.start, None);
+        assert_eq!(entry.entry_type, None);
+        assert_eq!(entry.flags, 0);
+        assert_eq!(entry.contacts, ["Alice", "Bob"]);
+        assert!(entry.journaled_message().is_none());
+    }
+}
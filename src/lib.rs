@@ -1,65 +1,111 @@
 #![allow(dead_code)]
+
+/// Trace low level parsing details. Compiles to nothing unless the `tracing`
+/// feature is enabled.
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        #[cfg(feature = "tracing")]
+        tracing::trace!($($arg)*);
+    };
+}
+
 use attachments::Attachment;
 use cfb::Entry;
 use chrono::{DateTime, Utc};
-use oxprops::property_ids::{tags::Tag, Pid};
+use diagnostics::{Diagnostic, Diagnostics, ParseOptions};
+use entry_id::EntryId;
+use links::Link;
+use named::NamedProperties;
+use properties::{read_properties_except, PropertyBag, StorageKind};
 use recipients::Recipient;
 use serde::{Deserialize, Serialize};
 use std::{
     convert::TryInto,
-    io::{Read, Seek, Write},
+    io::{Read, Seek},
     path::Path,
 };
 use uuid::Uuid;
+pub mod appointment;
 pub mod attachments;
+mod binary;
+pub mod contact;
+pub mod conversation;
+pub mod diagnostics;
+pub mod distlist;
+pub mod editor;
+pub mod entry_id;
+pub mod fingerprint;
+pub mod follow_up;
+mod ical;
+pub mod indicators;
+pub mod items;
+pub mod journal;
+pub mod links;
+pub mod meeting;
+pub mod named;
+pub mod note;
+pub mod properties;
+pub mod reader;
 pub mod recipients;
-use crate::oxprops::{property_ids::lids::Lid, property_sets::PropertySet};
+pub mod recurrence;
+pub mod report;
+pub mod rtf;
+pub mod state;
+pub mod task;
+#[cfg(test)]
+mod test_support;
+pub mod timezone;
+pub mod voting;
+pub use crate::oxprops::{
+    property_ids::{lids::Lid, names::Name, tags::Tag, Pid},
+    property_sets::PropertySet,
+};
 mod oxprops;
 
-/// Iterates up to 2048.
-struct AttachmentNameIter {
-    i: u16,
-}
-
-impl AttachmentNameIter {
-    fn new() -> Self {
-        Self { i: 0 }
-    }
-}
-
-impl Iterator for AttachmentNameIter {
-    type Item = String;
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.i >= 2048 {
-            return None;
-        }
-        let s = format!("__attach_version1.0_#0000{:04X}", self.i);
-        self.i += 1;
-        Some(s)
-    }
-}
-
-/// Iterates up to 2048.
-struct RecipientNameIter {
-    i: u16,
+/// The prefix of the names of attachment object storages.
+pub(crate) const ATTACHMENT_PREFIX: &str = "__attach_version1.0_#";
+/// The prefix of the names of recipient object storages.
+pub(crate) const RECIPIENT_PREFIX: &str = "__recip_version1.0_#";
+
+/// The names of the storages directly within `storage_path` whose names
+/// start with `prefix`, ordered by the hexadecimal ID following the prefix.
+///
+/// The IDs are usually sequential from zero, but gaps are left when objects
+/// are deleted, so the storage has to be listed rather than probed.
+pub(crate) fn sub_storages<F: Seek + Read>(
+    comp: &cfb::CompoundFile<F>,
+    storage_path: &str,
+    prefix: &str,
+) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+    let mut names: Vec<(u32, String)> = comp
+        .read_storage(storage_path)?
+        .filter(|entry| entry.is_storage())
+        .filter_map(|entry| {
+            let id = entry.name().strip_prefix(prefix)?;
+            let id = u32::from_str_radix(id, 16).ok()?;
+            Some((id, entry.name().to_string()))
+        })
+        .collect();
+    names.sort();
+    Ok(names.into_iter().map(|(_, name)| name).collect())
 }
 
-impl RecipientNameIter {
-    fn new() -> Self {
-        Self { i: 0 }
-    }
+/// The kinds of object a message holds in its own storages.
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum SubObject {
+    Recipient,
+    Attachment,
 }
 
-impl Iterator for RecipientNameIter {
-    type Item = String;
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.i >= 2048 {
-            return None;
-        }
-        let s = format!("/__recip_version1.0_#0000{:04X}", self.i);
-        self.i += 1;
-        Some(s)
-    }
+/// The property stream header of a message records a different number of
+/// recipients or attachments than there are storages for.
+#[derive(Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq)]
+pub struct CountMismatch {
+    pub object: SubObject,
+    /// The count given in the property stream header.
+    pub expected: u32,
+    /// The number of storages found.
+    pub found: u32,
 }
 
 // The top level of the file represents the entire Message object. The numbers and types of storages
@@ -100,11 +146,27 @@ pub struct EmailMessage {
     // pub hash: ObjectHash,
     pub subject: String,
     pub sender: String,
+    /// PidTagMessageClass, such as `IPM.Note`. See [`EmailMessage::kind`].
+    pub message_class: Option<String>,
+    /// The addresses replies should be sent to, where set.
+    pub reply_to: Vec<String>,
     pub attachments: Vec<Attachment>,
     pub recipients: Vec<Recipient>,
-    pub delivery_time: DateTime<Utc>,
+    /// PidTagMessageDeliveryTime, or PidTagClientSubmitTime for a message
+    /// that was sent but not delivered. Drafts and items such as contacts
+    /// and tasks have neither.
+    pub delivery_time: Option<DateTime<Utc>>,
     pub body: Option<String>,
-    // pub body_html: Option<String>,
+    pub body_html: Option<String>,
+    /// The decompressed RTF body.
+    pub body_rtf: Option<String>,
+    /// Differences between the recipient and attachment counts in the
+    /// property stream header and the storages actually present. A mismatch
+    /// suggests the file has been damaged or tampered with.
+    pub count_mismatches: Vec<CountMismatch>,
+    /// All properties of the message, other than the bodies, with named
+    /// properties resolved where they are known.
+    pub properties: PropertyBag,
 }
 
 impl EmailMessage {
@@ -116,6 +178,22 @@ impl EmailMessage {
         }
         false
     }
+    /// All of the links found in the plain text, HTML and RTF bodies.
+    pub fn links(&self) -> Vec<Link> {
+        let mut links = Vec::new();
+        if let Some(body) = &self.body {
+            links.extend(links::from_plain(body));
+        }
+        if let Some(body_html) = &self.body_html {
+            links.extend(links::from_html(body_html));
+        }
+        if let Some(body_rtf) = &self.body_rtf {
+            links.extend(links::from_rtf(body_rtf));
+        }
+        links
+    }
+    /// Read a message from a file, in lenient mode as with
+    /// [`Self::from_bytes`].
     pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, Box<dyn std::error::Error>> {
         // We will read the whole email into memory for safety. By reading the
         // whole thing into memory, we know that the library can't make any
@@ -126,114 +204,317 @@ impl EmailMessage {
         file.read_to_end(&mut buffer)?;
         Self::from_bytes(&buffer)
     }
+    /// Read a message from any seekable source without first copying it into
+    /// memory, in lenient mode as with [`Self::from_bytes`]. See
+    /// [`reader::MessageReader`] to avoid reading attachment data.
+    pub fn from_reader<F: Read + Seek>(inner: F) -> Result<Self, Box<dyn std::error::Error>> {
+        Ok(Self::from_reader_with_options(inner, &ParseOptions::default())?.0)
+    }
+    pub fn from_reader_with_options<F: Read + Seek>(
+        inner: F,
+        options: &ParseOptions,
+    ) -> Result<(Self, Diagnostics), Box<dyn std::error::Error>> {
+        reader::MessageReader::new(inner)?.message_with_options(options)
+    }
+    /// Read a message in lenient mode. Parts of the message that can't be
+    /// read, such as a malformed attachment or recipient, are left out
+    /// rather than failing the whole message. Use
+    /// [`Self::from_bytes_with_options`] to find out what was left out, or
+    /// to fail on it instead.
     pub fn from_bytes(buffer: &[u8]) -> Result<Self, Box<dyn std::error::Error>> {
-        let cursor = std::io::Cursor::new(buffer);
-        let mut comp = cfb::CompoundFile::open(cursor)?;
+        Ok(Self::from_bytes_with_options(buffer, &ParseOptions::default())?.0)
+    }
+    /// Read a message, returning any problems that were skipped over
+    /// alongside it. In strict mode the diagnostics are always empty.
+    pub fn from_bytes_with_options(
+        buffer: &[u8],
+        options: &ParseOptions,
+    ) -> Result<(Self, Diagnostics), Box<dyn std::error::Error>> {
+        Self::from_reader_with_options(std::io::Cursor::new(buffer), options)
+    }
 
+    /// Read the message stored at `storage_path`, which is either the root
+    /// storage or the storage of an embedded message, with a trailing
+    /// separator.
+    pub(crate) fn from_cfb<F: Seek + Read>(
+        comp: &mut cfb::CompoundFile<F>,
+        storage_path: &str,
+        names: &NamedProperties,
+        options: &ParseOptions,
+        diagnostics: &mut Diagnostics,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
         let mut attachments = Vec::new();
-
-        for name in AttachmentNameIter::new() {
-            if comp.exists(&name) {
-                match Attachment::from_cfb(&mut comp, name.as_str()) {
-                    Ok(attachment) => attachments.push(attachment),
-                    Err(err) => eprintln!("ERR: {:?}", err),
-                }
-            } else {
-                break;
+        let attachment_names = sub_storages(comp, storage_path, ATTACHMENT_PREFIX)?;
+        for name in &attachment_names {
+            match Attachment::from_cfb_with_options(
+                comp,
+                storage_path,
+                name,
+                names,
+                options,
+                diagnostics,
+            ) {
+                Ok(attachment) => attachments.push(attachment),
+                Err(err) => diagnostics.warn(
+                    options,
+                    Diagnostic::new(format!("{storage_path}{name}"), None, err),
+                )?,
             }
         }
 
         let mut recipients = Vec::new();
-
-        for name in RecipientNameIter::new() {
-            if comp.exists(&name) {
-                let recipient = Recipient::from_cfb(&mut comp, name)?;
-                recipients.push(recipient);
-            } else {
-                break;
+        let recipient_names = sub_storages(comp, storage_path, RECIPIENT_PREFIX)?;
+        for name in &recipient_names {
+            match Recipient::from_cfb_with_options(
+                comp,
+                storage_path,
+                name.clone(),
+                names,
+                options,
+                diagnostics,
+            ) {
+                Ok(recipient) => recipients.push(recipient),
+                Err(err) => diagnostics.warn(
+                    options,
+                    Diagnostic::new(format!("{storage_path}{name}"), None, err),
+                )?,
             }
         }
-        let subject = {
-            let mut stream = comp.open_stream("/__substg1.0_0037001F")?;
-            let buffer = {
-                let mut buffer = Vec::new();
-                stream.read_to_end(&mut buffer)?;
-                buffer
-            };
-            read(&buffer)?
+        let subject = read_string(comp, storage_path, Tag::Subject, options, diagnostics)?
+            .unwrap_or_default();
+        let sender = read_string(
+            comp,
+            storage_path,
+            Tag::SenderEmailAddress,
+            options,
+            diagnostics,
+        )?
+        .unwrap_or_default();
+        let message_class =
+            read_string(comp, storage_path, Tag::MessageClass, options, diagnostics)?;
+        let kind = if storage_path == "/" {
+            StorageKind::TopLevel
+        } else {
+            StorageKind::EmbeddedMessage
         };
-        let sender = {
-            // let mut stream = comp.open_stream("/__substg1.0_3FFA001F")?;
-            let mut stream = comp.open_stream("/__substg1.0_0C1F001F")?;
-            let buffer = {
-                let mut buffer = Vec::new();
-                stream.read_to_end(&mut buffer)?;
-                buffer
-            };
-            read(&buffer)?
+        // The bodies are already read into their own fields.
+        let bodies = [Tag::Body, Tag::Html, Tag::RtfCompressed].map(Tag::to_u16);
+        let bag = match read_properties_except(
+            comp,
+            storage_path,
+            kind,
+            names,
+            &bodies,
+            options,
+            diagnostics,
+        ) {
+            Ok(bag) => bag,
+            Err(err) => {
+                diagnostics.warn(options, Diagnostic::new(storage_path, None, err))?;
+                PropertyBag::default()
+            }
         };
+        let properties_path = format!("{storage_path}__properties_version1.0");
         let properties = {
-            let mut stream = comp.open_stream("/__properties_version1.0")?;
+            let mut stream = comp.open_stream(&properties_path)?;
             let buffer = {
                 let mut buffer = Vec::new();
                 stream.read_to_end(&mut buffer)?;
                 buffer
             };
-            parse_property_stream_header_top_level(&buffer)
+            if storage_path == "/" {
+                parse_property_stream_header_top_level(&buffer)
+            } else {
+                parse_property_stream_header_embedded(&buffer)
+            }
         };
-        let mut delivery_time = None;
-        let mut body = None;
-        for property in properties.properties.iter() {
-            if property.property_id == Pid::Tag(Tag::MessageDeliveryTime) {
-                if let PValue::Time(time) = property.value {
-                    delivery_time = Some(time);
-                }
+        let properties = match properties {
+            Ok(properties) => Some(properties),
+            Err(err) => {
+                diagnostics.warn(options, Diagnostic::new(&properties_path, None, err))?;
+                None
             }
-            if property.property_id == Pid::Tag(Tag::ClientSubmitTime) && delivery_time.is_none() {
-                if let PValue::Time(time) = property.value {
-                    delivery_time = Some(time);
-                }
+        };
+        let mut count_mismatches = Vec::new();
+        let counts = properties.iter().flat_map(|properties| {
+            [
+                (
+                    SubObject::Recipient,
+                    properties.recipient_count,
+                    recipient_names.len(),
+                ),
+                (
+                    SubObject::Attachment,
+                    properties.attachment_count,
+                    attachment_names.len(),
+                ),
+            ]
+        });
+        for (object, expected, found) in counts {
+            if expected as usize != found {
+                diagnostics.warn(
+                    options,
+                    Diagnostic::new(
+                        &properties_path,
+                        None,
+                        format!("header gives {expected} {object:?} objects, found {found}"),
+                    ),
+                )?;
+                count_mismatches.push(CountMismatch {
+                    object,
+                    expected,
+                    found: found as u32,
+                });
             }
-            if property.property_id == Pid::Tag(Tag::Body) {
-                if let PValue::String(time) = property.value {
-                    body = Some(time);
+        }
+        let delivery_time = bag
+            .time(Tag::MessageDeliveryTime)
+            .or_else(|| bag.time(Tag::ClientSubmitTime));
+        let body = read_string(comp, storage_path, Tag::Body, options, diagnostics)?;
+        let body_html = if let Some(buffer) =
+            read_stream(comp, &format!("{storage_path}__substg1.0_10130102"))
+        {
+            Some(match String::from_utf8(buffer) {
+                Ok(body_html) => body_html,
+                // Not UTF-8, so fall back to treating it as Latin-1.
+                Err(err) => err.into_bytes().into_iter().map(char::from).collect(),
+            })
+        } else {
+            read_string(comp, storage_path, Tag::Html, options, diagnostics)?
+        };
+        let rtf_path = format!("{storage_path}__substg1.0_10090102");
+        let body_rtf = match read_stream(comp, &rtf_path).map(|buffer| rtf::decompress(&buffer)) {
+            Some(Ok(rtf)) => Some(String::from_utf8_lossy(&rtf).into_owned()),
+            Some(Err(err)) => {
+                diagnostics.warn(
+                    options,
+                    Diagnostic::new(rtf_path, Some(Tag::RtfCompressed.into()), err),
+                )?;
+                None
+            }
+            None => None,
+        };
+        // Prefer the addresses in the internet headers. Otherwise only the
+        // one-off entry IDs of the reply recipients hold internet addresses,
+        // as PidTagReplyRecipientNames only has display names.
+        let headers = read_string(
+            comp,
+            storage_path,
+            Tag::TransportMessageHeaders,
+            options,
+            diagnostics,
+        )?;
+        let mut reply_to = headers
+            .as_deref()
+            .map(|headers| header_addresses(headers, "Reply-To"))
+            .unwrap_or_default();
+        if reply_to.is_empty() {
+            if let Some(data) = bag.binary(Tag::ReplyRecipientEntries) {
+                match entry_id::parse_entry_list(data) {
+                    Ok(entries) => {
+                        reply_to.extend(entries.into_iter().filter_map(|entry| match entry {
+                            EntryId::OneOff(one_off)
+                                if one_off.address_type.eq_ignore_ascii_case("SMTP") =>
+                            {
+                                Some(one_off.address)
+                            }
+                            _ => None,
+                        }))
+                    }
+                    Err(err) => diagnostics.warn(
+                        options,
+                        Diagnostic::new(
+                            format!("{storage_path}__substg1.0_004F0102"),
+                            Some(Tag::ReplyRecipientEntries.into()),
+                            err,
+                        ),
+                    )?,
                 }
             }
         }
-        let body = {
-            // let mut stream = comp.open_stream("/__substg1.0_3FFA001F")?;
-            let mut stream = comp.open_stream("/__substg1.0_1000001F")?;
-            let buffer = {
-                let mut buffer = Vec::new();
-                stream.read_to_end(&mut buffer)?;
-                buffer
-            };
-            read(&buffer)?
-        };
-        // let body_html = {
-        //     // let mut stream = comp.open_stream("/__substg1.0_3FFA001F")?;
-        //     let mut stream = comp.open_stream("/__substg1.0_1013001F")?;
-        //     let buffer = {
-        //         let mut buffer = Vec::new();
-        //         stream.read_to_end(&mut buffer)?;
-        //         buffer
-        //     };
-        //     read(&buffer)?
-        // };
-        let delivery_time = delivery_time.ok_or("no delivery time")?;
         Ok(Self {
             // hash,
             subject,
             sender,
+            message_class,
+            reply_to,
             attachments,
             recipients,
             delivery_time,
-            body: Some(body),
-            // body_html: Some(body_html),
+            body,
+            body_html,
+            body_rtf,
+            count_mismatches,
+            properties: bag,
         })
     }
 }
 
+/// Read a Unicode string property of the message at `storage_path`, or
+/// `None` if the message doesn't have it. A malformed value is recorded and
+/// also gives `None`.
+fn read_string<F: Seek + Read>(
+    comp: &mut cfb::CompoundFile<F>,
+    storage_path: &str,
+    tag: Tag,
+    options: &ParseOptions,
+    diagnostics: &mut Diagnostics,
+) -> Result<Option<String>, Box<dyn std::error::Error>> {
+    let path = format!("{storage_path}__substg1.0_{:04X}001F", tag.to_u16());
+    let buffer = match read_stream(comp, &path) {
+        Some(buffer) => buffer,
+        None => return Ok(None),
+    };
+    match read(&buffer) {
+        Ok(value) => Ok(Some(value)),
+        Err(reason) => {
+            diagnostics.warn(options, Diagnostic::new(path, Some(tag.into()), reason))?;
+            Ok(None)
+        }
+    }
+}
+
+/// The addresses in all instances of an address header such as `Reply-To`.
+fn header_addresses(headers: &str, name: &str) -> Vec<String> {
+    let mut addresses = Vec::new();
+    let mut lines = headers.lines().peekable();
+    while let Some(line) = lines.next() {
+        let value = match line.split_once(':') {
+            Some((header, value)) if header.trim().eq_ignore_ascii_case(name) => value,
+            _ => continue,
+        };
+        // Unfold any continuation lines.
+        let mut value = value.to_string();
+        while let Some(next) = lines.peek() {
+            if next.starts_with(' ') || next.starts_with('\t') {
+                value.push_str(next);
+                lines.next();
+            } else {
+                break;
+            }
+        }
+        for mailbox in value.split(',') {
+            let address = match (mailbox.rfind('<'), mailbox.rfind('>')) {
+                (Some(open), Some(close)) if open < close => &mailbox[open + 1..close],
+                _ => mailbox,
+            };
+            let address = address.trim();
+            if !address.is_empty() {
+                addresses.push(address.to_string());
+            }
+        }
+    }
+    addresses
+}
+
+/// Read the whole of a stream, if it exists.
+fn read_stream<F: Seek + Read>(comp: &mut cfb::CompoundFile<F>, path: &str) -> Option<Vec<u8>> {
+    let mut stream = comp.open_stream(path).ok()?;
+    let mut buffer = Vec::new();
+    stream.read_to_end(&mut buffer).ok()?;
+    Some(buffer)
+}
+
 struct StringStream {
     buffer: Vec<u8>,
 }
@@ -372,16 +653,6 @@ fn parse_entry(
             PropertyId::String(string_stream.get(num as usize).unwrap())
         }
     };
-    println!("    PropertyEntry[{property_index}]: Id: {identifier:?} PropertyIndex: {property_index} GuidIndex: {guid_index:?}");
-    println!(
-        "        {:02X} {:02X} {:02X} {:02X}",
-        data_slice[0], data_slice[1], data_slice[2], data_slice[3]
-    );
-    println!(
-        "        {:02X} {:02X} {:02X} {:02X}",
-        data_slice[4], data_slice[5], data_slice[6], data_slice[7]
-    );
-    std::io::stdout().flush().unwrap();
     let property_set = match guid_index {
         GuidIndex::PsMapi => PropertySet::PsMapi,
         GuidIndex::PublicStrings => PropertySet::PublicStrings,
@@ -390,9 +661,13 @@ fn parse_entry(
             PropertySet::from_uuid(guid)
         }
     };
-    print!("        PropertySet: {:?}", property_set);
-    std::io::stdout().flush().unwrap();
-    println!();
+    trace!(
+        property_index,
+        ?identifier,
+        ?guid_index,
+        ?property_set,
+        "named property entry"
+    );
     let stream_id = match identifier {
         PropertyId::Number(n) => 0x1000 + ((n as u16) ^ (guid_index.as_num() << 1)) % 0x1F,
         PropertyId::String(_s) => {
@@ -405,7 +680,7 @@ fn parse_entry(
     };
     let hex_id: u32 = ((stream_id as u32) << 16) | 0x00000102;
     let stream_name = format!("__substg1.0_{:X}", hex_id);
-    println!("        stream_name: {}", stream_name);
+    trace!(property_index, stream_name = %stream_name, "named property stream");
     PropertyEntry {
         property_set,
         property_index,
@@ -582,7 +857,7 @@ mod tests {
                 }
                 println!();
             } else if s.name().starts_with("__attach_version1.0_") {
-                attachments.push(Attachment::from_cfb(&mut comp, s.name()));
+                attachments.push(Attachment::from_cfb(&mut comp, "/", s.name()));
             } else if s.name().starts_with("__recip_version1.0_") {
                 // todo!("recip")
             }
@@ -620,13 +895,424 @@ mod tests {
         panic!("end");
     }
 
-    #[ignore]
     #[test]
-    fn attach_name_iter() {
-        let iter = AttachmentNameIter::new();
-        for s in iter {
-            println!("{}", s);
+    fn embedded_attachment_indicators() {
+        use test_support::{integer32, MsgBuilder};
+        let mut builder = MsgBuilder::new();
+        builder.string(
+            "/",
+            0x007D,
+            "From: a@example.com\r\nReply-To: Someone\r\n <reply@example.net>\r\n",
+        );
+        builder.storage("/__attach_version1.0_#00000000");
+        let attachment = "/__attach_version1.0_#00000000/";
+        builder.properties(attachment, &[(0x0003, 0x3705, integer32(5))]);
+        builder.string(attachment, 0x3001, "Forwarded");
+        builder.storage("/__attach_version1.0_#00000000/__substg1.0_3701000D");
+        let embedded = "/__attach_version1.0_#00000000/__substg1.0_3701000D/";
+        builder.message(
+            embedded,
+            "Inner",
+            "inner@example.com",
+            "See http://10.0.0.1/x",
+        );
+        builder.storage(&format!("{embedded}__attach_version1.0_#00000000"));
+        let inner = format!("{embedded}__attach_version1.0_#00000000/");
+        builder.properties(&inner, &[(0x0003, 0x3705, integer32(1))]);
+        builder.string(&inner, 0x3707, "invoice.pdf");
+        builder.binary(&inner, 0x3701, b"MZ\x90\x00");
+        let msg = EmailMessage::from_bytes(&builder.build()).unwrap();
+
+        let indicators = msg.indicators();
+        assert_eq!(indicators.reply_to, vec!["reply@example.net".to_string()]);
+        assert_eq!(indicators.urls.len(), 1);
+        assert_eq!(indicators.attachments.len(), 1);
+        let attachment = &indicators.attachments[0];
+        assert_eq!(attachment.container, vec!["Forwarded".to_string()]);
+        assert_eq!(attachment.fingerprint.name, "invoice.pdf");
+        assert!(attachment.fingerprint.type_mismatch);
+        let json = serde_json::to_value(&indicators).unwrap();
+        assert_eq!(json["attachments"][0]["extension"], "pdf");
+    }
+
+    #[test]
+    fn short_property_stream_headers() {
+        let header = parse_property_stream_header_top_level(&[0; 32]).unwrap();
+        assert!(header.properties.is_empty());
+        assert!(parse_property_stream_header_top_level(&[0; 31]).is_err());
+        assert!(parse_property_stream_header_embedded(&[0; 10]).is_err());
+        let mut data = vec![0; 24];
+        data[16] = 2;
+        data.extend([0; 20]);
+        let header = parse_property_stream_header_embedded(&data).unwrap();
+        assert_eq!(header.recipient_count, 2);
+        assert_eq!(header.properties.len(), 1);
+    }
+
+    #[test]
+    fn embedded_message_without_times() {
+        use diagnostics::ParseMode;
+        use test_support::{integer32, MsgBuilder};
+        let build = |properties: Option<&[u8]>| {
+            let mut builder = MsgBuilder::new();
+            builder.storage("/__attach_version1.0_#00000000");
+            let attachment = "/__attach_version1.0_#00000000/";
+            builder.properties(attachment, &[(0x0003, 0x3705, integer32(5))]);
+            builder.string(attachment, 0x3001, "Draft");
+            builder.storage("/__attach_version1.0_#00000000/__substg1.0_3701000D");
+            let embedded = "/__attach_version1.0_#00000000/__substg1.0_3701000D/";
+            builder.message(embedded, "Draft", "", "Not sent yet");
+            if let Some(properties) = properties {
+                builder.stream(&format!("{embedded}__properties_version1.0"), properties);
+            }
+            builder.counts("/", 0, 1);
+            builder.build()
+        };
+        let strict = ParseOptions {
+            mode: ParseMode::Strict,
+        };
+
+        // Neither message has a delivery or submit time.
+        let (msg, diagnostics) =
+            EmailMessage::from_bytes_with_options(&build(None), &strict).unwrap();
+        assert!(diagnostics.is_empty());
+        assert_eq!(msg.delivery_time, None);
+        let embedded = msg.attachments[0].embedded_message.as_ref().unwrap();
+        assert_eq!(embedded.subject, "Draft");
+        assert_eq!(embedded.delivery_time, None);
+
+        // A property stream too short for its header is skipped over.
+        let data = build(Some(&[0; 10]));
+        let (msg, diagnostics) =
+            EmailMessage::from_bytes_with_options(&data, &Default::default()).unwrap();
+        assert!(diagnostics.iter().any(|diagnostic| diagnostic
+            .path
+            .ends_with("3701000D/__properties_version1.0")));
+        let embedded = msg.attachments[0].embedded_message.as_ref().unwrap();
+        assert_eq!(embedded.body.as_deref(), Some("Not sent yet"));
+        assert!(EmailMessage::from_bytes_with_options(&data, &strict).is_err());
+    }
+
+    #[test]
+    fn ole_attachment_is_not_a_message() {
+        use attachments::AttachMethod;
+        use diagnostics::ParseMode;
+        use test_support::{integer32, MsgBuilder};
+        let mut builder = MsgBuilder::new();
+        builder.storage("/__attach_version1.0_#00000000");
+        let attachment = "/__attach_version1.0_#00000000/";
+        builder.properties(attachment, &[(0x0003, 0x3705, integer32(6))]);
+        builder.string(attachment, 0x3707, "chart.xls");
+        builder.storage("/__attach_version1.0_#00000000/__substg1.0_3701000D");
+        let storage = "/__attach_version1.0_#00000000/__substg1.0_3701000D/";
+        builder.stream(&format!("{storage}\u{1}Ole"), &[1, 0, 0, 2]);
+        builder.stream(&format!("{storage}Workbook"), b"\x09\x08");
+        builder.counts("/", 0, 1);
+        let data = builder.build();
+        for mode in [ParseMode::Lenient, ParseMode::Strict] {
+            let options = ParseOptions { mode };
+            let (msg, _) = EmailMessage::from_bytes_with_options(&data, &options).unwrap();
+            assert_eq!(msg.attachments.len(), 1);
+            assert_eq!(msg.attachments[0].name, "chart.xls");
+            assert_eq!(msg.attachments[0].method, AttachMethod::Storage);
+            assert!(msg.attachments[0].embedded_message.is_none());
+        }
+        let mut reader = reader::MessageReader::new(std::io::Cursor::new(data)).unwrap();
+        assert!(!reader.attachments().unwrap()[0].is_embedded_message);
+    }
+
+    #[test]
+    fn reply_to_from_entries() {
+        use entry_id::OneOffEntryId;
+        use test_support::MsgBuilder;
+        let entries: Vec<Vec<u8>> = [("SMTP", "reply@example.net"), ("FAX", "+1 555 0100")]
+            .iter()
+            .map(|(address_type, address)| {
+                EntryId::OneOff(OneOffEntryId {
+                    display_name: "Replies".to_string(),
+                    address_type: address_type.to_string(),
+                    address: address.to_string(),
+                    unicode: true,
+                    no_rich_info: false,
+                })
+                .to_bytes()
+            })
+            .collect();
+        let mut list = (entries.len() as u32).to_le_bytes().to_vec();
+        list.extend(0_u32.to_le_bytes());
+        for entry in &entries {
+            list.extend((entry.len() as u32).to_le_bytes());
+            list.extend(entry);
+            list.resize(list.len().next_multiple_of(4), 0);
         }
+        let mut builder = MsgBuilder::new();
+        builder.string("/", 0x0050, "Replies; Someone Else");
+        builder.binary("/", 0x004F, &list);
+        let msg = EmailMessage::from_bytes(&builder.build()).unwrap();
+        assert_eq!(msg.reply_to, ["reply@example.net"]);
+
+        let mut builder = MsgBuilder::new();
+        builder.string("/", 0x0050, "Replies");
+        let msg = EmailMessage::from_bytes(&builder.build()).unwrap();
+        assert!(msg.reply_to.is_empty());
+    }
+
+    #[test]
+    fn malformed_attachment_property() {
+        use diagnostics::ParseMode;
+        use test_support::{integer32, MsgBuilder};
+        let mut builder = MsgBuilder::new();
+        builder.storage("/__attach_version1.0_#00000000");
+        let attachment = "/__attach_version1.0_#00000000/";
+        builder.properties(attachment, &[(0x0003, 0x3705, integer32(1))]);
+        builder.string(attachment, 0x3707, "report.pdf");
+        // A UTF-16 string with an odd number of bytes.
+        builder.stream(&format!("{attachment}__substg1.0_370E001F"), b"app");
+        builder.counts("/", 0, 1);
+        let bytes = builder.build();
+
+        let (msg, diagnostics) =
+            EmailMessage::from_bytes_with_options(&bytes, &ParseOptions::default()).unwrap();
+        assert_eq!(msg.attachments[0].name, "report.pdf");
+        assert_eq!(msg.attachments[0].mime_type, None);
+        let diagnostic = diagnostics.iter().next().unwrap();
+        assert_eq!(diagnostic.path, format!("{attachment}__substg1.0_370E001F"));
+        assert_eq!(diagnostic.property, Some(Pid::Tag(Tag::AttachMimeTag)));
+
+        let strict = ParseOptions {
+            mode: ParseMode::Strict,
+        };
+        assert!(EmailMessage::from_bytes_with_options(&bytes, &strict).is_err());
+    }
+
+    #[test]
+    fn attachment_metadata() {
+        use attachments::{AttachFlags, AttachMethod};
+        use test_support::{filetime, integer32, MsgBuilder};
+        let created = chrono::TimeZone::with_ymd_and_hms(&Utc, 2021, 1, 2, 3, 4, 5).unwrap();
+        let mut builder = MsgBuilder::new();
+        builder.storage("/__attach_version1.0_#00000000");
+        let attachment = "/__attach_version1.0_#00000000/";
+        builder.properties(
+            attachment,
+            &[
+                (0x0003, 0x3705, integer32(1)),
+                (0x0003, 0x3714, integer32(4)),
+                (0x0003, 0x370B, integer32(-1)),
+                (0x0003, 0x0E20, integer32(1234)),
+                (0x0040, 0x3007, filetime(created)),
+            ],
+        );
+        builder.string(attachment, 0x3704, "IMAGE~1.PNG");
+        builder.string(attachment, 0x3707, "image001.png");
+        builder.string(attachment, 0x370E, "image/png");
+        builder.string(attachment, 0x3712, "image001.png@01D8");
+        builder.binary(attachment, 0x3701, b"\x89PNG\r\n\x1A\n");
+        let msg = EmailMessage::from_bytes(&builder.build()).unwrap();
+
+        let attachment = &msg.attachments[0];
+        assert_eq!(attachment.name, "image001.png");
+        assert_eq!(attachment.filename.as_deref(), Some("IMAGE~1.PNG"));
+        assert_eq!(attachment.method, AttachMethod::ByValue);
+        assert_eq!(attachment.flags, AttachFlags::RENDERED_IN_BODY);
+        assert_eq!(attachment.mime_type.as_deref(), Some("image/png"));
+        assert_eq!(attachment.content_id.as_deref(), Some("image001.png@01D8"));
+        assert_eq!(attachment.rendering_position, Some(-1));
+        assert_eq!(attachment.size, Some(1234));
+        assert_eq!(attachment.creation_time, Some(created));
+        assert_eq!(attachment.data.as_ref().unwrap().bytes.len(), 8);
+        assert!(attachment.is_inline());
+    }
+
+    #[test]
+    fn lazy_attachment_data() {
+        use test_support::{integer32, MsgBuilder};
+        let data: Vec<u8> = (0..10_000_u32).map(|n| n as u8).collect();
+        let mut builder = MsgBuilder::new();
+        builder.storage("/__attach_version1.0_#00000000");
+        let attachment = "/__attach_version1.0_#00000000/";
+        builder.properties(attachment, &[(0x0003, 0x3705, integer32(1))]);
+        builder.string(attachment, 0x3707, "big.bin");
+        builder.binary(attachment, 0x3701, &data);
+        let mut reader = reader::MessageReader::new(std::io::Cursor::new(builder.build())).unwrap();
+
+        let entries = reader.attachments().unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].attachment.name, "big.bin");
+        assert!(entries[0].attachment.data.is_none());
+        assert!(entries[0]
+            .attachment
+            .properties
+            .get(Tag::AttachDataBinary)
+            .is_none());
+        assert_eq!(entries[0].data_len, Some(10_000));
+        let mut read_data = Vec::new();
+        reader
+            .attachment_data(&entries[0])
+            .unwrap()
+            .read_to_end(&mut read_data)
+            .unwrap();
+        assert_eq!(read_data, data);
+    }
+
+    #[test]
+    fn sub_storages_with_gaps() {
+        use test_support::{integer32, MsgBuilder};
+        let mut builder = MsgBuilder::new();
+        for (id, name) in [(0xA, "c.txt"), (0x0, "a.txt"), (0x2, "b.txt")] {
+            let attachment = format!("/__attach_version1.0_#{id:08X}/");
+            builder.storage(&attachment);
+            builder.properties(&attachment, &[(0x0003, 0x3705, integer32(1))]);
+            builder.string(&attachment, 0x3707, name);
+        }
+        builder.storage("/__recip_version1.0_#00000001");
+        builder.string("/__recip_version1.0_#00000001/", 0x39FE, "to@example.com");
+        builder.counts("/", 1, 2);
+        let bytes = builder.build();
+        let msg = EmailMessage::from_bytes(&bytes).unwrap();
+
+        let names: Vec<_> = msg.attachments.iter().map(|a| a.name.as_str()).collect();
+        assert_eq!(names, ["a.txt", "b.txt", "c.txt"]);
+        assert_eq!(msg.recipients.len(), 1);
+        assert_eq!(msg.recipients[0].address, "to@example.com");
+        assert_eq!(
+            msg.count_mismatches,
+            vec![CountMismatch {
+                object: SubObject::Attachment,
+                expected: 2,
+                found: 3,
+            }]
+        );
+
+        let mut reader = reader::MessageReader::new(std::io::Cursor::new(bytes.clone())).unwrap();
+        assert_eq!(reader.attachments().unwrap().len(), 3);
+    }
+
+    #[test]
+    fn message_kind() {
+        use items::{Item, MessageKind};
+        use test_support::MsgBuilder;
+        let msg = EmailMessage::from_bytes(&MsgBuilder::new().build()).unwrap();
+        assert_eq!(msg.message_class, None);
+        assert_eq!(msg.kind(), MessageKind::Note);
+
+        let mut builder = MsgBuilder::new();
+        builder.string("/", 0x001A, "IPM.Schedule.Meeting.Request");
+        let msg = EmailMessage::from_bytes(&builder.build()).unwrap();
+        assert_eq!(msg.kind(), MessageKind::MeetingRequest);
+        assert!(matches!(msg.item(), Item::Appointment(_)));
+    }
+
+    #[test]
+    fn lenient_diagnostics() {
+        use diagnostics::ParseMode;
+        use test_support::{integer32, MsgBuilder};
+        let mut builder = MsgBuilder::new();
+        // An attachment without a name and a recipient without an address.
+        builder.storage("/__attach_version1.0_#00000000");
+        builder.properties(
+            "/__attach_version1.0_#00000000/",
+            &[(0x0003, 0x3705, integer32(1))],
+        );
+        builder.storage("/__recip_version1.0_#00000000");
+        builder.binary("/", 0x1009, b"not compressed rtf");
+        builder.counts("/", 1, 1);
+        let bytes = builder.build();
+
+        let lenient = ParseOptions::default();
+        let (msg, diagnostics) = EmailMessage::from_bytes_with_options(&bytes, &lenient).unwrap();
+        assert!(msg.attachments.is_empty());
+        assert!(msg.recipients.is_empty());
+        assert!(msg.body_rtf.is_none());
+        let paths: Vec<_> = diagnostics.iter().map(|d| d.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            [
+                "/__attach_version1.0_#00000000",
+                "/__recip_version1.0_#00000000",
+                "/__substg1.0_10090102",
+            ]
+        );
+        assert_eq!(
+            diagnostics.iter().last().unwrap().property,
+            Some(Pid::Tag(Tag::RtfCompressed))
+        );
+
+        let strict = ParseOptions {
+            mode: ParseMode::Strict,
+        };
+        let err = EmailMessage::from_bytes_with_options(&bytes, &strict).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "/__attach_version1.0_#00000000: attachment has no name"
+        );
+    }
+
+    #[test]
+    fn missing_optional_strings() {
+        use diagnostics::ParseMode;
+        use test_support::{integer32, MsgBuilder};
+        let mut builder = MsgBuilder::new();
+        builder.storage("/__attach_version1.0_#00000000");
+        let attachment = "/__attach_version1.0_#00000000/";
+        builder.properties(attachment, &[(0x0003, 0x3705, integer32(5))]);
+        builder.string(attachment, 0x3001, "HTML only");
+        builder.storage("/__attach_version1.0_#00000000/__substg1.0_3701000D");
+        let embedded = "/__attach_version1.0_#00000000/__substg1.0_3701000D/";
+        builder.properties(embedded, &[]);
+        builder.string(embedded, 0x1013, "<p>Hello</p>");
+        builder.counts("/", 0, 1);
+        let strict = ParseOptions {
+            mode: ParseMode::Strict,
+        };
+        let (msg, diagnostics) =
+            EmailMessage::from_bytes_with_options(&builder.build(), &strict).unwrap();
+        assert!(diagnostics.is_empty());
+        let embedded = msg.attachments[0].embedded_message.as_ref().unwrap();
+        assert_eq!(embedded.body, None);
+        assert_eq!(embedded.body_html.as_deref(), Some("<p>Hello</p>"));
+        assert_eq!(embedded.subject, "");
+    }
+
+    #[test]
+    fn malformed_named_properties() {
+        use diagnostics::ParseMode;
+        use test_support::MsgBuilder;
+        let mut builder = MsgBuilder::new();
+        builder.storage("/__nameid_version1.0");
+        // A named property without a GUID.
+        builder.stream("/__nameid_version1.0/__substg1.0_00030102", &[0; 8]);
+        builder.storage("/__recip_version1.0_#00000000/");
+        builder.properties("/__recip_version1.0_#00000000/", &[]);
+        builder.string("/__recip_version1.0_#00000000/", 0x39FE, "rita@example.com");
+        builder.storage("/__attach_version1.0_#00000000/");
+        builder.properties("/__attach_version1.0_#00000000/", &[]);
+        builder.string("/__attach_version1.0_#00000000/", 0x3707, "notes.txt");
+        let bytes = builder.build();
+        let strict = ParseOptions {
+            mode: ParseMode::Strict,
+        };
+        let (msg, diagnostics) =
+            EmailMessage::from_bytes_with_options(&bytes, &ParseOptions::default()).unwrap();
+        assert_eq!(msg.subject, "Subject");
+        assert_eq!(
+            diagnostics.iter().next().unwrap().reason,
+            "named property has no GUID"
+        );
+        assert!(EmailMessage::from_bytes_with_options(&bytes, &strict).is_err());
+
+        let mut reader = reader::MessageReader::new(std::io::Cursor::new(bytes.clone())).unwrap();
+        assert_eq!(reader.message().unwrap().subject, "Subject");
+        assert!(reader.message_with_options(&strict).is_err());
+
+        let mut comp = cfb::CompoundFile::open(std::io::Cursor::new(&bytes)).unwrap();
+        let recipient =
+            Recipient::from_cfb(&mut comp, "/", "__recip_version1.0_#00000000".to_string())
+                .unwrap();
+        assert_eq!(recipient.address, "rita@example.com");
+        let attachment =
+            Attachment::from_cfb(&mut comp, "/", "__attach_version1.0_#00000000").unwrap();
+        assert_eq!(attachment.long_filename.as_deref(), Some("notes.txt"));
     }
 
     #[test]
@@ -665,12 +1351,16 @@ fn parse_property_stream_top_level<F: Seek + Read>(
     };
     let property_stream = PropertyStream::new(data.clone());
     // Everything after this is pre-parsing.
-    println!("  other properties");
-    let properties = parse_property_stream_header_top_level(&data);
-    for property in properties.properties.iter() {
-        println!(
-            "    0x{:04X} {property:?}",
-            property.property_id.to_u16().unwrap()
+    #[cfg(feature = "tracing")]
+    for property in parse_property_stream_header_top_level(&data)
+        .map(|header| header.properties)
+        .unwrap_or_default()
+    {
+        tracing::trace!(
+            stream = %properties_path,
+            property_id = property.property_id.to_u16(),
+            value = ?property.value,
+            "fixed length property"
         );
     }
     property_stream
@@ -703,7 +1393,7 @@ fn parse_property_mappings(
     if let Ok(entries) = comp.read_storage("/__nameid_version1.0") {
         let entries: Vec<Entry> = entries.collect();
         for entry in entries {
-            println!("{}", entry.path().display());
+            trace!(stream = %entry.path().display(), "named property mapping stream");
             if entry.path().as_os_str() == "/__nameid_version1.0\\__substg1.0_00020102"
                 || entry.path().as_os_str() == "/__nameid_version1.0\\__substg1.0_00030102"
                 || entry.path().as_os_str() == "/__nameid_version1.0\\__substg1.0_00040102"
@@ -722,10 +1412,11 @@ fn parse_property_mappings(
                     buffer
                 };
                 // These are the property mappings (exlcuding the 3 streams already deal with)
-                let len = data.len();
-                let name = entry.name();
-                // println!("named property mapping (len = {len}): {name} - {identifier:?} - {index_kind:?}");
-                println!("  NamedPropertyMapping (len = {len}): {name}");
+                trace!(
+                    stream = entry.name(),
+                    len = data.len(),
+                    "named property mapping"
+                );
                 let mut data_slice = data.as_slice();
                 let mut n = 0;
                 loop {
@@ -794,7 +1485,6 @@ fn parse_property_mappings(
                         }
                     };
                     let hex_id: u32 = ((stream_id as u32) << 16) | 0x00000102;
-                    println!("    stream_id: {stream_id:04X}");
                     let stream_name = format!("__substg1.0_{:X}", hex_id);
                     let property_id = 0x8000 + property_index;
                     let property_name = identifier;
@@ -803,22 +1493,17 @@ fn parse_property_mappings(
                         property_name,
                         property_id,
                     };
-                    let name_string = match property_name {
-                        PropertyMappingIdentifier::Number(n) => {
-                            format!("id:  0x{n:08X}")
-                        }
-                        PropertyMappingIdentifier::String(crc) => {
-                            let n = u32::from_le_bytes(crc);
-                            format!("crc: 0x{n:08X}")
-                        }
-                    };
-                    let entry_offset = ((property_id - 0x8000) as usize) * 8;
-                    println!("    {name_string} -> 0x{property_id:02X} {property_mapping:?} entry_offset: {entry_offset}");
-                    if let PropertyMappingIdentifier::Number(n) = property_name {
-                        if let Some(lid) = Lid::from_u32(n) {
-                            println!("    LID: {lid:?}");
-                        }
-                    }
+                    trace!(
+                        stream = %stream_name,
+                        property_id,
+                        offset = (property_index as usize) * 8,
+                        ?property_mapping,
+                        lid = ?match property_name {
+                            PropertyMappingIdentifier::Number(n) => Lid::from_u32(n),
+                            PropertyMappingIdentifier::String(_) => None,
+                        },
+                        "named property mapping entry"
+                    );
                     if let Some(entry_data) = named_property_mapping
                         .entry_stream
                         .get_bytes(property_index as usize)
@@ -860,75 +1545,42 @@ fn parse_named_property_mapping(
                 stream.read_to_end(&mut buffer).unwrap();
                 buffer
             };
-            let len = data.len();
-            println!("GuidStream, len = {len}");
-            let guid_stream = GuidStream {
-                buffer: data.clone(),
-            };
             // Everything after this is pre-parsing
-            let mut data_slice = data.as_slice();
-            let mut n = 0;
-            loop {
-                if data_slice.is_empty() {
-                    break;
-                }
-                let guid: Uuid = parse_guid(data_slice);
-                println!("    GUID[{}]: {}", n, guid);
-                data_slice = &data_slice[16..];
-                n += 1;
+            #[cfg(feature = "tracing")]
+            for (index, guid) in data.chunks_exact(16).map(parse_guid).enumerate() {
+                tracing::trace!(index, guid = %guid, offset = index * 16, "guid stream entry");
             }
-            guid_stream
+            GuidStream { buffer: data }
         } else {
             panic!("no guid stream")
         };
 
-    let string_stream = if let Ok(mut stream) =
-        comp.open_stream("/__nameid_version1.0\\__substg1.0_00040102")
-    {
-        let data = {
-            let mut buffer = Vec::new();
-            stream.read_to_end(&mut buffer).unwrap();
-            buffer
-        };
-        let string_stream = StringStream {
-            buffer: data.clone(),
-        };
-        // Everything after this is pre-parsing
-        let len = data.len();
-        println!("StringStream, len = {len}");
-        let mut data_slice = data.as_slice();
-        let mut n = 0;
-        loop {
-            if data_slice.is_empty() {
-                break;
-            }
-            let length =
-                u32::from_le_bytes([data_slice[0], data_slice[1], data_slice[2], data_slice[3]])
-                    as usize;
-            if length > data_slice.len() {
-                // data_slice = &data_slice[1..];
-                // continue;
-                break;
-            }
-            data_slice = &data_slice[4..];
-            print!("    StringEntry[{}]({})", n, length);
-            std::io::stdout().flush().unwrap();
-            if let Ok(recip0) = read(&data_slice[0..length]) {
-                print!(": {}", recip0);
-            }
-            println!();
-            let next_offset = length + length % 4;
-            // println!("next_offset: {}", next_offset);
-            if next_offset > data_slice.len() {
-                break;
+    let string_stream =
+        if let Ok(mut stream) = comp.open_stream("/__nameid_version1.0\\__substg1.0_00040102") {
+            let data = {
+                let mut buffer = Vec::new();
+                stream.read_to_end(&mut buffer).unwrap();
+                buffer
+            };
+            // Everything after this is pre-parsing
+            #[cfg(feature = "tracing")]
+            {
+                let mut offset = 0;
+                while offset + 4 <= data.len() {
+                    let length =
+                        u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap()) as usize;
+                    let value = match data.get(offset + 4..offset + 4 + length) {
+                        Some(value) => read(value),
+                        None => break,
+                    };
+                    tracing::trace!(offset, length, ?value, "string stream entry");
+                    offset += 4 + length + length % 4;
+                }
             }
-            data_slice = &data_slice[next_offset..];
-            n += 1;
-        }
-        string_stream
-    } else {
-        panic!("no string stream")
-    };
+            StringStream { buffer: data }
+        } else {
+            panic!("no string stream")
+        };
 
     let entry_stream =
         if let Ok(mut stream) = comp.open_stream("/__nameid_version1.0\\__substg1.0_00030102") {
@@ -937,12 +1589,10 @@ fn parse_named_property_mapping(
                 stream.read_to_end(&mut buffer).unwrap();
                 buffer
             };
-            let entry_stream = EntryStream::new(data.clone());
             // Everything after this is pre-parsing
-            let len = data.len();
-            println!("EntryStream, len = {len}");
+            #[cfg(feature = "tracing")]
             parse_properties(&string_stream, &guid_stream, data.as_slice());
-            entry_stream
+            EntryStream::new(data)
         } else {
             panic!("no entry stream")
         };
@@ -983,31 +1633,11 @@ fn parse_properties(
     guid_stream: &GuidStream,
     data_slice: &[u8],
 ) -> Vec<PropertyEntry> {
-    let mut data_slice = data_slice;
-    let mut n = 0;
     let mut properties = Vec::new();
-    loop {
-        print!("    [{}]", n * 8);
-        let property = parse_entry(
-            string_stream,
-            guid_stream,
-            [
-                data_slice[0],
-                data_slice[1],
-                data_slice[2],
-                data_slice[3],
-                data_slice[4],
-                data_slice[5],
-                data_slice[6],
-                data_slice[7],
-            ],
-        );
+    for entry in data_slice.chunks_exact(8) {
+        trace!(offset = properties.len() * 8, "entry stream entry");
+        let property = parse_entry(string_stream, guid_stream, entry.try_into().unwrap());
         properties.push(property);
-        data_slice = &data_slice[8..];
-        if data_slice.is_empty() {
-            break;
-        }
-        n += 1;
     }
     properties
 }
@@ -1047,91 +1677,53 @@ pub struct Properties {
     properties: Vec<FixedLengthPropertyEntry>,
 }
 
-fn parse_property_stream_header_top_level(data_slice: &[u8]) -> TopProperties {
-    let mut data_slice = data_slice;
+fn parse_property_stream_header_top_level(
+    data_slice: &[u8],
+) -> Result<TopProperties, &'static str> {
+    // The header ends with 8 more reserved bytes than that of an embedded
+    // message.
+    parse_message_property_stream(data_slice, 32)
+}
+
+/// Embedded messages have the same header as the top level, but without the
+/// second reserved field.
+fn parse_property_stream_header_embedded(data_slice: &[u8]) -> Result<TopProperties, &'static str> {
+    parse_message_property_stream(data_slice, 24)
+}
+
+fn parse_message_property_stream(
+    data_slice: &[u8],
+    header_len: usize,
+) -> Result<TopProperties, &'static str> {
+    let header = data_slice
+        .get(..header_len)
+        .ok_or("property stream shorter than its header")?;
     // Ignore the first 8 bytes as required by spec.
-    let _reserved1 = &data_slice[0..8];
-    let next_recipient_id = u32::from_le_bytes(data_slice[8..12].try_into().unwrap());
-    let next_attachment_id = u32::from_le_bytes(data_slice[12..16].try_into().unwrap());
-    let recipient_count = u32::from_le_bytes(data_slice[16..20].try_into().unwrap());
-    let attachment_count = u32::from_le_bytes(data_slice[20..24].try_into().unwrap());
-    // Ignore the first last bytes as required by spec.
-    let _reserved2 = &data_slice[24..32];
-    data_slice = &data_slice[32..];
-    let mut n = 0;
-    let mut properties = Vec::new();
-    loop {
-        let property = parse_fixed_length_property_entry([
-            data_slice[0],
-            data_slice[1],
-            data_slice[2],
-            data_slice[3],
-            data_slice[4],
-            data_slice[5],
-            data_slice[6],
-            data_slice[7],
-            data_slice[8],
-            data_slice[9],
-            data_slice[10],
-            data_slice[11],
-            data_slice[12],
-            data_slice[13],
-            data_slice[14],
-            data_slice[15],
-        ]);
-        properties.push(property);
-        data_slice = &data_slice[16..];
-        if data_slice.is_empty() {
-            break;
-        }
-        n += 1;
-    }
-    TopProperties {
-        next_recipient_id,
-        next_attachment_id,
-        recipient_count,
-        attachment_count,
-        properties,
-    }
+    let field = |i: usize| u32::from_le_bytes(header[8 + i * 4..12 + i * 4].try_into().unwrap());
+    Ok(TopProperties {
+        next_recipient_id: field(0),
+        next_attachment_id: field(1),
+        recipient_count: field(2),
+        attachment_count: field(3),
+        properties: parse_property_entries(&data_slice[header_len..]),
+    })
 }
 
 fn parse_property_stream_header_other(data_slice: &[u8]) -> Option<Properties> {
-    let mut data_slice = data_slice;
-    if data_slice.len() < 8 {
-        return None;
-    }
     // Ignore the first 8 bytes as required by spec.
-    let _reserved1 = &data_slice[0..8];
-    data_slice = &data_slice[8..];
-    let mut n = 0;
-    let mut properties = Vec::new();
-    loop {
-        let property = parse_fixed_length_property_entry([
-            data_slice[0],
-            data_slice[1],
-            data_slice[2],
-            data_slice[3],
-            data_slice[4],
-            data_slice[5],
-            data_slice[6],
-            data_slice[7],
-            data_slice[8],
-            data_slice[9],
-            data_slice[10],
-            data_slice[11],
-            data_slice[12],
-            data_slice[13],
-            data_slice[14],
-            data_slice[15],
-        ]);
-        properties.push(property);
-        data_slice = &data_slice[16..];
-        if data_slice.is_empty() {
-            break;
-        }
-        n += 1;
-    }
-    Some(Properties { properties })
+    let entries = data_slice.get(8..)?;
+    Some(Properties {
+        properties: parse_property_entries(entries),
+    })
+}
+
+/// Parse the 16 byte property entries that follow the header of a property
+/// stream, ignoring any partial entry at the end.
+fn parse_property_entries(data_slice: &[u8]) -> Vec<FixedLengthPropertyEntry> {
+    data_slice
+        .chunks_exact(16)
+        .map(|entry| parse_fixed_length_property_entry(entry.try_into().unwrap()))
+        .collect()
 }
 
 bitflags::bitflags! {
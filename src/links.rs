@@ -0,0 +1,656 @@
+//! Extraction of links from the plain text, HTML and RTF bodies of a message,
+//! along with some simple indicators that a link may be a phishing attempt.
+
+use serde::{Deserialize, Serialize};
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use crate::rtf;
+
+/// The body a link was found in.
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum BodySource {
+    Plain,
+    Html,
+    Rtf,
+}
+
+/// A URL rewriting service which wraps the original URL.
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum LinkWrapper {
+    /// Microsoft Defender for Office 365 Safe Links.
+    SafeLinks,
+    /// Proofpoint URL Defense (v1, v2 and v3 formats).
+    UrlDefense,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum LinkIndicator {
+    /// The anchor text looks like a link to a different domain than the one
+    /// the link actually points to.
+    DomainMismatch {
+        text_domain: String,
+        href_domain: String,
+    },
+    /// The host is an IP address rather than a domain name.
+    IpLiteralHost,
+    /// The host contains punycode or non-ASCII labels, which are often used
+    /// for lookalike domains. The decoded form of the host is included.
+    Punycode { decoded_host: String },
+    /// The link has been rewritten by a URL protection service.
+    Wrapped(LinkWrapper),
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct Link {
+    /// The URL as it appears in the body.
+    pub url: String,
+    /// The anchor text, where the body format records one.
+    pub text: Option<String>,
+    pub source: BodySource,
+    /// Byte offset of the URL within the body it was found in.
+    pub position: usize,
+    /// The original URL if `url` was wrapped by a URL protection service.
+    pub unwrapped: Option<String>,
+    pub indicators: Vec<LinkIndicator>,
+}
+
+impl Link {
+    fn new(url: String, text: Option<String>, source: BodySource, position: usize) -> Self {
+        let wrapped = unwrap(&url);
+        let unwrapped = wrapped.as_ref().map(|(_, original)| original.clone());
+        let mut indicators = Vec::new();
+        if let Some((wrapper, _)) = wrapped {
+            indicators.push(LinkIndicator::Wrapped(wrapper));
+        }
+        // Assess the URL that the user will eventually end up at.
+        let target = unwrapped.as_deref().unwrap_or(&url);
+        if let Some(host) = url_host(target) {
+            if is_ip_literal(&host) {
+                indicators.push(LinkIndicator::IpLiteralHost);
+            }
+            if host
+                .split('.')
+                .any(|label| label.starts_with("xn--") || !label.is_ascii())
+            {
+                indicators.push(LinkIndicator::Punycode {
+                    decoded_host: decode_host(&host),
+                });
+            }
+            if let Some(text_domain) = text.as_deref().and_then(text_host) {
+                if !same_site(&text_domain, &host) {
+                    indicators.push(LinkIndicator::DomainMismatch {
+                        text_domain,
+                        href_domain: host,
+                    });
+                }
+            }
+        }
+        Self {
+            url,
+            text,
+            source,
+            position,
+            unwrapped,
+            indicators,
+        }
+    }
+
+    /// The URL the link will ultimately take the reader to.
+    pub fn target(&self) -> &str {
+        self.unwrapped.as_deref().unwrap_or(&self.url)
+    }
+
+    pub fn is_suspicious(&self) -> bool {
+        self.indicators
+            .iter()
+            .any(|indicator| !matches!(indicator, LinkIndicator::Wrapped(_)))
+    }
+}
+
+/// Find bare URLs in plain text.
+pub fn from_plain(body: &str) -> Vec<Link> {
+    let mut links = Vec::new();
+    let lower = body.to_ascii_lowercase();
+    let mut i = 0;
+    while i < body.len() {
+        let start = match ["http://", "https://", "ftp://", "www."]
+            .iter()
+            .filter_map(|prefix| lower[i..].find(prefix))
+            .min()
+        {
+            Some(offset) => i + offset,
+            None => break,
+        };
+        // Don't pick up the "www." in the middle of a URL we've already seen
+        // or in the middle of a word.
+        let preceded_by_word = body[..start]
+            .chars()
+            .next_back()
+            .map(|c| c.is_alphanumeric() || c == '.' || c == '/')
+            .unwrap_or(false);
+        let end = body[start..]
+            .find(|c: char| c.is_whitespace() || matches!(c, '<' | '>' | '"' | '\'' | '`'))
+            .map(|end| start + end)
+            .unwrap_or(body.len());
+        let url = body[start..end].trim_end_matches(|c: char| {
+            matches!(c, '.' | ',' | ';' | ':' | '!' | '?' | ')' | ']' | '}')
+        });
+        if !preceded_by_word && url.len() > 4 && !url.ends_with("://") {
+            links.push(Link::new(url.to_string(), None, BodySource::Plain, start));
+        }
+        i = end.max(start + 1);
+        while !body.is_char_boundary(i) {
+            i += 1;
+        }
+    }
+    links
+}
+
+/// Find the `href` of each anchor element in an HTML body.
+pub fn from_html(body: &str) -> Vec<Link> {
+    let mut links = Vec::new();
+    let lower = body.to_ascii_lowercase();
+    let mut i = 0;
+    while let Some(offset) = lower[i..].find("<a") {
+        let tag_start = i + offset;
+        i = tag_start + 2;
+        if !lower[i..]
+            .chars()
+            .next()
+            .map(|c| c.is_whitespace())
+            .unwrap_or(false)
+        {
+            continue;
+        }
+        let tag_end = match lower[i..].find('>') {
+            Some(end) => i + end,
+            None => break,
+        };
+        let (href, position) = match attribute(body, i, tag_end, "href") {
+            Some(href) => href,
+            None => continue,
+        };
+        let content_end = lower[tag_end..]
+            .find("</a")
+            .map(|end| tag_end + end)
+            .unwrap_or(body.len());
+        let text = strip_tags(&body[tag_end + 1..content_end]);
+        let text = if text.is_empty() { None } else { Some(text) };
+        let href = decode_entities(href.trim());
+        if !href.is_empty() && !href.starts_with('#') {
+            links.push(Link::new(href, text, BodySource::Html, position));
+        }
+        i = tag_end;
+    }
+    links
+}
+
+/// Find the HYPERLINK fields in a decompressed RTF body.
+pub fn from_rtf(body: &str) -> Vec<Link> {
+    rtf::hyperlinks(body)
+        .into_iter()
+        .map(|link| Link::new(link.url, link.text, BodySource::Rtf, link.position))
+        .collect()
+}
+
+/// Returns the value of an attribute in the tag between `start` and `end`,
+/// along with the byte offset of the value.
+fn attribute<'a>(body: &'a str, start: usize, end: usize, name: &str) -> Option<(&'a str, usize)> {
+    let tag = &body[start..end];
+    let bytes = tag.as_bytes();
+    let mut i = 0;
+    while i < tag.len() {
+        while i < tag.len() && bytes[i].is_ascii_whitespace() {
+            i += 1;
+        }
+        let name_start = i;
+        while i < tag.len() && !bytes[i].is_ascii_whitespace() && bytes[i] != b'=' {
+            i += 1;
+        }
+        let attribute_name = &tag[name_start..i];
+        while i < tag.len() && bytes[i].is_ascii_whitespace() {
+            i += 1;
+        }
+        if i >= tag.len() || bytes[i] != b'=' {
+            if i == name_start {
+                i += 1;
+            }
+            continue;
+        }
+        i += 1;
+        while i < tag.len() && bytes[i].is_ascii_whitespace() {
+            i += 1;
+        }
+        let (value_start, value_end) = match bytes.get(i) {
+            Some(quote @ (b'"' | b'\'')) => {
+                let value_start = i + 1;
+                let value_end = tag[value_start..]
+                    .find(*quote as char)
+                    .map(|end| value_start + end)
+                    .unwrap_or(tag.len());
+                (value_start, value_end)
+            }
+            _ => {
+                let value_end = tag[i..]
+                    .find(|c: char| c.is_whitespace())
+                    .map(|end| i + end)
+                    .unwrap_or(tag.len());
+                (i, value_end)
+            }
+        };
+        if attribute_name.eq_ignore_ascii_case(name) {
+            return Some((&tag[value_start..value_end], start + value_start));
+        }
+        i = value_end + 1;
+    }
+    None
+}
+
+fn strip_tags(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => (),
+        }
+    }
+    decode_entities(&text)
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn decode_entities(s: &str) -> String {
+    let mut decoded = String::with_capacity(s.len());
+    let mut rest = s;
+    while let Some(amp) = rest.find('&') {
+        decoded.push_str(&rest[..amp]);
+        rest = &rest[amp..];
+        // Entities are short, so only look for the end within a few
+        // characters.
+        let window = match rest.char_indices().nth(12) {
+            Some((end, _)) => &rest[..end],
+            None => rest,
+        };
+        let semicolon = match window.find(';') {
+            Some(semicolon) => semicolon,
+            None => {
+                decoded.push('&');
+                rest = &rest[1..];
+                continue;
+            }
+        };
+        let entity = &rest[1..semicolon];
+        let c = match entity {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some(' '),
+            _ => {
+                let code = if let Some(hex) = entity
+                    .strip_prefix("#x")
+                    .or_else(|| entity.strip_prefix("#X"))
+                {
+                    u32::from_str_radix(hex, 16).ok()
+                } else if let Some(dec) = entity.strip_prefix('#') {
+                    dec.parse().ok()
+                } else {
+                    None
+                };
+                code.and_then(std::char::from_u32)
+            }
+        };
+        match c {
+            Some(c) => {
+                decoded.push(c);
+                rest = &rest[semicolon + 1..];
+            }
+            None => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
+
+/// If the URL has been wrapped by a URL protection service, return the
+/// service and the original URL.
+fn unwrap(url: &str) -> Option<(LinkWrapper, String)> {
+    let host = url_host(url)?;
+    let query = url.split_once('?').map(|(_, query)| query);
+    if host.ends_with("safelinks.protection.outlook.com") {
+        let original = query_parameter(query?, "url")?;
+        return Some((LinkWrapper::SafeLinks, percent_decode(original)));
+    }
+    if host == "urldefense.proofpoint.com" {
+        let path = url.splitn(4, '/').nth(3).unwrap_or("");
+        let encoded = query_parameter(query?, "u")?;
+        let original = if path.starts_with("v1/") {
+            percent_decode(encoded)
+        } else {
+            percent_decode(&encoded.replace('-', "%").replace('_', "/"))
+        };
+        return Some((LinkWrapper::UrlDefense, original));
+    }
+    if host == "urldefense.com" {
+        return decode_url_defense_v3(url).map(|original| (LinkWrapper::UrlDefense, original));
+    }
+    None
+}
+
+/// Proofpoint v3 URLs embed the original URL between `__` markers, with
+/// unsafe characters replaced by `*` and moved to a base64 encoded suffix.
+fn decode_url_defense_v3(url: &str) -> Option<String> {
+    const RUN_LENGTHS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+    let rest = &url[url.find("/v3/__")? + "/v3/__".len()..];
+    let (embedded, rest) = rest.split_once("__;")?;
+    let encoded = rest.split('!').next().unwrap_or("");
+    let replacements = String::from_utf8(base64_url_decode(encoded)?).ok()?;
+    let mut replacements = replacements.chars();
+    let mut original = String::with_capacity(embedded.len());
+    let mut chars = embedded.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '*' {
+            original.push(c);
+            continue;
+        }
+        let run = if chars.peek() == Some(&'*') {
+            chars.next();
+            let length = chars.next()?;
+            RUN_LENGTHS.iter().position(|&b| b as char == length)? + 2
+        } else {
+            1
+        };
+        for _ in 0..run {
+            original.push(replacements.next()?);
+        }
+    }
+    Some(original)
+}
+
+fn base64_url_decode(s: &str) -> Option<Vec<u8>> {
+    let mut bytes = Vec::with_capacity(s.len() * 3 / 4);
+    let mut buffer = 0_u32;
+    let mut bits = 0;
+    for c in s.bytes() {
+        let value = match c {
+            b'A'..=b'Z' => c - b'A',
+            b'a'..=b'z' => c - b'a' + 26,
+            b'0'..=b'9' => c - b'0' + 52,
+            b'-' | b'+' => 62,
+            b'_' | b'/' => 63,
+            b'=' => break,
+            _ => return None,
+        };
+        buffer = (buffer << 6) | value as u32;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((buffer >> bits) as u8);
+        }
+    }
+    Some(bytes)
+}
+
+fn query_parameter<'a>(query: &'a str, name: &str) -> Option<&'a str> {
+    let query = query.split('#').next().unwrap_or(query);
+    query.split('&').find_map(|pair| {
+        let (key, value) = pair.split_once('=')?;
+        if key == name {
+            Some(value)
+        } else {
+            None
+        }
+    })
+}
+
+fn percent_decode(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' {
+            if let Some(byte) = s
+                .get(i + 1..i + 3)
+                .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+            {
+                decoded.push(byte);
+                i += 3;
+                continue;
+            }
+        }
+        decoded.push(bytes[i]);
+        i += 1;
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+/// The lowercased host of an absolute http(s) or ftp URL.
+fn url_host(url: &str) -> Option<String> {
+    let lower = url.to_lowercase();
+    if lower.starts_with("www.") {
+        return authority_host(&lower);
+    }
+    let (scheme, rest) = lower.split_once("://")?;
+    if !matches!(scheme, "http" | "https" | "ftp") {
+        return None;
+    }
+    authority_host(rest)
+}
+
+/// Extract the host from the part of a URL following the scheme.
+fn authority_host(rest: &str) -> Option<String> {
+    let authority = rest.split(['/', '?', '#', '\\']).next()?;
+    // Anything before an @ is user info, which is a common way to disguise
+    // the real host.
+    let host_port = authority.rsplit('@').next()?;
+    let host = if let Some(bracketed) = host_port.strip_prefix('[') {
+        bracketed.split(']').next()?
+    } else {
+        host_port.split(':').next()?
+    };
+    let host = host.trim_end_matches('.');
+    if host.is_empty() {
+        None
+    } else {
+        Some(host.to_string())
+    }
+}
+
+/// If the anchor text itself looks like a URL or domain name, return its host.
+fn text_host(text: &str) -> Option<String> {
+    let text = text.trim();
+    if text.contains(char::is_whitespace) {
+        return None;
+    }
+    if text.contains("://") {
+        return url_host(text);
+    }
+    if text.contains('@') {
+        return None;
+    }
+    let host = authority_host(&text.to_lowercase())?;
+    let tld = host.rsplit('.').next()?;
+    if host.contains('.')
+        && tld.len() >= 2
+        && tld.chars().all(|c| c.is_alphabetic())
+        && host
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '.' || c == '-')
+    {
+        Some(host)
+    } else {
+        None
+    }
+}
+
+/// Hosts are treated as the same site if one is the other or a subdomain of it.
+fn same_site(a: &str, b: &str) -> bool {
+    let a = a.strip_prefix("www.").unwrap_or(a);
+    let b = b.strip_prefix("www.").unwrap_or(b);
+    a == b || a.ends_with(&format!(".{}", b)) || b.ends_with(&format!(".{}", a))
+}
+
+fn is_ip_literal(host: &str) -> bool {
+    if host.parse::<Ipv4Addr>().is_ok() || host.parse::<Ipv6Addr>().is_ok() {
+        return true;
+    }
+    // Browsers also accept dotted hex/octal/decimal forms such as 0x7f.1 or
+    // a single 32-bit number.
+    host.split('.').all(|part| {
+        !part.is_empty()
+            && (part.chars().all(|c| c.is_ascii_digit())
+                || part
+                    .strip_prefix("0x")
+                    .map(|hex| hex.chars().all(|c| c.is_ascii_hexdigit()))
+                    .unwrap_or(false))
+    })
+}
+
+/// Decode the punycode labels in a host name (RFC 3492).
+fn decode_host(host: &str) -> String {
+    host.split('.')
+        .map(|label| {
+            label
+                .strip_prefix("xn--")
+                .and_then(punycode_decode)
+                .unwrap_or_else(|| label.to_string())
+        })
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+fn punycode_decode(input: &str) -> Option<String> {
+    const BASE: u32 = 36;
+    const T_MIN: u32 = 1;
+    const T_MAX: u32 = 26;
+    const SKEW: u32 = 38;
+    const DAMP: u32 = 700;
+    fn adapt(mut delta: u32, num_points: u32, first_time: bool) -> u32 {
+        delta /= if first_time { DAMP } else { 2 };
+        delta += delta / num_points;
+        let mut k = 0;
+        while delta > ((BASE - T_MIN) * T_MAX) / 2 {
+            delta /= BASE - T_MIN;
+            k += BASE;
+        }
+        k + (((BASE - T_MIN + 1) * delta) / (delta + SKEW))
+    }
+
+    let (basic, extended) = match input.rfind('-') {
+        Some(i) => (&input[..i], &input[i + 1..]),
+        None => ("", input),
+    };
+    let mut output: Vec<char> = basic.chars().collect();
+    let mut n = 128_u32;
+    let mut i = 0_u32;
+    let mut bias = 72;
+    let mut digits = extended.chars().peekable();
+    while digits.peek().is_some() {
+        let old_i = i;
+        let mut weight = 1_u32;
+        let mut k = BASE;
+        loop {
+            let digit = match digits.next()? {
+                c @ 'a'..='z' => c as u32 - 'a' as u32,
+                c @ 'A'..='Z' => c as u32 - 'A' as u32,
+                c @ '0'..='9' => c as u32 - '0' as u32 + 26,
+                _ => return None,
+            };
+            i = i.checked_add(digit.checked_mul(weight)?)?;
+            let t = if k <= bias {
+                T_MIN
+            } else if k >= bias + T_MAX {
+                T_MAX
+            } else {
+                k - bias
+            };
+            if digit < t {
+                break;
+            }
+            weight = weight.checked_mul(BASE - t)?;
+            k += BASE;
+        }
+        let length = output.len() as u32 + 1;
+        bias = adapt(i - old_i, length, old_i == 0);
+        n = n.checked_add(i / length)?;
+        i %= length;
+        output.insert(i as usize, std::char::from_u32(n)?);
+        i += 1;
+    }
+    Some(output.into_iter().collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plain_links() {
+        let body = "See https://example.com/path?a=1. Or www.example.org, thanks";
+        let links = from_plain(body);
+        assert_eq!(links.len(), 2);
+        assert_eq!(links[0].url, "https://example.com/path?a=1");
+        assert_eq!(links[0].position, 4);
+        assert_eq!(links[1].url, "www.example.org");
+        assert!(links.iter().all(|link| !link.is_suspicious()));
+    }
+
+    #[test]
+    fn entities_next_to_non_ascii() {
+        let links = from_html("<a href=\"https://example.com/?a=1&bé=ééééé\">x</a>");
+        assert_eq!(links[0].url, "https://example.com/?a=1&bé=ééééé");
+        assert_eq!(decode_entities("&ééééééééééé;"), "&ééééééééééé;");
+        assert_eq!(decode_entities("é&amp;é&#xE9;"), "é&éé");
+    }
+
+    #[test]
+    fn html_mismatch_and_ip() {
+        let body = r#"<p>Log in at <a class="x" href="http://paypal.com.evil.example/login?a=1&amp;b=2">paypal.com</a>
+            or <A HREF='http://192.168.0.1/'>here</A></p>"#;
+        let links = from_html(body);
+        assert_eq!(links.len(), 2);
+        assert_eq!(links[0].url, "http://paypal.com.evil.example/login?a=1&b=2");
+        assert_eq!(links[0].text.as_deref(), Some("paypal.com"));
+        assert_eq!(&body[links[0].position..][..7], "http://");
+        assert_eq!(
+            links[0].indicators,
+            vec![LinkIndicator::DomainMismatch {
+                text_domain: "paypal.com".to_string(),
+                href_domain: "paypal.com.evil.example".to_string(),
+            }]
+        );
+        assert_eq!(links[1].indicators, vec![LinkIndicator::IpLiteralHost]);
+    }
+
+    #[test]
+    fn punycode_host() {
+        let links = from_plain("http://xn--pple-43d.com/");
+        assert_eq!(
+            links[0].indicators,
+            vec![LinkIndicator::Punycode {
+                decoded_host: "\u{0430}pple.com".to_string()
+            }]
+        );
+    }
+
+    #[test]
+    fn wrapped_links() {
+        let safe = "https://eur01.safelinks.protection.outlook.com/?url=https%3A%2F%2Fexample.com%2Fa%3Fb%3Dc&data=04%7C01&reserved=0";
+        let v2 = "https://urldefense.proofpoint.com/v2/url?u=https-3A__example.com_a-3Fb-3Dc&d=DwMFaQ&c=x";
+        let v3 = "https://urldefense.com/v3/__https://example.com/a*b=c__;Pw!!abc$";
+        for url in [safe, v2, v3] {
+            let links = from_plain(url);
+            assert_eq!(links.len(), 1, "{}", url);
+            assert_eq!(links[0].target(), "https://example.com/a?b=c", "{}", url);
+            assert!(matches!(links[0].indicators[0], LinkIndicator::Wrapped(_)));
+        }
+    }
+}
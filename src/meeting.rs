@@ -0,0 +1,333 @@
+//! The meeting properties in PSETID_Meeting and PSETID_Appointment that
+//! relate requests, responses and cancellations for the same meeting.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    appointment::{BusyStatus, ResponseStatus},
+    binary::{filetime_to_datetime, ByteReader},
+    EmailMessage, Lid,
+};
+
+/// The first 16 bytes of every global object ID.
+const GLOBAL_OBJECT_ID_PREFIX: [u8; 16] = [
+    0x04, 0x00, 0x00, 0x00, 0x82, 0x00, 0xE0, 0x00, 0x74, 0xC5, 0xB7, 0x10, 0x1A, 0x82, 0xE0, 0x08,
+];
+
+/// Outlook stores the UID of meetings created from iCalendar objects in the
+/// global object ID after this marker.
+const VCAL_UID_MARKER: &[u8] = b"vCal-Uid\x01\x00\x00\x00";
+
+/// PidLidGlobalObjectId or PidLidCleanGlobalObjectId, which identify a
+/// meeting (MS-OXOCAL 2.2.1.27).
+#[derive(Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct GlobalObjectId {
+    /// The date of the original occurrence for an exception to a recurring
+    /// meeting, or zero.
+    pub year: u16,
+    pub month: u8,
+    pub day: u8,
+    pub creation_time: Option<DateTime<Utc>>,
+    /// The rest of the identifier, which is unique to the meeting.
+    pub data: Vec<u8>,
+    /// The UID of the iCalendar object the meeting came from.
+    pub vcal_uid: Option<String>,
+    /// The whole identifier, which `uid` and `is_same_meeting` use.
+    #[serde(with = "crate::binary::hex")]
+    bytes: Vec<u8>,
+}
+
+impl GlobalObjectId {
+    pub fn parse(data: &[u8]) -> Result<Self, &'static str> {
+        let mut reader = ByteReader::new(data);
+        if reader.bytes(16)? != GLOBAL_OBJECT_ID_PREFIX {
+            return Err("not a global object ID");
+        }
+        let year = u16::from_be_bytes([reader.u8()?, reader.u8()?]);
+        let month = reader.u8()?;
+        let day = reader.u8()?;
+        let creation_time = filetime_to_datetime(reader.u64()?);
+        reader.bytes(8)?;
+        let size = reader.u32()? as usize;
+        let id_data = reader.bytes(size)?.to_vec();
+        let vcal_uid = id_data.strip_prefix(VCAL_UID_MARKER).map(|uid| {
+            let end = uid.iter().position(|&c| c == 0).unwrap_or(uid.len());
+            String::from_utf8_lossy(&uid[..end]).into_owned()
+        });
+        Ok(Self {
+            year,
+            month,
+            day,
+            creation_time,
+            data: id_data,
+            vcal_uid,
+            bytes: data.to_vec(),
+        })
+    }
+
+    /// The original date of the occurrence, for an exception to a recurring
+    /// meeting.
+    pub fn instance_date(&self) -> Option<NaiveDate> {
+        if self.year == 0 {
+            return None;
+        }
+        NaiveDate::from_ymd_opt(self.year as i32, self.month as u32, self.day as u32)
+    }
+
+    /// The identifier without the date of any occurrence, which is the same
+    /// for every occurrence of a meeting.
+    pub fn clean_bytes(&self) -> Vec<u8> {
+        let mut bytes = self.bytes.clone();
+        if let Some(date) = bytes.get_mut(16..20) {
+            date.fill(0);
+        }
+        bytes
+    }
+
+    /// The iCalendar UID of the meeting: the UID it was created with if
+    /// there is one, and otherwise the clean identifier in hex as Outlook
+    /// exports it.
+    pub fn uid(&self) -> String {
+        match &self.vcal_uid {
+            Some(uid) => uid.clone(),
+            None => self
+                .clean_bytes()
+                .iter()
+                .map(|byte| format!("{byte:02X}"))
+                .collect(),
+        }
+    }
+
+    /// Whether both identifiers are for the same meeting, regardless of the
+    /// occurrence.
+    pub fn is_same_meeting(&self, other: &Self) -> bool {
+        self.clean_bytes() == other.clean_bytes()
+    }
+}
+
+bitflags::bitflags! {
+    /// PidLidMeetingType.
+    #[derive(Serialize, Deserialize)]
+    pub struct MeetingType: u32 {
+        const REQUEST = 0x0000_0001;
+        /// An update that changed the time or location.
+        const FULL_UPDATE = 0x0001_0000;
+        /// An update that only changed other details.
+        const INFO_UPDATE = 0x0002_0000;
+        /// Superseded by a later update.
+        const OUT_OF_DATE = 0x0008_0000;
+        /// A copy sent to a delegator.
+        const DELEGATOR_COPY = 0x0010_0000;
+    }
+}
+
+bitflags::bitflags! {
+    /// PidLidAppointmentStateFlags.
+    #[derive(Serialize, Deserialize)]
+    pub struct AppointmentState: u32 {
+        /// The appointment is a meeting.
+        const MEETING = 0x0001;
+        /// The meeting was received rather than organized.
+        const RECEIVED = 0x0002;
+        const CANCELED = 0x0004;
+    }
+}
+
+/// A new time proposed by an attendee in their response.
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct CounterProposal {
+    pub start: Option<DateTime<Utc>>,
+    pub end: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq)]
+pub struct Meeting {
+    pub global_object_id: Option<GlobalObjectId>,
+    pub clean_global_object_id: Option<GlobalObjectId>,
+    /// The response in a meeting response, from the message class, or the
+    /// response of the owner of an appointment (PidLidResponseStatus).
+    pub response: Option<ResponseStatus>,
+    pub counter_proposal: Option<CounterProposal>,
+    /// The busy status the organizer wants attendees to use
+    /// (PidLidIntendedBusyStatus).
+    pub intended_busy_status: Option<BusyStatus>,
+    pub meeting_type: Option<MeetingType>,
+    pub state: Option<AppointmentState>,
+    /// When the organizer last made a change attendees must see
+    /// (PidLidOwnerCriticalChange).
+    pub owner_critical_change: Option<DateTime<Utc>>,
+    /// When the attendee last changed their copy
+    /// (PidLidAttendeeCriticalChange).
+    pub attendee_critical_change: Option<DateTime<Utc>>,
+}
+
+impl Meeting {
+    pub fn from_message(message: &EmailMessage) -> Self {
+        let properties = &message.properties;
+        let class = message
+            .message_class
+            .as_deref()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        let response = match class.strip_prefix("ipm.schedule.meeting.resp.") {
+            Some(kind) if kind.starts_with("pos") => Some(ResponseStatus::Accepted),
+            Some(kind) if kind.starts_with("neg") => Some(ResponseStatus::Declined),
+            Some(kind) if kind.starts_with("tent") => Some(ResponseStatus::Tentative),
+            _ => properties
+                .integer32(Lid::ResponseStatus)
+                .map(ResponseStatus::from_i32),
+        };
+        let counter_proposal = if properties
+            .boolean(Lid::AppointmentCounterProposal)
+            .unwrap_or(false)
+        {
+            Some(CounterProposal {
+                start: properties.time(Lid::AppointmentProposedStartWhole),
+                end: properties.time(Lid::AppointmentProposedEndWhole),
+            })
+        } else {
+            None
+        };
+        Self {
+            global_object_id: properties
+                .binary(Lid::GlobalObjectId)
+                .and_then(|data| GlobalObjectId::parse(data).ok()),
+            clean_global_object_id: properties
+                .binary(Lid::CleanGlobalObjectId)
+                .and_then(|data| GlobalObjectId::parse(data).ok()),
+            response,
+            counter_proposal,
+            intended_busy_status: properties
+                .integer32(Lid::IntendedBusyStatus)
+                .map(BusyStatus::from_i32),
+            meeting_type: properties
+                .integer32(Lid::MeetingType)
+                .map(|flags| MeetingType::from_bits_truncate(flags as u32)),
+            state: properties
+                .integer32(Lid::AppointmentStateFlags)
+                .map(|flags| AppointmentState::from_bits_truncate(flags as u32)),
+            owner_critical_change: properties.time(Lid::OwnerCriticalChange),
+            attendee_critical_change: properties.time(Lid::AttendeeCriticalChange),
+        }
+    }
+
+    /// The iCalendar UID of the meeting, which is the same for requests,
+    /// responses and cancellations of it.
+    pub fn uid(&self) -> Option<String> {
+        self.clean_global_object_id
+            .as_ref()
+            .or(self.global_object_id.as_ref())
+            .map(GlobalObjectId::uid)
+    }
+
+    /// Whether both messages are about the same meeting.
+    pub fn is_same_meeting(&self, other: &Self) -> bool {
+        let id = |meeting: &Self| {
+            meeting
+                .clean_global_object_id
+                .as_ref()
+                .or(meeting.global_object_id.as_ref())
+                .map(GlobalObjectId::clean_bytes)
+        };
+        match (id(self), id(other)) {
+            (Some(a), Some(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+    use crate::{
+        named::PropertyName,
+        test_support::{boolean, filetime, global_object_id, integer32, MsgBuilder},
+        PropertySet,
+    };
+
+    #[test]
+    fn global_object_ids() {
+        let occurrence = global_object_id((2023, 5, 8), &[1, 2, 3]);
+        let id = GlobalObjectId::parse(&occurrence).unwrap();
+        assert_eq!(id.instance_date(), NaiveDate::from_ymd_opt(2023, 5, 8));
+        assert_eq!(
+            id.creation_time,
+            Some(Utc.with_ymd_and_hms(2023, 4, 20, 8, 0, 0).unwrap())
+        );
+        assert_eq!(id.vcal_uid, None);
+        assert!(id
+            .uid()
+            .starts_with("040000008200E00074C5B7101A82E00800000000"));
+        let series = GlobalObjectId::parse(&global_object_id((0, 0, 0), &[1, 2, 3])).unwrap();
+        assert!(id.is_same_meeting(&series));
+        assert_eq!(id.uid(), series.uid());
+
+        let mut data = VCAL_UID_MARKER.to_vec();
+        data.extend(b"abc@example.com\0");
+        let id = GlobalObjectId::parse(&global_object_id((0, 0, 0), &data)).unwrap();
+        assert_eq!(id.uid(), "abc@example.com");
+
+        assert!(GlobalObjectId::parse(&[0; 40]).is_err());
+    }
+
+    #[test]
+    fn global_object_id_serde() {
+        let id = GlobalObjectId::parse(&global_object_id((2023, 5, 8), &[1, 2, 3])).unwrap();
+        let other = GlobalObjectId::parse(&global_object_id((0, 0, 0), &[4, 5, 6])).unwrap();
+        let json = serde_json::to_string(&id).unwrap();
+        let round_trip: GlobalObjectId = serde_json::from_str(&json).unwrap();
+        assert_eq!(round_trip, id);
+        assert_eq!(round_trip.uid(), id.uid());
+        assert!(!round_trip.is_same_meeting(&other));
+
+        let json = json.replace(&id.uid()[..8], "04X00000");
+        assert!(serde_json::from_str::<GlobalObjectId>(&json).is_err());
+    }
+
+    #[test]
+    fn counter_proposal() {
+        let start = Utc.with_ymd_and_hms(2023, 5, 2, 9, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2023, 5, 2, 10, 0, 0).unwrap();
+        let mut builder = MsgBuilder::new();
+        builder.string("/", 0x001A, "IPM.Schedule.Meeting.Resp.Tent");
+        builder.named(&[
+            (PropertySet::Meeting, PropertyName::Id(0x0023)),
+            (PropertySet::Appointment, PropertyName::Id(0x8257)),
+            (PropertySet::Appointment, PropertyName::Id(0x8250)),
+            (PropertySet::Appointment, PropertyName::Id(0x8251)),
+            (PropertySet::Appointment, PropertyName::Id(0x8224)),
+            (PropertySet::Meeting, PropertyName::Id(0x0026)),
+        ]);
+        builder.properties(
+            "/",
+            &[
+                (0x000B, 0x8001, boolean(true)),
+                (0x0040, 0x8002, filetime(start)),
+                (0x0040, 0x8003, filetime(end)),
+                (0x0003, 0x8004, integer32(1)),
+                (0x0003, 0x8005, integer32(0x0001_0001)),
+            ],
+        );
+        builder.binary("/", 0x8000, &global_object_id((0, 0, 0), &[7]));
+        let msg = EmailMessage::from_bytes(&builder.build()).unwrap();
+        let meeting = Meeting::from_message(&msg);
+        assert_eq!(meeting.response, Some(ResponseStatus::Tentative));
+        assert_eq!(
+            meeting.counter_proposal,
+            Some(CounterProposal {
+                start: Some(start),
+                end: Some(end)
+            })
+        );
+        assert_eq!(meeting.intended_busy_status, Some(BusyStatus::Tentative));
+        assert_eq!(
+            meeting.meeting_type,
+            Some(MeetingType::REQUEST | MeetingType::FULL_UPDATE)
+        );
+        assert!(meeting.uid().unwrap().ends_with("0100000007"));
+        assert!(meeting.is_same_meeting(&meeting.clone()));
+    }
+}
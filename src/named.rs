@@ -0,0 +1,120 @@
+//! The named property mapping storage, which gives the name of each property
+//! ID from 0x8000 upwards used in a file.
+
+use std::{
+    collections::BTreeMap,
+    convert::TryInto,
+    io::{Read, Seek},
+};
+
+use crate::{
+    oxprops::props::{lid_from_id, name_from_string},
+    read, read_stream, Pid, PropertySet,
+};
+
+pub(crate) const NAMEID_STORAGE: &str = "/__nameid_version1.0";
+pub(crate) const GUID_STREAM: &str = "/__nameid_version1.0/__substg1.0_00020102";
+pub(crate) const ENTRY_STREAM: &str = "/__nameid_version1.0/__substg1.0_00030102";
+pub(crate) const STRING_STREAM: &str = "/__nameid_version1.0/__substg1.0_00040102";
+
+#[derive(Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum PropertyName {
+    /// A numeric name, known as a LID.
+    Id(u32),
+    String(String),
+}
+
+#[derive(Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct NamedProperty {
+    pub property_set: PropertySet,
+    pub name: PropertyName,
+}
+
+impl NamedProperty {
+    /// The property as a [`Pid`], if it is one of the known named
+    /// properties.
+    pub fn pid(&self) -> Option<Pid> {
+        match &self.name {
+            PropertyName::Id(id) => lid_from_id(self.property_set, *id).map(Pid::Lid),
+            PropertyName::String(name) => name_from_string(self.property_set, name).map(Pid::Name),
+        }
+    }
+}
+
+/// The names of the named properties in a file, by property ID.
+#[derive(Clone, Debug, Default)]
+pub struct NamedProperties {
+    properties: BTreeMap<u16, NamedProperty>,
+}
+
+impl NamedProperties {
+    /// Read the mapping from the root storage of a file. Files without named
+    /// properties may not have the storage at all, which gives an empty
+    /// mapping.
+    pub(crate) fn read<F: Seek + Read>(
+        comp: &mut cfb::CompoundFile<F>,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        let mut properties = BTreeMap::new();
+        let entries = match read_stream(comp, ENTRY_STREAM) {
+            Some(entries) => entries,
+            None => return Ok(Self { properties }),
+        };
+        let guids = read_stream(comp, GUID_STREAM).unwrap_or_default();
+        let strings = read_stream(comp, STRING_STREAM).unwrap_or_default();
+        for entry in entries.chunks_exact(8) {
+            let name_or_offset = u32::from_le_bytes(entry[0..4].try_into().unwrap());
+            let index_and_kind = u32::from_le_bytes(entry[4..8].try_into().unwrap());
+            let is_string = index_and_kind & 1 == 1;
+            let guid_index = ((index_and_kind >> 1) & 0x7FFF) as usize;
+            let property_index = (index_and_kind >> 16) as u16;
+            let property_set = match guid_index {
+                1 => PropertySet::PsMapi,
+                2 => PropertySet::PublicStrings,
+                n if n >= 3 => {
+                    let guid = guids
+                        .get((n - 3) * 16..(n - 2) * 16)
+                        .ok_or("named property GUID index out of range")?;
+                    PropertySet::from_uuid(crate::parse_guid(guid))
+                }
+                _ => return Err("named property has no GUID".into()),
+            };
+            let name = if is_string {
+                let offset = name_or_offset as usize;
+                let length = strings
+                    .get(offset..offset + 4)
+                    .map(|length| u32::from_le_bytes(length.try_into().unwrap()) as usize)
+                    .ok_or("named property string offset out of range")?;
+                let name = strings
+                    .get(offset + 4..offset + 4 + length)
+                    .ok_or("named property string out of range")?;
+                PropertyName::String(read(name)?)
+            } else {
+                PropertyName::Id(name_or_offset)
+            };
+            let id = property_index
+                .checked_add(0x8000)
+                .ok_or("named property index out of range")?;
+            properties.insert(id, NamedProperty { property_set, name });
+        }
+        Ok(Self { properties })
+    }
+
+    pub fn get(&self, id: u16) -> Option<&NamedProperty> {
+        self.properties.get(&id)
+    }
+
+    pub(crate) fn insert(&mut self, id: u16, property: NamedProperty) {
+        self.properties.insert(id, property);
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (u16, &NamedProperty)> {
+        self.properties.iter().map(|(id, property)| (*id, property))
+    }
+
+    /// The property ID a named property has in this file.
+    pub fn id_of(&self, property_set: PropertySet, name: &PropertyName) -> Option<u16> {
+        self.iter()
+            .find(|(_, property)| property.property_set == property_set && property.name == *name)
+            .map(|(id, _)| id)
+    }
+}
@@ -0,0 +1,154 @@
+//! Sticky notes (`IPM.StickyNote`), from the properties in MS-OXONOTE.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{EmailMessage, Lid, Tag};
+
+/// PidLidNoteColor.
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum NoteColor {
+    Blue,
+    Green,
+    Pink,
+    Yellow,
+    White,
+    Other(i32),
+}
+
+impl NoteColor {
+    pub fn from_i32(n: i32) -> Self {
+        match n {
+            0 => Self::Blue,
+            1 => Self::Green,
+            2 => Self::Pink,
+            3 => Self::Yellow,
+            4 => Self::White,
+            n => Self::Other(n),
+        }
+    }
+
+    /// The background colour Outlook draws the note with, as `#RRGGBB`.
+    pub fn hex(self) -> Option<&'static str> {
+        match self {
+            Self::Blue => Some("#CCE5FF"),
+            Self::Green => Some("#CCFFCC"),
+            Self::Pink => Some("#FFCCE5"),
+            Self::Yellow => Some("#FFFFCC"),
+            Self::White => Some("#FFFFFF"),
+            Self::Other(_) => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq)]
+pub struct StickyNote {
+    pub text: String,
+    /// Outlook uses yellow for notes without a colour.
+    pub color: NoteColor,
+    /// The position of the note window on the screen in pixels
+    /// (PidLidNoteX and PidLidNoteY).
+    pub x: Option<i32>,
+    pub y: Option<i32>,
+    /// The size of the note window in pixels (PidLidNoteWidth and
+    /// PidLidNoteHeight).
+    pub width: Option<i32>,
+    pub height: Option<i32>,
+    pub created: Option<DateTime<Utc>>,
+    pub last_modified: Option<DateTime<Utc>>,
+}
+
+impl StickyNote {
+    pub fn from_message(message: &EmailMessage) -> Self {
+        let properties = &message.properties;
+        Self {
+            // The subject is only the first line of the text.
+            text: message
+                .body
+                .clone()
+                .unwrap_or_else(|| message.subject.clone()),
+            color: properties
+                .integer32(Lid::NoteColor)
+                .map(NoteColor::from_i32)
+                .unwrap_or(NoteColor::Yellow),
+            x: properties.integer32(Lid::NoteX),
+            y: properties.integer32(Lid::NoteY),
+            width: properties.integer32(Lid::NoteWidth),
+            height: properties.integer32(Lid::NoteHeight),
+            created: properties.time(Tag::CreationTime),
+            last_modified: properties.time(Tag::LastModificationTime),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+    use crate::{
+        items::Item,
+        named::PropertyName,
+        test_support::{filetime, integer32, MsgBuilder},
+        PropertySet,
+    };
+
+    #[test]
+    fn sticky_note() {
+        let created = Utc.with_ymd_and_hms(2023, 5, 2, 9, 0, 0).unwrap();
+        let mut builder = MsgBuilder::new();
+        builder.message("/", "Milk", "", "Milk\r\nEggs");
+        builder.string("/", 0x001A, "IPM.StickyNote");
+        builder.named(&[
+            (PropertySet::Note, PropertyName::Id(0x8B00)),
+            (PropertySet::Note, PropertyName::Id(0x8B04)),
+            (PropertySet::Note, PropertyName::Id(0x8B05)),
+            (PropertySet::Note, PropertyName::Id(0x8B02)),
+            (PropertySet::Note, PropertyName::Id(0x8B03)),
+        ]);
+        builder.properties(
+            "/",
+            &[
+                (0x0040, 0x3007, filetime(created)),
+                (0x0003, 0x8000, integer32(1)),
+                (0x0003, 0x8001, integer32(120)),
+                (0x0003, 0x8002, integer32(80)),
+                (0x0003, 0x8003, integer32(200)),
+                (0x0003, 0x8004, integer32(166)),
+            ],
+        );
+        let msg = EmailMessage::from_bytes(&builder.build()).unwrap();
+
+        let note = match msg.item() {
+            Item::StickyNote(note) => note,
+            item => panic!("not a sticky note: {:?}", item),
+        };
+        assert_eq!(note.text, "Milk\r\nEggs");
+        assert_eq!(note.color, NoteColor::Green);
+        assert_eq!((note.x, note.y), (Some(120), Some(80)));
+        assert_eq!((note.width, note.height), (Some(200), Some(166)));
+        assert_eq!(note.created, Some(created));
+        assert_eq!(note.last_modified, None);
+    }
+
+    #[test]
+    fn sparse_sticky_note() {
+        let note = |color: Option<[u8; 8]>| {
+            let mut builder = MsgBuilder::new();
+            builder.string("/", 0x001A, "IPM.StickyNote");
+            builder.named(&[(PropertySet::Note, PropertyName::Id(0x8B00))]);
+            if let Some(color) = color {
+                builder.properties("/", &[(0x0003, 0x8000, color)]);
+            }
+            let msg = EmailMessage::from_bytes(&builder.build()).unwrap();
+            StickyNote::from_message(&msg)
+        };
+
+        let plain = note(None);
+        assert_eq!(plain.color, NoteColor::Yellow);
+        assert_eq!((plain.x, plain.width), (None, None));
+        let unknown = note(Some(integer32(7))).color;
+        assert_eq!(unknown, NoteColor::Other(7));
+        assert_eq!(unknown.hex(), None);
+    }
+}
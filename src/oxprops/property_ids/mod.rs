@@ -2,8 +2,9 @@ pub mod lids;
 pub mod names;
 pub mod tags;
 use self::{lids::Lid, names::Name, tags::Tag};
+use serde::{Deserialize, Serialize};
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub enum Pid {
     Lid(Lid),
     Name(Name),
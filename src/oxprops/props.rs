@@ -34,511 +34,8 @@ impl PropertyInfo {
 #[rustfmt::skip]
 fn to_detail(pid: Pid) -> PropertyInfo {
     match pid {
-        Pid::Lid(Lid::AddressBookProviderArrayType) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x00008029,p_type:PType::Integer32},
-        Pid::Lid(Lid::AddressBookProviderEmailList) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x00008028,p_type:PType::MultipleInteger32},
-        Pid::Lid(Lid::AddressCountryCode) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x000080DD,p_type:PType::String},
-        Pid::Lid(Lid::AgingDontAgeMe) => PropertyInfo::Lid{property_set: PropertySet::Common,id:0x0000850E,p_type:PType::Boolean},
-        Pid::Lid(Lid::AllAttendeesString) => PropertyInfo::Lid{property_set: PropertySet::Appointment,id:0x00008238,p_type:PType::String},
-        Pid::Lid(Lid::AllowExternalCheck) => PropertyInfo::Lid{property_set: PropertySet::Appointment,id:0x00008246,p_type:PType::Boolean},
-        Pid::Lid(Lid::AnniversaryEventEntryId) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x0000804E,p_type:PType::Binary},
-        Pid::Lid(Lid::AppointmentAuxiliaryFlags) => PropertyInfo::Lid{property_set: PropertySet::Appointment,id:0x00008207,p_type:PType::Integer32},
-        Pid::Lid(Lid::AppointmentColor) => PropertyInfo::Lid{property_set: PropertySet::Appointment,id:0x00008214,p_type:PType::Integer32},
-        Pid::Lid(Lid::AppointmentCounterProposal) => PropertyInfo::Lid{property_set: PropertySet::Appointment,id:0x00008257,p_type:PType::Boolean},
-        Pid::Lid(Lid::AppointmentDuration) => PropertyInfo::Lid{property_set: PropertySet::Appointment,id:0x00008213,p_type:PType::Integer32},
-        Pid::Lid(Lid::AppointmentEndDate) => PropertyInfo::Lid{property_set: PropertySet::Appointment,id:0x00008211,p_type:PType::Time},
-        Pid::Lid(Lid::AppointmentEndTime) => PropertyInfo::Lid{property_set: PropertySet::Appointment,id:0x00008210,p_type:PType::Time},
-        Pid::Lid(Lid::AppointmentEndWhole) => PropertyInfo::Lid{property_set: PropertySet::Appointment,id:0x0000820E,p_type:PType::Time},
-        Pid::Lid(Lid::AppointmentLastSequence) => PropertyInfo::Lid{property_set: PropertySet::Appointment,id:0x00008203,p_type:PType::Integer32},
-        Pid::Lid(Lid::AppointmentMessageClass) => PropertyInfo::Lid{property_set: PropertySet::Meeting,id:0x00000024,p_type:PType::String},
-        Pid::Lid(Lid::AppointmentNotAllowPropose) => PropertyInfo::Lid{property_set: PropertySet::Appointment,id:0x0000825A,p_type:PType::Boolean},
-        Pid::Lid(Lid::AppointmentProposalNumber) => PropertyInfo::Lid{property_set: PropertySet::Appointment,id:0x00008259,p_type:PType::Integer32},
-        Pid::Lid(Lid::AppointmentProposedDuration) => PropertyInfo::Lid{property_set: PropertySet::Appointment,id:0x00008256,p_type:PType::Integer32},
-        Pid::Lid(Lid::AppointmentProposedEndWhole) => PropertyInfo::Lid{property_set: PropertySet::Appointment,id:0x00008251,p_type:PType::Time},
-        Pid::Lid(Lid::AppointmentProposedStartWhole) => PropertyInfo::Lid{property_set: PropertySet::Appointment,id:0x00008250,p_type:PType::Time},
-        Pid::Lid(Lid::AppointmentRecur) => PropertyInfo::Lid{property_set: PropertySet::Appointment,id:0x00008216,p_type:PType::Binary},
-        Pid::Lid(Lid::AppointmentReplyName) => PropertyInfo::Lid{property_set: PropertySet::Appointment,id:0x00008230,p_type:PType::String},
-        Pid::Lid(Lid::AppointmentReplyTime) => PropertyInfo::Lid{property_set: PropertySet::Appointment,id:0x00008220,p_type:PType::Time},
-        Pid::Lid(Lid::AppointmentSequence) => PropertyInfo::Lid{property_set: PropertySet::Appointment,id:0x00008201,p_type:PType::Integer32},
-        Pid::Lid(Lid::AppointmentSequenceTime) => PropertyInfo::Lid{property_set: PropertySet::Appointment,id:0x00008202,p_type:PType::Time},
-        Pid::Lid(Lid::AppointmentStartDate) => PropertyInfo::Lid{property_set: PropertySet::Appointment,id:0x00008212,p_type:PType::Time},
-        Pid::Lid(Lid::AppointmentStartTime) => PropertyInfo::Lid{property_set: PropertySet::Appointment,id:0x0000820F,p_type:PType::Time},
-        Pid::Lid(Lid::AppointmentStartWhole) => PropertyInfo::Lid{property_set: PropertySet::Appointment,id:0x0000820D,p_type:PType::Time},
-        Pid::Lid(Lid::AppointmentStateFlags) => PropertyInfo::Lid{property_set: PropertySet::Appointment,id:0x00008217,p_type:PType::Integer32},
-        Pid::Lid(Lid::AppointmentSubType) => PropertyInfo::Lid{property_set: PropertySet::Appointment,id:0x00008215,p_type:PType::Boolean},
-        Pid::Lid(Lid::AppointmentTimeZoneDefinitionEndDisplay) => PropertyInfo::Lid{property_set: PropertySet::Appointment,id:0x0000825F,p_type:PType::Binary},
-        Pid::Lid(Lid::AppointmentTimeZoneDefinitionRecur) => PropertyInfo::Lid{property_set: PropertySet::Appointment,id:0x00008260,p_type:PType::Binary},
-        Pid::Lid(Lid::AppointmentTimeZoneDefinitionStartDisplay) => PropertyInfo::Lid{property_set: PropertySet::Appointment,id:0x0000825E,p_type:PType::Binary},
-        Pid::Lid(Lid::AppointmentUnsendableRecipients) => PropertyInfo::Lid{property_set: PropertySet::Appointment,id:0x0000825D,p_type:PType::Binary},
-        Pid::Lid(Lid::AppointmentUpdateTime) => PropertyInfo::Lid{property_set: PropertySet::Appointment,id:0x00008226,p_type:PType::Time},
-        Pid::Lid(Lid::AttendeeCriticalChange) => PropertyInfo::Lid{property_set: PropertySet::Meeting,id:0x00000001,p_type:PType::Time},
-        Pid::Lid(Lid::AutoFillLocation) => PropertyInfo::Lid{property_set: PropertySet::Appointment,id:0x0000823A,p_type:PType::Boolean},
-        Pid::Lid(Lid::AutoLog) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x00008025,p_type:PType::Boolean},
-        Pid::Lid(Lid::AutoProcessState) => PropertyInfo::Lid{property_set: PropertySet::Common,id:0x0000851A,p_type:PType::Integer32},
-        Pid::Lid(Lid::AutoStartCheck) => PropertyInfo::Lid{property_set: PropertySet::Appointment,id:0x00008244,p_type:PType::Boolean},
-        Pid::Lid(Lid::Billing) => PropertyInfo::Lid{property_set: PropertySet::Common,id:0x00008535,p_type:PType::String},
-        Pid::Lid(Lid::BirthdayEventEntryId) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x0000804D,p_type:PType::Binary},
-        Pid::Lid(Lid::BirthdayLocal) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x000080DE,p_type:PType::Time},
-        Pid::Lid(Lid::BusinessCardCardPicture) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x00008041,p_type:PType::Binary},
-        Pid::Lid(Lid::BusinessCardDisplayDefinition) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x00008040,p_type:PType::Binary},
-        Pid::Lid(Lid::BusyStatus) => PropertyInfo::Lid{property_set: PropertySet::Appointment,id:0x00008205,p_type:PType::Integer32},
-        Pid::Lid(Lid::CalendarType) => PropertyInfo::Lid{property_set: PropertySet::Meeting,id:0x0000001C,p_type:PType::Integer32},
-        Pid::Lid(Lid::Categories) => PropertyInfo::Lid{property_set: PropertySet::PublicStrings,id:0x00009000,p_type:PType::MultipleString},
-        Pid::Lid(Lid::CcAttendeesString) => PropertyInfo::Lid{property_set: PropertySet::Appointment,id:0x0000823C,p_type:PType::String},
-        Pid::Lid(Lid::ChangeHighlight) => PropertyInfo::Lid{property_set: PropertySet::Appointment,id:0x00008204,p_type:PType::Integer32},
-        Pid::Lid(Lid::Classification) => PropertyInfo::Lid{property_set: PropertySet::Common,id:0x000085B6,p_type:PType::String},
-        Pid::Lid(Lid::ClassificationDescription) => PropertyInfo::Lid{property_set: PropertySet::Common,id:0x000085B7,p_type:PType::String},
-        Pid::Lid(Lid::ClassificationGuid) => PropertyInfo::Lid{property_set: PropertySet::Common,id:0x000085B8,p_type:PType::String},
-        Pid::Lid(Lid::ClassificationKeep) => PropertyInfo::Lid{property_set: PropertySet::Common,id:0x000085BA,p_type:PType::Boolean},
-        Pid::Lid(Lid::Classified) => PropertyInfo::Lid{property_set: PropertySet::Common,id:0x000085B5,p_type:PType::Boolean},
-        Pid::Lid(Lid::CleanGlobalObjectId) => PropertyInfo::Lid{property_set: PropertySet::Meeting,id:0x00000023,p_type:PType::Binary},
-        Pid::Lid(Lid::ClientIntent) => PropertyInfo::Lid{property_set: PropertySet::CalendarAssistant,id:0x00000015,p_type:PType::Integer32},
-        Pid::Lid(Lid::ClipEnd) => PropertyInfo::Lid{property_set: PropertySet::Appointment,id:0x00008236,p_type:PType::Time},
-        Pid::Lid(Lid::ClipStart) => PropertyInfo::Lid{property_set: PropertySet::Appointment,id:0x00008235,p_type:PType::Time},
-        Pid::Lid(Lid::CollaborateDoc) => PropertyInfo::Lid{property_set: PropertySet::Appointment,id:0x00008247,p_type:PType::String},
-        Pid::Lid(Lid::CommonEnd) => PropertyInfo::Lid{property_set: PropertySet::Common,id:0x00008517,p_type:PType::Time},
-        Pid::Lid(Lid::CommonStart) => PropertyInfo::Lid{property_set: PropertySet::Common,id:0x00008516,p_type:PType::Time},
-        Pid::Lid(Lid::Companies) => PropertyInfo::Lid{property_set: PropertySet::Common,id:0x00008539,p_type:PType::MultipleString},
-        Pid::Lid(Lid::ConferencingCheck) => PropertyInfo::Lid{property_set: PropertySet::Appointment,id:0x00008240,p_type:PType::Boolean},
-        Pid::Lid(Lid::ConferencingType) => PropertyInfo::Lid{property_set: PropertySet::Appointment,id:0x00008241,p_type:PType::Integer32},
-        Pid::Lid(Lid::ContactCharacterSet) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x00008023,p_type:PType::Integer32},
-        Pid::Lid(Lid::ContactItemData) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x00008007,p_type:PType::MultipleInteger32},
-        Pid::Lid(Lid::ContactLinkedGlobalAddressListEntryId) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x000080E2,p_type:PType::Binary},
-        Pid::Lid(Lid::ContactLinkEntry) => PropertyInfo::Lid{property_set: PropertySet::Common,id:0x00008585,p_type:PType::Binary},
-        Pid::Lid(Lid::ContactLinkGlobalAddressListLinkId) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x000080E8,p_type:PType::Guid},
-        Pid::Lid(Lid::ContactLinkGlobalAddressListLinkState) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x000080E6,p_type:PType::Integer32},
-        Pid::Lid(Lid::ContactLinkLinkRejectHistory) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x000080E5,p_type:PType::MultipleBinary},
-        Pid::Lid(Lid::ContactLinkName) => PropertyInfo::Lid{property_set: PropertySet::Common,id:0x00008586,p_type:PType::String},
-        Pid::Lid(Lid::ContactLinkSearchKey) => PropertyInfo::Lid{property_set: PropertySet::Common,id:0x00008584,p_type:PType::Binary},
-        Pid::Lid(Lid::ContactLinkSMTPAddressCache) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x000080E3,p_type:PType::MultipleString},
-        Pid::Lid(Lid::Contacts) => PropertyInfo::Lid{property_set: PropertySet::Common,id:0x0000853A,p_type:PType::MultipleString},
-        Pid::Lid(Lid::ContactUserField1) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x0000804F,p_type:PType::String},
-        Pid::Lid(Lid::ContactUserField2) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x00008050,p_type:PType::String},
-        Pid::Lid(Lid::ContactUserField3) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x00008051,p_type:PType::String},
-        Pid::Lid(Lid::ContactUserField4) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x00008052,p_type:PType::String},
-        Pid::Lid(Lid::ConversationActionLastAppliedTime) => PropertyInfo::Lid{property_set: PropertySet::Common,id:0x000085CA,p_type:PType::Time},
-        Pid::Lid(Lid::ConversationActionMaxDeliveryTime) => PropertyInfo::Lid{property_set: PropertySet::Common,id:0x000085C8,p_type:PType::Time},
-        Pid::Lid(Lid::ConversationActionMoveFolderEid) => PropertyInfo::Lid{property_set: PropertySet::Common,id:0x000085C6,p_type:PType::Binary},
-        Pid::Lid(Lid::ConversationActionMoveStoreEid) => PropertyInfo::Lid{property_set: PropertySet::Common,id:0x000085C7,p_type:PType::Binary},
-        Pid::Lid(Lid::ConversationActionVersion) => PropertyInfo::Lid{property_set: PropertySet::Common,id:0x000085CB,p_type:PType::Integer32},
-        Pid::Lid(Lid::ConversationProcessed) => PropertyInfo::Lid{property_set: PropertySet::Common,id:0x000085C9,p_type:PType::Integer32},
-        Pid::Lid(Lid::CurrentVersion) => PropertyInfo::Lid{property_set: PropertySet::Common,id:0x00008552,p_type:PType::Integer32},
-        Pid::Lid(Lid::CurrentVersionName) => PropertyInfo::Lid{property_set: PropertySet::Common,id:0x00008554,p_type:PType::String},
-        Pid::Lid(Lid::DayInterval) => PropertyInfo::Lid{property_set: PropertySet::Meeting,id:0x00000011,p_type:PType::Integer16},
-        Pid::Lid(Lid::DayOfMonth) => PropertyInfo::Lid{property_set: PropertySet::Common,id:0x00001000,p_type:PType::Integer32},
-        Pid::Lid(Lid::DelegateMail) => PropertyInfo::Lid{property_set: PropertySet::Meeting,id:0x00000009,p_type:PType::Boolean},
-        Pid::Lid(Lid::Department) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x00008010,p_type:PType::String},
-        Pid::Lid(Lid::Directory) => PropertyInfo::Lid{property_set: PropertySet::Appointment,id:0x00008242,p_type:PType::String},
-        Pid::Lid(Lid::DistributionListChecksum) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x0000804C,p_type:PType::Integer32},
-        Pid::Lid(Lid::DistributionListMembers) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x00008055,p_type:PType::MultipleBinary},
-        Pid::Lid(Lid::DistributionListName) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x00008053,p_type:PType::String},
-        Pid::Lid(Lid::DistributionListOneOffMembers) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x00008054,p_type:PType::MultipleBinary},
-        Pid::Lid(Lid::DistributionListStream) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x00008064,p_type:PType::Binary},
-        Pid::Lid(Lid::Email1AddressType) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x00008082,p_type:PType::String},
-        Pid::Lid(Lid::Email1DisplayName) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x00008080,p_type:PType::String},
-        Pid::Lid(Lid::Email1EmailAddress) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x00008083,p_type:PType::String},
-        Pid::Lid(Lid::Email1OriginalDisplayName) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x00008084,p_type:PType::String},
-        Pid::Lid(Lid::Email1OriginalEntryId) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x00008085,p_type:PType::Binary},
-        Pid::Lid(Lid::Email2AddressType) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x00008092,p_type:PType::String},
-        Pid::Lid(Lid::Email2DisplayName) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x00008090,p_type:PType::String},
-        Pid::Lid(Lid::Email2EmailAddress) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x00008093,p_type:PType::String},
-        Pid::Lid(Lid::Email2OriginalDisplayName) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x00008094,p_type:PType::String},
-        Pid::Lid(Lid::Email2OriginalEntryId) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x00008095,p_type:PType::Binary},
-        Pid::Lid(Lid::Email3AddressType) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x000080A2,p_type:PType::String},
-        Pid::Lid(Lid::Email3DisplayName) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x000080A0,p_type:PType::String},
-        Pid::Lid(Lid::Email3EmailAddress) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x000080A3,p_type:PType::String},
-        Pid::Lid(Lid::Email3OriginalDisplayName) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x000080A4,p_type:PType::String},
-        Pid::Lid(Lid::Email3OriginalEntryId) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x000080A5,p_type:PType::Binary},
-        Pid::Lid(Lid::EndRecurrenceDate) => PropertyInfo::Lid{property_set: PropertySet::Meeting,id:0x0000000F,p_type:PType::Integer32},
-        Pid::Lid(Lid::EndRecurrenceTime) => PropertyInfo::Lid{property_set: PropertySet::Meeting,id:0x00000010,p_type:PType::Integer32},
-        Pid::Lid(Lid::ExceptionReplaceTime) => PropertyInfo::Lid{property_set: PropertySet::Appointment,id:0x00008228,p_type:PType::Time},
-        Pid::Lid(Lid::Fax1AddressType) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x000080B2,p_type:PType::String},
-        Pid::Lid(Lid::Fax1EmailAddress) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x000080B3,p_type:PType::String},
-        Pid::Lid(Lid::Fax1OriginalDisplayName) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x000080B4,p_type:PType::String},
-        Pid::Lid(Lid::Fax1OriginalEntryId) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x000080B5,p_type:PType::Binary},
-        Pid::Lid(Lid::Fax2AddressType) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x000080C2,p_type:PType::String},
-        Pid::Lid(Lid::Fax2EmailAddress) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x000080C3,p_type:PType::String},
-        Pid::Lid(Lid::Fax2OriginalDisplayName) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x000080C4,p_type:PType::String},
-        Pid::Lid(Lid::Fax2OriginalEntryId) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x000080C5,p_type:PType::Binary},
-        Pid::Lid(Lid::Fax3AddressType) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x000080D2,p_type:PType::String},
-        Pid::Lid(Lid::Fax3EmailAddress) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x000080D3,p_type:PType::String},
-        Pid::Lid(Lid::Fax3OriginalDisplayName) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x000080D4,p_type:PType::String},
-        Pid::Lid(Lid::Fax3OriginalEntryId) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x000080D5,p_type:PType::Binary},
-        Pid::Lid(Lid::FExceptionalAttendees) => PropertyInfo::Lid{property_set: PropertySet::Appointment,id:0x0000822B,p_type:PType::Boolean},
-        Pid::Lid(Lid::FExceptionalBody) => PropertyInfo::Lid{property_set: PropertySet::Appointment,id:0x00008206,p_type:PType::Boolean},
-        Pid::Lid(Lid::FileUnder) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x00008005,p_type:PType::String},
-        Pid::Lid(Lid::FileUnderId) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x00008006,p_type:PType::Integer32},
-        Pid::Lid(Lid::FileUnderList) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x00008026,p_type:PType::MultipleInteger32},
-        Pid::Lid(Lid::FInvited) => PropertyInfo::Lid{property_set: PropertySet::Appointment,id:0x00008229,p_type:PType::Boolean},
-        Pid::Lid(Lid::FlagRequest) => PropertyInfo::Lid{property_set: PropertySet::Common,id:0x00008530,p_type:PType::String},
-        Pid::Lid(Lid::FlagString) => PropertyInfo::Lid{property_set: PropertySet::Common,id:0x000085C0,p_type:PType::Integer32},
-        Pid::Lid(Lid::ForwardInstance) => PropertyInfo::Lid{property_set: PropertySet::Appointment,id:0x0000820A,p_type:PType::Boolean},
-        Pid::Lid(Lid::ForwardNotificationRecipients) => PropertyInfo::Lid{property_set: PropertySet::Appointment,id:0x00008261,p_type:PType::Binary},
-        Pid::Lid(Lid::FOthersAppointment) => PropertyInfo::Lid{property_set: PropertySet::Appointment,id:0x0000822F,p_type:PType::Boolean},
-        Pid::Lid(Lid::FreeBusyLocation) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x000080D8,p_type:PType::String},
-        Pid::Lid(Lid::GlobalObjectId) => PropertyInfo::Lid{property_set: PropertySet::Meeting,id:0x00000003,p_type:PType::Binary},
-        Pid::Lid(Lid::HasPicture) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x00008015,p_type:PType::Boolean},
-        Pid::Lid(Lid::HomeAddress) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x0000801A,p_type:PType::String},
-        Pid::Lid(Lid::HomeAddressCountryCode) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x000080DA,p_type:PType::String},
-        Pid::Lid(Lid::Html) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x0000802B,p_type:PType::String},
-        Pid::Lid(Lid::ICalendarDayOfWeekMask) => PropertyInfo::Lid{property_set: PropertySet::Common,id:0x00001001,p_type:PType::Integer32},
-        Pid::Lid(Lid::InboundICalStream) => PropertyInfo::Lid{property_set: PropertySet::Appointment,id:0x0000827A,p_type:PType::Binary},
-        Pid::Lid(Lid::InfoPathFormName) => PropertyInfo::Lid{property_set: PropertySet::Common,id:0x000085B1,p_type:PType::String},
-        Pid::Lid(Lid::InstantMessagingAddress) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x00008062,p_type:PType::String},
-        Pid::Lid(Lid::IntendedBusyStatus) => PropertyInfo::Lid{property_set: PropertySet::Appointment,id:0x00008224,p_type:PType::Integer32},
-        Pid::Lid(Lid::InternetAccountName) => PropertyInfo::Lid{property_set: PropertySet::Common,id:0x00008580,p_type:PType::String},
-        Pid::Lid(Lid::InternetAccountStamp) => PropertyInfo::Lid{property_set: PropertySet::Common,id:0x00008581,p_type:PType::String},
-        Pid::Lid(Lid::IsContactLinked) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x000080E0,p_type:PType::Boolean},
-        Pid::Lid(Lid::IsException) => PropertyInfo::Lid{property_set: PropertySet::Meeting,id:0x0000000A,p_type:PType::Boolean},
-        Pid::Lid(Lid::IsRecurring) => PropertyInfo::Lid{property_set: PropertySet::Meeting,id:0x00000005,p_type:PType::Boolean},
-        Pid::Lid(Lid::IsSilent) => PropertyInfo::Lid{property_set: PropertySet::Meeting,id:0x00000004,p_type:PType::Boolean},
-        Pid::Lid(Lid::LinkedTaskItems) => PropertyInfo::Lid{property_set: PropertySet::Appointment,id:0x0000820C,p_type:PType::MultipleBinary},
-        Pid::Lid(Lid::Location) => PropertyInfo::Lid{property_set: PropertySet::Appointment,id:0x00008208,p_type:PType::String},
-        Pid::Lid(Lid::LogDocumentPosted) => PropertyInfo::Lid{property_set: PropertySet::Log,id:0x00008711,p_type:PType::Boolean},
-        Pid::Lid(Lid::LogDocumentPrinted) => PropertyInfo::Lid{property_set: PropertySet::Log,id:0x0000870E,p_type:PType::Boolean},
-        Pid::Lid(Lid::LogDocumentRouted) => PropertyInfo::Lid{property_set: PropertySet::Log,id:0x00008710,p_type:PType::Boolean},
-        Pid::Lid(Lid::LogDocumentSaved) => PropertyInfo::Lid{property_set: PropertySet::Log,id:0x0000870F,p_type:PType::Boolean},
-        Pid::Lid(Lid::LogDuration) => PropertyInfo::Lid{property_set: PropertySet::Log,id:0x00008707,p_type:PType::Integer32},
-        Pid::Lid(Lid::LogEnd) => PropertyInfo::Lid{property_set: PropertySet::Log,id:0x00008708,p_type:PType::Time},
-        Pid::Lid(Lid::LogFlags) => PropertyInfo::Lid{property_set: PropertySet::Log,id:0x0000870C,p_type:PType::Integer32},
-        Pid::Lid(Lid::LogStart) => PropertyInfo::Lid{property_set: PropertySet::Log,id:0x00008706,p_type:PType::Time},
-        Pid::Lid(Lid::LogType) => PropertyInfo::Lid{property_set: PropertySet::Log,id:0x00008700,p_type:PType::String},
-        Pid::Lid(Lid::LogTypeDesc) => PropertyInfo::Lid{property_set: PropertySet::Log,id:0x00008712,p_type:PType::String},
-        Pid::Lid(Lid::MeetingType) => PropertyInfo::Lid{property_set: PropertySet::Meeting,id:0x00000026,p_type:PType::Integer32},
-        Pid::Lid(Lid::MeetingWorkspaceUrl) => PropertyInfo::Lid{property_set: PropertySet::Appointment,id:0x00008209,p_type:PType::String},
-        Pid::Lid(Lid::MonthInterval) => PropertyInfo::Lid{property_set: PropertySet::Meeting,id:0x00000013,p_type:PType::Integer16},
-        Pid::Lid(Lid::MonthOfYear) => PropertyInfo::Lid{property_set: PropertySet::Common,id:0x00001006,p_type:PType::Integer32},
-        Pid::Lid(Lid::MonthOfYearMask) => PropertyInfo::Lid{property_set: PropertySet::Meeting,id:0x00000017,p_type:PType::Integer32},
-        Pid::Lid(Lid::NetShowUrl) => PropertyInfo::Lid{property_set: PropertySet::Appointment,id:0x00008248,p_type:PType::String},
-        Pid::Lid(Lid::NoEndDateFlag) => PropertyInfo::Lid{property_set: PropertySet::Common,id:0x0000100B,p_type:PType::Boolean},
-        Pid::Lid(Lid::NonSendableBcc) => PropertyInfo::Lid{property_set: PropertySet::Common,id:0x00008538,p_type:PType::String},
-        Pid::Lid(Lid::NonSendableCc) => PropertyInfo::Lid{property_set: PropertySet::Common,id:0x00008537,p_type:PType::String},
-        Pid::Lid(Lid::NonSendableTo) => PropertyInfo::Lid{property_set: PropertySet::Common,id:0x00008536,p_type:PType::String},
-        Pid::Lid(Lid::NonSendBccTrackStatus) => PropertyInfo::Lid{property_set: PropertySet::Common,id:0x00008545,p_type:PType::MultipleInteger32},
-        Pid::Lid(Lid::NonSendCcTrackStatus) => PropertyInfo::Lid{property_set: PropertySet::Common,id:0x00008544,p_type:PType::MultipleInteger32},
-        Pid::Lid(Lid::NonSendToTrackStatus) => PropertyInfo::Lid{property_set: PropertySet::Common,id:0x00008543,p_type:PType::MultipleInteger32},
-        Pid::Lid(Lid::NoteColor) => PropertyInfo::Lid{property_set: PropertySet::Note,id:0x00008B00,p_type:PType::Integer32},
-        Pid::Lid(Lid::NoteHeight) => PropertyInfo::Lid{property_set: PropertySet::Note,id:0x00008B03,p_type:PType::Integer32},
-        Pid::Lid(Lid::NoteWidth) => PropertyInfo::Lid{property_set: PropertySet::Note,id:0x00008B02,p_type:PType::Integer32},
-        Pid::Lid(Lid::NoteX) => PropertyInfo::Lid{property_set: PropertySet::Note,id:0x00008B04,p_type:PType::Integer32},
-        Pid::Lid(Lid::NoteY) => PropertyInfo::Lid{property_set: PropertySet::Note,id:0x00008B05,p_type:PType::Integer32},
-        Pid::Lid(Lid::Occurrences) => PropertyInfo::Lid{property_set: PropertySet::Common,id:0x00001005,p_type:PType::Integer32},
-        Pid::Lid(Lid::OldLocation) => PropertyInfo::Lid{property_set: PropertySet::Meeting,id:0x00000028,p_type:PType::String},
-        Pid::Lid(Lid::OldRecurrenceType) => PropertyInfo::Lid{property_set: PropertySet::Meeting,id:0x00000018,p_type:PType::Integer16},
-        Pid::Lid(Lid::OldWhenEndWhole) => PropertyInfo::Lid{property_set: PropertySet::Meeting,id:0x0000002A,p_type:PType::Time},
-        Pid::Lid(Lid::OldWhenStartWhole) => PropertyInfo::Lid{property_set: PropertySet::Meeting,id:0x00000029,p_type:PType::Time},
-        Pid::Lid(Lid::OnlinePassword) => PropertyInfo::Lid{property_set: PropertySet::Appointment,id:0x00008249,p_type:PType::String},
-        Pid::Lid(Lid::OptionalAttendees) => PropertyInfo::Lid{property_set: PropertySet::Meeting,id:0x00000007,p_type:PType::String},
-        Pid::Lid(Lid::OrganizerAlias) => PropertyInfo::Lid{property_set: PropertySet::Appointment,id:0x00008243,p_type:PType::String},
-        Pid::Lid(Lid::OriginalStoreEntryId) => PropertyInfo::Lid{property_set: PropertySet::Appointment,id:0x00008237,p_type:PType::Binary},
-        Pid::Lid(Lid::OtherAddress) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x0000801C,p_type:PType::String},
-        Pid::Lid(Lid::OtherAddressCountryCode) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x000080DC,p_type:PType::String},
-        Pid::Lid(Lid::OwnerCriticalChange) => PropertyInfo::Lid{property_set: PropertySet::Meeting,id:0x0000001A,p_type:PType::Time},
-        Pid::Lid(Lid::OwnerName) => PropertyInfo::Lid{property_set: PropertySet::Appointment,id:0x0000822E,p_type:PType::String},
-        Pid::Lid(Lid::PendingStateForSiteMailboxDocument) => PropertyInfo::Lid{property_set: PropertySet::Common,id:0x000085E0,p_type:PType::Integer32},
-        Pid::Lid(Lid::PercentComplete) => PropertyInfo::Lid{property_set: PropertySet::Task,id:0x00008102,p_type:PType::Floating64},
-        Pid::Lid(Lid::PostalAddressId) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x00008022,p_type:PType::Integer32},
-        Pid::Lid(Lid::PostRssChannel) => PropertyInfo::Lid{property_set: PropertySet::PostRss,id:0x00008904,p_type:PType::String},
-        Pid::Lid(Lid::PostRssChannelLink) => PropertyInfo::Lid{property_set: PropertySet::PostRss,id:0x00008900,p_type:PType::String},
-        Pid::Lid(Lid::PostRssItemGuid) => PropertyInfo::Lid{property_set: PropertySet::PostRss,id:0x00008903,p_type:PType::String},
-        Pid::Lid(Lid::PostRssItemHash) => PropertyInfo::Lid{property_set: PropertySet::PostRss,id:0x00008902,p_type:PType::Integer32},
-        Pid::Lid(Lid::PostRssItemLink) => PropertyInfo::Lid{property_set: PropertySet::PostRss,id:0x00008901,p_type:PType::String},
-        Pid::Lid(Lid::PostRssItemXml) => PropertyInfo::Lid{property_set: PropertySet::PostRss,id:0x00008905,p_type:PType::String},
-        Pid::Lid(Lid::PostRssSubscription) => PropertyInfo::Lid{property_set: PropertySet::PostRss,id:0x00008906,p_type:PType::String},
-        Pid::Lid(Lid::Private) => PropertyInfo::Lid{property_set: PropertySet::Common,id:0x00008506,p_type:PType::Boolean},
-        Pid::Lid(Lid::PromptSendUpdate) => PropertyInfo::Lid{property_set: PropertySet::Common,id:0x00008045,p_type:PType::Boolean},
-        Pid::Lid(Lid::RecurrenceDuration) => PropertyInfo::Lid{property_set: PropertySet::Common,id:0x0000100D,p_type:PType::Integer32},
-        Pid::Lid(Lid::RecurrencePattern) => PropertyInfo::Lid{property_set: PropertySet::Appointment,id:0x00008232,p_type:PType::String},
-        Pid::Lid(Lid::RecurrenceType) => PropertyInfo::Lid{property_set: PropertySet::Appointment,id:0x00008231,p_type:PType::Integer32},
-        Pid::Lid(Lid::Recurring) => PropertyInfo::Lid{property_set: PropertySet::Appointment,id:0x00008223,p_type:PType::Boolean},
-        Pid::Lid(Lid::ReferenceEntryId) => PropertyInfo::Lid{property_set: PropertySet::Common,id:0x000085BD,p_type:PType::Binary},
-        Pid::Lid(Lid::ReminderDelta) => PropertyInfo::Lid{property_set: PropertySet::Common,id:0x00008501,p_type:PType::Integer32},
-        Pid::Lid(Lid::ReminderFileParameter) => PropertyInfo::Lid{property_set: PropertySet::Common,id:0x0000851F,p_type:PType::String},
-        Pid::Lid(Lid::ReminderOverride) => PropertyInfo::Lid{property_set: PropertySet::Common,id:0x0000851C,p_type:PType::Boolean},
-        Pid::Lid(Lid::ReminderPlaySound) => PropertyInfo::Lid{property_set: PropertySet::Common,id:0x0000851E,p_type:PType::Boolean},
-        Pid::Lid(Lid::ReminderSet) => PropertyInfo::Lid{property_set: PropertySet::Common,id:0x00008503,p_type:PType::Boolean},
-        Pid::Lid(Lid::ReminderSignalTime) => PropertyInfo::Lid{property_set: PropertySet::Common,id:0x00008560,p_type:PType::Time},
-        Pid::Lid(Lid::ReminderTime) => PropertyInfo::Lid{property_set: PropertySet::Common,id:0x00008502,p_type:PType::Time},
-        Pid::Lid(Lid::ReminderTimeDate) => PropertyInfo::Lid{property_set: PropertySet::Common,id:0x00008505,p_type:PType::Time},
-        Pid::Lid(Lid::ReminderTimeTime) => PropertyInfo::Lid{property_set: PropertySet::Common,id:0x00008504,p_type:PType::Time},
-        Pid::Lid(Lid::ReminderType) => PropertyInfo::Lid{property_set: PropertySet::Common,id:0x0000851D,p_type:PType::Integer32},
-        Pid::Lid(Lid::RemoteStatus) => PropertyInfo::Lid{property_set: PropertySet::Common,id:0x00008511,p_type:PType::Integer32},
-        Pid::Lid(Lid::RequiredAttendees) => PropertyInfo::Lid{property_set: PropertySet::Meeting,id:0x00000006,p_type:PType::String},
-        Pid::Lid(Lid::ResourceAttendees) => PropertyInfo::Lid{property_set: PropertySet::Meeting,id:0x00000008,p_type:PType::String},
-        Pid::Lid(Lid::ResponseStatus) => PropertyInfo::Lid{property_set: PropertySet::Appointment,id:0x00008218,p_type:PType::Integer32},
-        Pid::Lid(Lid::ServerProcessed) => PropertyInfo::Lid{property_set: PropertySet::CalendarAssistant,id:0x000085CC,p_type:PType::Boolean},
-        Pid::Lid(Lid::ServerProcessingActions) => PropertyInfo::Lid{property_set: PropertySet::CalendarAssistant,id:0x000085CD,p_type:PType::Integer32},
-        Pid::Lid(Lid::SharingAnonymity) => PropertyInfo::Lid{property_set: PropertySet::Sharing,id:0x00008A19,p_type:PType::Integer32},
-        Pid::Lid(Lid::SharingBindingEntryId) => PropertyInfo::Lid{property_set: PropertySet::Sharing,id:0x00008A2D,p_type:PType::Binary},
-        Pid::Lid(Lid::SharingBrowseUrl) => PropertyInfo::Lid{property_set: PropertySet::Sharing,id:0x00008A51,p_type:PType::String},
-        Pid::Lid(Lid::SharingCapabilities) => PropertyInfo::Lid{property_set: PropertySet::Sharing,id:0x00008A17,p_type:PType::Integer32},
-        Pid::Lid(Lid::SharingConfigurationUrl) => PropertyInfo::Lid{property_set: PropertySet::Sharing,id:0x00008A24,p_type:PType::String},
-        Pid::Lid(Lid::SharingDataRangeEnd) => PropertyInfo::Lid{property_set: PropertySet::Sharing,id:0x00008A45,p_type:PType::Time},
-        Pid::Lid(Lid::SharingDataRangeStart) => PropertyInfo::Lid{property_set: PropertySet::Sharing,id:0x00008A44,p_type:PType::Time},
-        Pid::Lid(Lid::SharingDetail) => PropertyInfo::Lid{property_set: PropertySet::Sharing,id:0x00008A2B,p_type:PType::Integer32},
-        Pid::Lid(Lid::SharingExtensionXml) => PropertyInfo::Lid{property_set: PropertySet::Sharing,id:0x00008A21,p_type:PType::String},
-        Pid::Lid(Lid::SharingFilter) => PropertyInfo::Lid{property_set: PropertySet::Sharing,id:0x00008A13,p_type:PType::Binary},
-        Pid::Lid(Lid::SharingFlags) => PropertyInfo::Lid{property_set: PropertySet::Sharing,id:0x00008A0A,p_type:PType::Integer32},
-        Pid::Lid(Lid::SharingFlavor) => PropertyInfo::Lid{property_set: PropertySet::Sharing,id:0x00008A18,p_type:PType::Integer32},
-        Pid::Lid(Lid::SharingFolderEntryId) => PropertyInfo::Lid{property_set: PropertySet::Sharing,id:0x00008A15,p_type:PType::Binary},
-        Pid::Lid(Lid::SharingIndexEntryId) => PropertyInfo::Lid{property_set: PropertySet::Sharing,id:0x00008A2E,p_type:PType::Binary},
-        Pid::Lid(Lid::SharingInitiatorEntryId) => PropertyInfo::Lid{property_set: PropertySet::Sharing,id:0x00008A09,p_type:PType::Binary},
-        Pid::Lid(Lid::SharingInitiatorName) => PropertyInfo::Lid{property_set: PropertySet::Sharing,id:0x00008A07,p_type:PType::String},
-        Pid::Lid(Lid::SharingInitiatorSmtp) => PropertyInfo::Lid{property_set: PropertySet::Sharing,id:0x00008A08,p_type:PType::String},
-        Pid::Lid(Lid::SharingInstanceGuid) => PropertyInfo::Lid{property_set: PropertySet::Sharing,id:0x00008A1C,p_type:PType::Binary},
-        Pid::Lid(Lid::SharingLastAutoSyncTime) => PropertyInfo::Lid{property_set: PropertySet::Sharing,id:0x00008A55,p_type:PType::Time},
-        Pid::Lid(Lid::SharingLastSyncTime) => PropertyInfo::Lid{property_set: PropertySet::Sharing,id:0x00008A1F,p_type:PType::Time},
-        Pid::Lid(Lid::SharingLocalComment) => PropertyInfo::Lid{property_set: PropertySet::Sharing,id:0x00008A4D,p_type:PType::String},
-        Pid::Lid(Lid::SharingLocalLastModificationTime) => PropertyInfo::Lid{property_set: PropertySet::Sharing,id:0x00008A23,p_type:PType::Time},
-        Pid::Lid(Lid::SharingLocalName) => PropertyInfo::Lid{property_set: PropertySet::Sharing,id:0x00008A0F,p_type:PType::String},
-        Pid::Lid(Lid::SharingLocalPath) => PropertyInfo::Lid{property_set: PropertySet::Sharing,id:0x00008A0E,p_type:PType::String},
-        Pid::Lid(Lid::SharingLocalStoreUid) => PropertyInfo::Lid{property_set: PropertySet::Sharing,id:0x00008A49,p_type:PType::String},
-        Pid::Lid(Lid::SharingLocalType) => PropertyInfo::Lid{property_set: PropertySet::Sharing,id:0x00008A14,p_type:PType::String},
-        Pid::Lid(Lid::SharingLocalUid) => PropertyInfo::Lid{property_set: PropertySet::Sharing,id:0x00008A10,p_type:PType::String},
-        Pid::Lid(Lid::SharingOriginalMessageEntryId) => PropertyInfo::Lid{property_set: PropertySet::Sharing,id:0x00008A29,p_type:PType::Binary},
-        Pid::Lid(Lid::SharingParentBindingEntryId) => PropertyInfo::Lid{property_set: PropertySet::Sharing,id:0x00008A5C,p_type:PType::Binary},
-        Pid::Lid(Lid::SharingParticipants) => PropertyInfo::Lid{property_set: PropertySet::Sharing,id:0x00008A1E,p_type:PType::String},
-        Pid::Lid(Lid::SharingPermissions) => PropertyInfo::Lid{property_set: PropertySet::Sharing,id:0x00008A1B,p_type:PType::Integer32},
-        Pid::Lid(Lid::SharingProviderExtension) => PropertyInfo::Lid{property_set: PropertySet::Sharing,id:0x00008A0B,p_type:PType::String},
-        Pid::Lid(Lid::SharingProviderGuid) => PropertyInfo::Lid{property_set: PropertySet::Sharing,id:0x00008A01,p_type:PType::Binary},
-        Pid::Lid(Lid::SharingProviderName) => PropertyInfo::Lid{property_set: PropertySet::Sharing,id:0x00008A02,p_type:PType::String},
-        Pid::Lid(Lid::SharingProviderUrl) => PropertyInfo::Lid{property_set: PropertySet::Sharing,id:0x00008A03,p_type:PType::String},
-        Pid::Lid(Lid::SharingRangeEnd) => PropertyInfo::Lid{property_set: PropertySet::Sharing,id:0x00008A47,p_type:PType::Integer32},
-        Pid::Lid(Lid::SharingRangeStart) => PropertyInfo::Lid{property_set: PropertySet::Sharing,id:0x00008A46,p_type:PType::Integer32},
-        Pid::Lid(Lid::SharingReciprocation) => PropertyInfo::Lid{property_set: PropertySet::Sharing,id:0x00008A1A,p_type:PType::Integer32},
-        Pid::Lid(Lid::SharingRemoteByteSize) => PropertyInfo::Lid{property_set: PropertySet::Sharing,id:0x00008A4B,p_type:PType::Integer32},
-        Pid::Lid(Lid::SharingRemoteComment) => PropertyInfo::Lid{property_set: PropertySet::Sharing,id:0x00008A2F,p_type:PType::String},
-        Pid::Lid(Lid::SharingRemoteCrc) => PropertyInfo::Lid{property_set: PropertySet::Sharing,id:0x00008A4C,p_type:PType::Integer32},
-        Pid::Lid(Lid::SharingRemoteLastModificationTime) => PropertyInfo::Lid{property_set: PropertySet::Sharing,id:0x00008A22,p_type:PType::Time},
-        Pid::Lid(Lid::SharingRemoteMessageCount) => PropertyInfo::Lid{property_set: PropertySet::Sharing,id:0x00008A4F,p_type:PType::Integer32},
-        Pid::Lid(Lid::SharingRemoteName) => PropertyInfo::Lid{property_set: PropertySet::Sharing,id:0x00008A05,p_type:PType::String},
-        Pid::Lid(Lid::SharingRemotePass) => PropertyInfo::Lid{property_set: PropertySet::Sharing,id:0x00008A0D,p_type:PType::String},
-        Pid::Lid(Lid::SharingRemotePath) => PropertyInfo::Lid{property_set: PropertySet::Sharing,id:0x00008A04,p_type:PType::String},
-        Pid::Lid(Lid::SharingRemoteStoreUid) => PropertyInfo::Lid{property_set: PropertySet::Sharing,id:0x00008A48,p_type:PType::String},
-        Pid::Lid(Lid::SharingRemoteType) => PropertyInfo::Lid{property_set: PropertySet::Sharing,id:0x00008A1D,p_type:PType::String},
-        Pid::Lid(Lid::SharingRemoteUid) => PropertyInfo::Lid{property_set: PropertySet::Sharing,id:0x00008A06,p_type:PType::String},
-        Pid::Lid(Lid::SharingRemoteUser) => PropertyInfo::Lid{property_set: PropertySet::Sharing,id:0x00008A0C,p_type:PType::String},
-        Pid::Lid(Lid::SharingRemoteVersion) => PropertyInfo::Lid{property_set: PropertySet::Sharing,id:0x00008A5B,p_type:PType::String},
-        Pid::Lid(Lid::SharingResponseTime) => PropertyInfo::Lid{property_set: PropertySet::Sharing,id:0x00008A28,p_type:PType::Time},
-        Pid::Lid(Lid::SharingResponseType) => PropertyInfo::Lid{property_set: PropertySet::Sharing,id:0x00008A27,p_type:PType::Integer32},
-        Pid::Lid(Lid::SharingRoamLog) => PropertyInfo::Lid{property_set: PropertySet::Sharing,id:0x00008A4E,p_type:PType::Integer32},
-        Pid::Lid(Lid::SharingStart) => PropertyInfo::Lid{property_set: PropertySet::Sharing,id:0x00008A25,p_type:PType::Time},
-        Pid::Lid(Lid::SharingStatus) => PropertyInfo::Lid{property_set: PropertySet::Sharing,id:0x00008A00,p_type:PType::Integer32},
-        Pid::Lid(Lid::SharingStop) => PropertyInfo::Lid{property_set: PropertySet::Sharing,id:0x00008A26,p_type:PType::Time},
-        Pid::Lid(Lid::SharingSyncFlags) => PropertyInfo::Lid{property_set: PropertySet::Sharing,id:0x00008A60,p_type:PType::Integer32},
-        Pid::Lid(Lid::SharingSyncInterval) => PropertyInfo::Lid{property_set: PropertySet::Sharing,id:0x00008A2A,p_type:PType::Integer32},
-        Pid::Lid(Lid::SharingTimeToLive) => PropertyInfo::Lid{property_set: PropertySet::Sharing,id:0x00008A2C,p_type:PType::Integer32},
-        Pid::Lid(Lid::SharingTimeToLiveAuto) => PropertyInfo::Lid{property_set: PropertySet::Sharing,id:0x00008A56,p_type:PType::Integer32},
-        Pid::Lid(Lid::SharingWorkingHoursDays) => PropertyInfo::Lid{property_set: PropertySet::Sharing,id:0x00008A42,p_type:PType::Integer32},
-        Pid::Lid(Lid::SharingWorkingHoursEnd) => PropertyInfo::Lid{property_set: PropertySet::Sharing,id:0x00008A41,p_type:PType::Time},
-        Pid::Lid(Lid::SharingWorkingHoursStart) => PropertyInfo::Lid{property_set: PropertySet::Sharing,id:0x00008A40,p_type:PType::Time},
-        Pid::Lid(Lid::SharingWorkingHoursTimeZone) => PropertyInfo::Lid{property_set: PropertySet::Sharing,id:0x00008A43,p_type:PType::Binary},
-        Pid::Lid(Lid::SideEffects) => PropertyInfo::Lid{property_set: PropertySet::Common,id:0x00008510,p_type:PType::Integer32},
-        Pid::Lid(Lid::SingleBodyICal) => PropertyInfo::Lid{property_set: PropertySet::Appointment,id:0x0000827B,p_type:PType::Boolean},
-        Pid::Lid(Lid::SmartNoAttach) => PropertyInfo::Lid{property_set: PropertySet::Common,id:0x00008514,p_type:PType::Boolean},
-        Pid::Lid(Lid::SpamOriginalFolder) => PropertyInfo::Lid{property_set: PropertySet::Common,id:0x0000859C,p_type:PType::Binary},
-        Pid::Lid(Lid::StartRecurrenceDate) => PropertyInfo::Lid{property_set: PropertySet::Meeting,id:0x0000000D,p_type:PType::Integer32},
-        Pid::Lid(Lid::StartRecurrenceTime) => PropertyInfo::Lid{property_set: PropertySet::Meeting,id:0x0000000E,p_type:PType::Integer32},
-        Pid::Lid(Lid::TaskAcceptanceState) => PropertyInfo::Lid{property_set: PropertySet::Task,id:0x0000812A,p_type:PType::Integer32},
-        Pid::Lid(Lid::TaskAccepted) => PropertyInfo::Lid{property_set: PropertySet::Task,id:0x00008108,p_type:PType::Boolean},
-        Pid::Lid(Lid::TaskActualEffort) => PropertyInfo::Lid{property_set: PropertySet::Task,id:0x00008110,p_type:PType::Integer32},
-        Pid::Lid(Lid::TaskAssigner) => PropertyInfo::Lid{property_set: PropertySet::Task,id:0x00008121,p_type:PType::String},
-        Pid::Lid(Lid::TaskAssigners) => PropertyInfo::Lid{property_set: PropertySet::Task,id:0x00008117,p_type:PType::Binary},
-        Pid::Lid(Lid::TaskComplete) => PropertyInfo::Lid{property_set: PropertySet::Task,id:0x0000811C,p_type:PType::Boolean},
-        Pid::Lid(Lid::TaskCustomFlags) => PropertyInfo::Lid{property_set: PropertySet::Task,id:0x00008139,p_type:PType::Integer32},
-        Pid::Lid(Lid::TaskDateCompleted) => PropertyInfo::Lid{property_set: PropertySet::Task,id:0x0000810F,p_type:PType::Time},
-        Pid::Lid(Lid::TaskDeadOccurrence) => PropertyInfo::Lid{property_set: PropertySet::Task,id:0x00008109,p_type:PType::Boolean},
-        Pid::Lid(Lid::TaskDueDate) => PropertyInfo::Lid{property_set: PropertySet::Task,id:0x00008105,p_type:PType::Time},
-        Pid::Lid(Lid::TaskEstimatedEffort) => PropertyInfo::Lid{property_set: PropertySet::Task,id:0x00008111,p_type:PType::Integer32},
-        Pid::Lid(Lid::TaskFCreator) => PropertyInfo::Lid{property_set: PropertySet::Task,id:0x0000811E,p_type:PType::Boolean},
-        Pid::Lid(Lid::TaskFFixOffline) => PropertyInfo::Lid{property_set: PropertySet::Task,id:0x0000812C,p_type:PType::Boolean},
-        Pid::Lid(Lid::TaskFRecurring) => PropertyInfo::Lid{property_set: PropertySet::Task,id:0x00008126,p_type:PType::Boolean},
-        Pid::Lid(Lid::TaskGlobalId) => PropertyInfo::Lid{property_set: PropertySet::Common,id:0x00008519,p_type:PType::Binary},
-        Pid::Lid(Lid::TaskHistory) => PropertyInfo::Lid{property_set: PropertySet::Task,id:0x0000811A,p_type:PType::Integer32},
-        Pid::Lid(Lid::TaskLastDelegate) => PropertyInfo::Lid{property_set: PropertySet::Task,id:0x00008125,p_type:PType::String},
-        Pid::Lid(Lid::TaskLastUpdate) => PropertyInfo::Lid{property_set: PropertySet::Task,id:0x00008115,p_type:PType::Time},
-        Pid::Lid(Lid::TaskLastUser) => PropertyInfo::Lid{property_set: PropertySet::Task,id:0x00008122,p_type:PType::String},
-        Pid::Lid(Lid::TaskMode) => PropertyInfo::Lid{property_set: PropertySet::Common,id:0x00008518,p_type:PType::Integer32},
-        Pid::Lid(Lid::TaskMultipleRecipients) => PropertyInfo::Lid{property_set: PropertySet::Task,id:0x00008120,p_type:PType::Integer32},
-        Pid::Lid(Lid::TaskNoCompute) => PropertyInfo::Lid{property_set: PropertySet::Task,id:0x00008124,p_type:PType::Boolean},
-        Pid::Lid(Lid::TaskOrdinal) => PropertyInfo::Lid{property_set: PropertySet::Task,id:0x00008123,p_type:PType::Integer32},
-        Pid::Lid(Lid::TaskOwner) => PropertyInfo::Lid{property_set: PropertySet::Task,id:0x0000811F,p_type:PType::String},
-        Pid::Lid(Lid::TaskOwnership) => PropertyInfo::Lid{property_set: PropertySet::Task,id:0x00008129,p_type:PType::Integer32},
-        Pid::Lid(Lid::TaskRecurrence) => PropertyInfo::Lid{property_set: PropertySet::Task,id:0x00008116,p_type:PType::Binary},
-        Pid::Lid(Lid::TaskResetReminder) => PropertyInfo::Lid{property_set: PropertySet::Task,id:0x00008107,p_type:PType::Boolean},
-        Pid::Lid(Lid::TaskRole) => PropertyInfo::Lid{property_set: PropertySet::Task,id:0x00008127,p_type:PType::String},
-        Pid::Lid(Lid::TaskStartDate) => PropertyInfo::Lid{property_set: PropertySet::Task,id:0x00008104,p_type:PType::Time},
-        Pid::Lid(Lid::TaskState) => PropertyInfo::Lid{property_set: PropertySet::Task,id:0x00008113,p_type:PType::Integer32},
-        Pid::Lid(Lid::TaskStatus) => PropertyInfo::Lid{property_set: PropertySet::Task,id:0x00008101,p_type:PType::Integer32},
-        Pid::Lid(Lid::TaskStatusOnComplete) => PropertyInfo::Lid{property_set: PropertySet::Task,id:0x00008119,p_type:PType::Boolean},
-        Pid::Lid(Lid::TaskUpdates) => PropertyInfo::Lid{property_set: PropertySet::Task,id:0x0000811B,p_type:PType::Boolean},
-        Pid::Lid(Lid::TaskVersion) => PropertyInfo::Lid{property_set: PropertySet::Task,id:0x00008112,p_type:PType::Integer32},
-        Pid::Lid(Lid::TeamTask) => PropertyInfo::Lid{property_set: PropertySet::Task,id:0x00008103,p_type:PType::Boolean},
-        Pid::Lid(Lid::TimeZone) => PropertyInfo::Lid{property_set: PropertySet::Meeting,id:0x0000000C,p_type:PType::Integer32},
-        Pid::Lid(Lid::TimeZoneDescription) => PropertyInfo::Lid{property_set: PropertySet::Appointment,id:0x00008234,p_type:PType::String},
-        Pid::Lid(Lid::TimeZoneStruct) => PropertyInfo::Lid{property_set: PropertySet::Appointment,id:0x00008233,p_type:PType::Binary},
-        Pid::Lid(Lid::ToAttendeesString) => PropertyInfo::Lid{property_set: PropertySet::Appointment,id:0x0000823B,p_type:PType::String},
-        Pid::Lid(Lid::ToDoOrdinalDate) => PropertyInfo::Lid{property_set: PropertySet::Common,id:0x000085A0,p_type:PType::Time},
-        Pid::Lid(Lid::ToDoSubOrdinal) => PropertyInfo::Lid{property_set: PropertySet::Common,id:0x000085A1,p_type:PType::String},
-        Pid::Lid(Lid::ToDoTitle) => PropertyInfo::Lid{property_set: PropertySet::Common,id:0x000085A4,p_type:PType::String},
-        Pid::Lid(Lid::UseTnef) => PropertyInfo::Lid{property_set: PropertySet::Common,id:0x00008582,p_type:PType::Boolean},
-        Pid::Lid(Lid::ValidFlagStringProof) => PropertyInfo::Lid{property_set: PropertySet::Common,id:0x000085BF,p_type:PType::Time},
-        Pid::Lid(Lid::VerbResponse) => PropertyInfo::Lid{property_set: PropertySet::Common,id:0x00008524,p_type:PType::String},
-        Pid::Lid(Lid::VerbStream) => PropertyInfo::Lid{property_set: PropertySet::Common,id:0x00008520,p_type:PType::Binary},
-        Pid::Lid(Lid::WeddingAnniversaryLocal) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x000080DF,p_type:PType::Time},
-        Pid::Lid(Lid::WeekInterval) => PropertyInfo::Lid{property_set: PropertySet::Meeting,id:0x00000012,p_type:PType::Integer16},
-        Pid::Lid(Lid::Where) => PropertyInfo::Lid{property_set: PropertySet::Meeting,id:0x00000002,p_type:PType::String},
-        Pid::Lid(Lid::WorkAddress) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x0000801B,p_type:PType::String},
-        Pid::Lid(Lid::WorkAddressCity) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x00008046,p_type:PType::String},
-        Pid::Lid(Lid::WorkAddressCountry) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x00008049,p_type:PType::String},
-        Pid::Lid(Lid::WorkAddressCountryCode) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x000080DB,p_type:PType::String},
-        Pid::Lid(Lid::WorkAddressPostalCode) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x00008048,p_type:PType::String},
-        Pid::Lid(Lid::WorkAddressPostOfficeBox) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x0000804A,p_type:PType::String},
-        Pid::Lid(Lid::WorkAddressState) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x00008047,p_type:PType::String},
-        Pid::Lid(Lid::WorkAddressStreet) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x00008045,p_type:PType::String},
-        Pid::Lid(Lid::YearInterval) => PropertyInfo::Lid{property_set: PropertySet::Meeting,id:0x00000014,p_type:PType::Integer16},
-        Pid::Lid(Lid::YomiCompanyName) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x0000802E,p_type:PType::String},
-        Pid::Lid(Lid::YomiFirstName) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x0000802C,p_type:PType::String},
-        Pid::Lid(Lid::YomiLastName) => PropertyInfo::Lid{property_set: PropertySet::Address,id:0x0000802D,p_type:PType::String},
-
-        Pid::Name(Name::AcceptLanguage) => PropertyInfo::Name{property_set: PropertySet::Headers,id:"Accept-Language",p_type:PType::String},
-        Pid::Name(Name::ApplicationName) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"AppName",p_type:PType::String},
-        Pid::Name(Name::AttachmentMacContentType) => PropertyInfo::Name{property_set: PropertySet::Attachment,id:"AttachmentMacContentType",p_type:PType::String},
-        Pid::Name(Name::AttachmentMacInfo) => PropertyInfo::Name{property_set: PropertySet::Attachment,id:"AttachmentMacInfo",p_type:PType::Binary},
-        Pid::Name(Name::AttachmentOriginalPermissionType) => PropertyInfo::Name{property_set: PropertySet::Attachment,id:"AttachmentOriginalPermissionType",p_type:PType::Integer32},
-        Pid::Name(Name::AttachmentPermissionType) => PropertyInfo::Name{property_set: PropertySet::Attachment,id:"AttachmentPermissionType",p_type:PType::Integer32},
-        Pid::Name(Name::AttachmentProviderType) => PropertyInfo::Name{property_set: PropertySet::Attachment,id:"AttachmentProviderType",p_type:PType::String},
-        Pid::Name(Name::AudioNotes) => PropertyInfo::Name{property_set: PropertySet::UnifiedMessaging,id:"UMAudioNotes",p_type:PType::String},
-        Pid::Name(Name::Author) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"Author",p_type:PType::String},
-        Pid::Name(Name::AutomaticSpeechRecognitionData) => PropertyInfo::Name{property_set: PropertySet::UnifiedMessaging,id:"AsrData",p_type:PType::Binary},
-        Pid::Name(Name::BirthdayContactAttributionDisplayName) => PropertyInfo::Name{property_set: PropertySet::Address,id:"BirthdayContactAttributionDisplayName",p_type:PType::String},
-        Pid::Name(Name::BirthdayContactEntryId) => PropertyInfo::Name{property_set: PropertySet::Address,id:"BirthdayContactEntryId",p_type:PType::Binary},
-        Pid::Name(Name::BirthdayContactPersonGuid) => PropertyInfo::Name{property_set: PropertySet::Address,id:"BirthdayContactPersonGuid",p_type:PType::Binary},
-        Pid::Name(Name::ByteCount) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"ByteCount",p_type:PType::Integer32},
-        Pid::Name(Name::CalendarAttendeeRole) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"urn:schemas:calendar:attendeerole",p_type:PType::Integer32},
-        Pid::Name(Name::CalendarBusystatus) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"urn:schemas:calendar:busystatus",p_type:PType::String},
-        Pid::Name(Name::CalendarContact) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"urn:schemas:calendar:contact",p_type:PType::String},
-        Pid::Name(Name::CalendarContactUrl) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"urn:schemas:calendar:contacturl",p_type:PType::String},
-        Pid::Name(Name::CalendarCreated) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"urn:schemas:calendar:created",p_type:PType::Time},
-        Pid::Name(Name::CalendarDescriptionUrl) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"urn:schemas:calendar:descriptionurl",p_type:PType::String},
-        Pid::Name(Name::CalendarDuration) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"urn:schemas:calendar:duration",p_type:PType::Integer32},
-        Pid::Name(Name::CalendarExceptionDate) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"urn:schemas:calendar:exdate",p_type:PType::MultipleTime},
-        Pid::Name(Name::CalendarExceptionRule) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"urn:schemas:calendar:exrule",p_type:PType::MultipleString},
-        Pid::Name(Name::CalendarGeoLatitude) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"urn:schemas:calendar:geolatitude",p_type:PType::Floating64},
-        Pid::Name(Name::CalendarGeoLongitude) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"urn:schemas:calendar:geolongitude",p_type:PType::Floating64},
-        Pid::Name(Name::CalendarInstanceType) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"urn:schemas:calendar:instancetype",p_type:PType::Integer32},
-        Pid::Name(Name::CalendarIsOrganizer) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"urn:schemas:calendar:isorganizer",p_type:PType::Boolean},
-        Pid::Name(Name::CalendarLastModified) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"urn:schemas:calendar:lastmodified",p_type:PType::Time},
-        Pid::Name(Name::CalendarLocationUrl) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"urn:schemas:calendar:locationurl",p_type:PType::String},
-        Pid::Name(Name::CalendarMeetingStatus) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"urn:schemas:calendar:meetingstatus",p_type:PType::String},
-        Pid::Name(Name::CalendarMethod) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"urn:schemas:calendar:method",p_type:PType::String},
-        Pid::Name(Name::CalendarProductId) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"urn:schemas:calendar:prodid",p_type:PType::String},
-        Pid::Name(Name::CalendarRecurrenceIdRange) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"urn:schemas:calendar:recurrenceidrange",p_type:PType::String},
-        Pid::Name(Name::CalendarReminderOffset) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"urn:schemas:calendar:reminderoffset",p_type:PType::Integer32},
-        Pid::Name(Name::CalendarResources) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"urn:schemas:calendar:resources",p_type:PType::String},
-        Pid::Name(Name::CalendarRsvp) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"urn:schemas:calendar:rsvp",p_type:PType::Boolean},
-        Pid::Name(Name::CalendarSequence) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"urn:schemas:calendar:sequence",p_type:PType::Integer32},
-        Pid::Name(Name::CalendarTimeZone) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"urn:schemas:calendar:timezone",p_type:PType::String},
-        Pid::Name(Name::CalendarTimeZoneId) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"urn:schemas:calendar:timezoneid",p_type:PType::Integer32},
-        Pid::Name(Name::CalendarTransparent) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"urn:schemas:calendar:transparent",p_type:PType::String},
-        Pid::Name(Name::CalendarUid) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"urn:schemas:calendar:uid",p_type:PType::String},
-        Pid::Name(Name::CalendarVersion) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"urn:schemas:calendar:version",p_type:PType::String},
-        Pid::Name(Name::Category) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"Category",p_type:PType::String},
-        Pid::Name(Name::CharacterCount) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"CharCount",p_type:PType::Integer32},
-        Pid::Name(Name::Comments) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"Comments",p_type:PType::String},
-        Pid::Name(Name::Company) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"Company",p_type:PType::String},
-        Pid::Name(Name::ContentBase) => PropertyInfo::Name{property_set: PropertySet::Headers,id:"Content-Base",p_type:PType::String},
-        Pid::Name(Name::ContentClass) => PropertyInfo::Name{property_set: PropertySet::Headers,id:"Content-Class",p_type:PType::String},
-        Pid::Name(Name::ContentType) => PropertyInfo::Name{property_set: PropertySet::Headers,id:"Content-Type",p_type:PType::String},
-        Pid::Name(Name::CreateDateTimeReadOnly) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"CreateDtmRo",p_type:PType::Time},
-        Pid::Name(Name::CrossReference) => PropertyInfo::Name{property_set: PropertySet::Headers,id:"Xref",p_type:PType::String},
-        Pid::Name(Name::DavId) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"DAV:id",p_type:PType::String},
-        Pid::Name(Name::DavIsCollection) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"DAV:iscollection",p_type:PType::Boolean},
-        Pid::Name(Name::DavIsStructuredDocument) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"DAV:isstructureddocument",p_type:PType::Boolean},
-        Pid::Name(Name::DavParentName) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"DAV:parentname",p_type:PType::String},
-        Pid::Name(Name::DavUid) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"DAV:uid",p_type:PType::String},
-        Pid::Name(Name::DocumentParts) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"DocParts",p_type:PType::MultipleString},
-        Pid::Name(Name::EditTime) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"EditTime",p_type:PType::String},
-        Pid::Name(Name::ExchangeIntendedBusyStatus) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"http://schemas.microsoft.com/exchange/intendedbusystatus",p_type:PType::String},
-        Pid::Name(Name::ExchangeJunkEmailMoveStamp) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"http://schemas.microsoft.com/exchange/junkemailmovestamp",p_type:PType::Integer32},
-        Pid::Name(Name::ExchangeModifyExceptionStructure) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"http://schemas.microsoft.com/exchange/modifyexceptionstruct",p_type:PType::Binary},
-        Pid::Name(Name::ExchangeNoModifyExceptions) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"http://schemas.microsoft.com/exchange/nomodifyexceptions",p_type:PType::Boolean},
-        Pid::Name(Name::ExchangePatternEnd) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"http://schemas.microsoft.com/exchange/patternend",p_type:PType::Time},
-        Pid::Name(Name::ExchangePatternStart) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"http://schemas.microsoft.com/exchange/patternstart",p_type:PType::Time},
-        Pid::Name(Name::ExchangeReminderInterval) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"http://schemas.microsoft.com/exchange/reminderinterval",p_type:PType::Integer32},
-        Pid::Name(Name::ExchDatabaseSchema) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"urn:schemas-microsoft-com:exch-data:baseschema",p_type:PType::MultipleString},
-        Pid::Name(Name::ExchDataExpectedContentClass) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"urn:schemas-microsoft-com:exch-data:expected-content-class",p_type:PType::MultipleString},
-        Pid::Name(Name::ExchDataSchemaCollectionReference) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"urn:schemas-microsoft-com:exch-data:schema-collection-ref",p_type:PType::String},
-        Pid::Name(Name::ExtractedAddresses) => PropertyInfo::Name{property_set: PropertySet::XmlExtrEntities,id:"XmlExtractedAddresses",p_type:PType::String},
-        Pid::Name(Name::ExtractedContacts) => PropertyInfo::Name{property_set: PropertySet::XmlExtrEntities,id:"XmlExtractedContacts",p_type:PType::String},
-        Pid::Name(Name::ExtractedEmails) => PropertyInfo::Name{property_set: PropertySet::XmlExtrEntities,id:"XmlExtractedEmails",p_type:PType::String},
-        Pid::Name(Name::ExtractedMeetings) => PropertyInfo::Name{property_set: PropertySet::XmlExtrEntities,id:"XmlExtractedMeetings",p_type:PType::String},
-        Pid::Name(Name::ExtractedPhones) => PropertyInfo::Name{property_set: PropertySet::XmlExtrEntities,id:"XmlExtractedPhones",p_type:PType::String},
-        Pid::Name(Name::ExtractedTasks) => PropertyInfo::Name{property_set: PropertySet::XmlExtrEntities,id:"XmlExtractedTasks",p_type:PType::String},
-        Pid::Name(Name::ExtractedUrls) => PropertyInfo::Name{property_set: PropertySet::XmlExtrEntities,id:"XmlExtractedUrls",p_type:PType::String},
-        Pid::Name(Name::From) => PropertyInfo::Name{property_set: PropertySet::Headers,id:"From",p_type:PType::String},
-        Pid::Name(Name::HeadingPairs) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"HeadingPairs",p_type:PType::Binary},
-        Pid::Name(Name::HiddenCount) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"HiddenCount",p_type:PType::Integer32},
-        Pid::Name(Name::HttpmailCalendar) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"urn:schemas:httpmail:calendar",p_type:PType::String},
-        Pid::Name(Name::HttpmailHtmlDescription) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"urn:schemas:httpmail:htmldescription",p_type:PType::String},
-        Pid::Name(Name::HttpmailSendMessage) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"urn:schemas:httpmail:sendmsg",p_type:PType::String},
-        Pid::Name(Name::ICalendarRecurrenceDate) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"urn:schemas:calendar:rdate",p_type:PType::MultipleTime},
-        Pid::Name(Name::ICalendarRecurrenceRule) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"urn:schemas:calendar:rrule",p_type:PType::MultipleString},
-        Pid::Name(Name::InternetSubject) => PropertyInfo::Name{property_set: PropertySet::Headers,id:"Subject",p_type:PType::String},
-        Pid::Name(Name::IsBirthdayContactWritable) => PropertyInfo::Name{property_set: PropertySet::Address,id:"IsBirthdayContactWritable",p_type:PType::Boolean},
-        Pid::Name(Name::Keywords) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"Keywords",p_type:PType::MultipleString},
-        Pid::Name(Name::LastAuthor) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"LastAuthor",p_type:PType::String},
-        Pid::Name(Name::LastPrinted) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"LastPrinted",p_type:PType::Time},
-        Pid::Name(Name::LastSaveDateTime) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"LastSaveDtm",p_type:PType::Time},
-        Pid::Name(Name::LineCount) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"LineCount",p_type:PType::Integer32},
-        Pid::Name(Name::LinksDirty) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"LinksDirty",p_type:PType::Boolean},
-        Pid::Name(Name::LocationUrl) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"urn:schemas:calendar:locationurl",p_type:PType::String},
-        Pid::Name(Name::Manager) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"Manager",p_type:PType::String},
-        Pid::Name(Name::MeetingDoNotForward) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"DoNotForward",p_type:PType::Boolean},
-        Pid::Name(Name::MSIPLabels) => PropertyInfo::Name{property_set: PropertySet::Headers,id:"msip_labels",p_type:PType::String},
-        Pid::Name(Name::MultimediaClipCount) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"MMClipCount",p_type:PType::Integer32},
-        Pid::Name(Name::NoteCount) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"NoteCount",p_type:PType::Integer32},
-        Pid::Name(Name::OMSAccountGuid) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"OMSAccountGuid",p_type:PType::String},
-        Pid::Name(Name::OMSMobileModel) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"OMSMobileModel",p_type:PType::String},
-        Pid::Name(Name::OMSScheduleTime) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"OMSScheduleTime",p_type:PType::Time},
-        Pid::Name(Name::OMSServiceType) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"OMSServiceType",p_type:PType::Integer32},
-        Pid::Name(Name::OMSSourceType) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"OMSSourceType",p_type:PType::Integer32},
-        Pid::Name(Name::PageCount) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"PageCount",p_type:PType::Integer32},
-        Pid::Name(Name::ParagraphCount) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"ParCount",p_type:PType::Integer32},
-        Pid::Name(Name::PhishingStamp) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"http://schemas.microsoft.com/outlook/phishingstamp",p_type:PType::Integer32},
-        Pid::Name(Name::PresentationFormat) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"PresFormat",p_type:PType::String},
-        Pid::Name(Name::QuarantineOriginalSender) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"quarantine-original-sender",p_type:PType::String},
-        Pid::Name(Name::RevisionNumber) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"RevNumber",p_type:PType::String},
-        Pid::Name(Name::RightsManagementLicense) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"DRMLicense",p_type:PType::MultipleBinary},
-        Pid::Name(Name::Scale) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"Scale",p_type:PType::Boolean},
-        Pid::Name(Name::Security) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"Security",p_type:PType::Integer32},
-        Pid::Name(Name::SlideCount) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"SlideCount",p_type:PType::Integer32},
-        Pid::Name(Name::Subject) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"Subject",p_type:PType::String},
-        Pid::Name(Name::Template) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"Template",p_type:PType::String},
-        Pid::Name(Name::Thumbnail) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"Thumbnail",p_type:PType::Binary},
-        Pid::Name(Name::Title) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"Title",p_type:PType::String},
-        Pid::Name(Name::WordCount) => PropertyInfo::Name{property_set: PropertySet::PublicStrings,id:"WordCount",p_type:PType::Integer32},
-        Pid::Name(Name::XCallId) => PropertyInfo::Name{property_set: PropertySet::Headers,id:"X-CallID",p_type:PType::String},
-        Pid::Name(Name::XFaxNumberOfPages) => PropertyInfo::Name{property_set: PropertySet::Headers,id:"X-FaxNumberOfPages",p_type:PType::Integer16},
-        Pid::Name(Name::XRequireProtectedPlayOnPhone) => PropertyInfo::Name{property_set: PropertySet::Headers,id:"X-RequireProtectedPlayOnPhone",p_type:PType::Boolean},
-        Pid::Name(Name::XSenderTelephoneNumber) => PropertyInfo::Name{property_set: PropertySet::Headers,id:"X-CallingTelephoneNumber",p_type:PType::String},
-        Pid::Name(Name::XSharingBrowseUrl) => PropertyInfo::Name{property_set: PropertySet::Headers,id:"X-Sharing-Browse-Url",p_type:PType::String},
-        Pid::Name(Name::XSharingCapabilities) => PropertyInfo::Name{property_set: PropertySet::Headers,id:"X-Sharing-Capabilities",p_type:PType::String},
-        Pid::Name(Name::XSharingConfigUrl) => PropertyInfo::Name{property_set: PropertySet::Headers,id:"X-Sharing-Config-Url",p_type:PType::String},
-        Pid::Name(Name::XSharingExendedCaps) => PropertyInfo::Name{property_set: PropertySet::Headers,id:"X-Sharing-Exended-Caps",p_type:PType::String},
-        Pid::Name(Name::XSharingFlavor) => PropertyInfo::Name{property_set: PropertySet::Headers,id:"X-Sharing-Flavor",p_type:PType::String},
-        Pid::Name(Name::XSharingInstanceGuid) => PropertyInfo::Name{property_set: PropertySet::Headers,id:"X-Sharing-Instance-Guid",p_type:PType::String},
-        Pid::Name(Name::XSharingLocalType) => PropertyInfo::Name{property_set: PropertySet::Headers,id:"X-Sharing-Local-Type",p_type:PType::String},
-        Pid::Name(Name::XSharingProviderGuid) => PropertyInfo::Name{property_set: PropertySet::Headers,id:"X-Sharing-Provider-Guid",p_type:PType::String},
-        Pid::Name(Name::XSharingProviderName) => PropertyInfo::Name{property_set: PropertySet::Headers,id:"X-Sharing-Provider-Name",p_type:PType::String},
-        Pid::Name(Name::XSharingProviderUrl) => PropertyInfo::Name{property_set: PropertySet::Headers,id:"X-Sharing-Provider-Url",p_type:PType::String},
-        Pid::Name(Name::XSharingRemoteName) => PropertyInfo::Name{property_set: PropertySet::Headers,id:"X-Sharing-Remote-Name",p_type:PType::String},
-        Pid::Name(Name::XSharingRemotePath) => PropertyInfo::Name{property_set: PropertySet::Headers,id:"X-Sharing-Remote-Path",p_type:PType::String},
-        Pid::Name(Name::XSharingRemoteStoreUid) => PropertyInfo::Name{property_set: PropertySet::Headers,id:"X-Sharing-Remote-Store-Uid",p_type:PType::String},
-        Pid::Name(Name::XSharingRemoteType) => PropertyInfo::Name{property_set: PropertySet::Headers,id:"X-Sharing-Remote-Type",p_type:PType::String},
-        Pid::Name(Name::XSharingRemoteUid) => PropertyInfo::Name{property_set: PropertySet::Headers,id:"X-Sharing-Remote-Uid",p_type:PType::String},
-        Pid::Name(Name::XVoiceMessageAttachmentOrder) => PropertyInfo::Name{property_set: PropertySet::Headers,id:"X-AttachmentOrder",p_type:PType::String},
-        Pid::Name(Name::XVoiceMessageDuration) => PropertyInfo::Name{property_set: PropertySet::Headers,id:"X-VoiceMessageDuration",p_type:PType::Integer16},
-        Pid::Name(Name::XVoiceMessageSenderName) => PropertyInfo::Name{property_set: PropertySet::Headers,id:"X-VoiceMessageSenderName",p_type:PType::String},
+        Pid::Lid(lid) => lid_detail(lid),
+        Pid::Name(name) => name_detail(name),
 
         Pid::Tag(Tag::Access) => PropertyInfo::Tag{id:0x0FF4,p_type:PType::Integer32},
         Pid::Tag(Tag::AccessControlListData) => PropertyInfo::Tag{id:0x3FE0,p_type:PType::Binary},
@@ -1120,3 +617,562 @@ fn to_detail(pid: Pid) -> PropertyInfo {
         Pid::Tag(Tag::Unknown(n)) => PropertyInfo::Tag{id:n,p_type:PType::Integer32},
     }
 }
+
+/// Generates the properties of each LID from one table, along with the
+/// reverse lookup from the property set and ID.
+macro_rules! lids {
+    ($($lid:ident => ($property_set:ident, $id:literal, $p_type:ident),)*) => {
+        fn lid_detail(lid: Lid) -> PropertyInfo {
+            match lid {
+                $(Lid::$lid => PropertyInfo::Lid {
+                    property_set: PropertySet::$property_set,
+                    id: $id,
+                    p_type: PType::$p_type,
+                },)*
+            }
+        }
+
+        /// The known LID with the given ID in `property_set`.
+        pub fn lid_from_id(property_set: PropertySet, id: u32) -> Option<Lid> {
+            match (property_set, id) {
+                $((PropertySet::$property_set, $id) => Some(Lid::$lid),)*
+                _ => None,
+            }
+        }
+    };
+}
+
+/// As `lids!`, for the named properties with string names.
+macro_rules! names {
+    ($($name:ident => ($property_set:ident, $id:literal, $p_type:ident),)*) => {
+        fn name_detail(name: Name) -> PropertyInfo {
+            match name {
+                $(Name::$name => PropertyInfo::Name {
+                    property_set: PropertySet::$property_set,
+                    id: $id,
+                    p_type: PType::$p_type,
+                },)*
+            }
+        }
+
+        /// The known named property with the given name in `property_set`.
+        // MS-OXPROPS gives some properties two names, and the first is used.
+        #[allow(unreachable_patterns)]
+        pub fn name_from_string(property_set: PropertySet, name: &str) -> Option<Name> {
+            match (property_set, name) {
+                $((PropertySet::$property_set, $id) => Some(Name::$name),)*
+                _ => None,
+            }
+        }
+    };
+}
+
+lids! {
+    AddressBookProviderArrayType => (Address, 0x00008029, Integer32),
+    AddressBookProviderEmailList => (Address, 0x00008028, MultipleInteger32),
+    AddressCountryCode => (Address, 0x000080DD, String),
+    AgingDontAgeMe => (Common, 0x0000850E, Boolean),
+    AllAttendeesString => (Appointment, 0x00008238, String),
+    AllowExternalCheck => (Appointment, 0x00008246, Boolean),
+    AnniversaryEventEntryId => (Address, 0x0000804E, Binary),
+    AppointmentAuxiliaryFlags => (Appointment, 0x00008207, Integer32),
+    AppointmentColor => (Appointment, 0x00008214, Integer32),
+    AppointmentCounterProposal => (Appointment, 0x00008257, Boolean),
+    AppointmentDuration => (Appointment, 0x00008213, Integer32),
+    AppointmentEndDate => (Appointment, 0x00008211, Time),
+    AppointmentEndTime => (Appointment, 0x00008210, Time),
+    AppointmentEndWhole => (Appointment, 0x0000820E, Time),
+    AppointmentLastSequence => (Appointment, 0x00008203, Integer32),
+    AppointmentMessageClass => (Meeting, 0x00000024, String),
+    AppointmentNotAllowPropose => (Appointment, 0x0000825A, Boolean),
+    AppointmentProposalNumber => (Appointment, 0x00008259, Integer32),
+    AppointmentProposedDuration => (Appointment, 0x00008256, Integer32),
+    AppointmentProposedEndWhole => (Appointment, 0x00008251, Time),
+    AppointmentProposedStartWhole => (Appointment, 0x00008250, Time),
+    AppointmentRecur => (Appointment, 0x00008216, Binary),
+    AppointmentReplyName => (Appointment, 0x00008230, String),
+    AppointmentReplyTime => (Appointment, 0x00008220, Time),
+    AppointmentSequence => (Appointment, 0x00008201, Integer32),
+    AppointmentSequenceTime => (Appointment, 0x00008202, Time),
+    AppointmentStartDate => (Appointment, 0x00008212, Time),
+    AppointmentStartTime => (Appointment, 0x0000820F, Time),
+    AppointmentStartWhole => (Appointment, 0x0000820D, Time),
+    AppointmentStateFlags => (Appointment, 0x00008217, Integer32),
+    AppointmentSubType => (Appointment, 0x00008215, Boolean),
+    AppointmentTimeZoneDefinitionEndDisplay => (Appointment, 0x0000825F, Binary),
+    AppointmentTimeZoneDefinitionRecur => (Appointment, 0x00008260, Binary),
+    AppointmentTimeZoneDefinitionStartDisplay => (Appointment, 0x0000825E, Binary),
+    AppointmentUnsendableRecipients => (Appointment, 0x0000825D, Binary),
+    AppointmentUpdateTime => (Appointment, 0x00008226, Time),
+    AttendeeCriticalChange => (Meeting, 0x00000001, Time),
+    AutoFillLocation => (Appointment, 0x0000823A, Boolean),
+    AutoLog => (Address, 0x00008025, Boolean),
+    AutoProcessState => (Common, 0x0000851A, Integer32),
+    AutoStartCheck => (Appointment, 0x00008244, Boolean),
+    Billing => (Common, 0x00008535, String),
+    BirthdayEventEntryId => (Address, 0x0000804D, Binary),
+    BirthdayLocal => (Address, 0x000080DE, Time),
+    BusinessCardCardPicture => (Address, 0x00008041, Binary),
+    BusinessCardDisplayDefinition => (Address, 0x00008040, Binary),
+    BusyStatus => (Appointment, 0x00008205, Integer32),
+    CalendarType => (Meeting, 0x0000001C, Integer32),
+    Categories => (PublicStrings, 0x00009000, MultipleString),
+    CcAttendeesString => (Appointment, 0x0000823C, String),
+    ChangeHighlight => (Appointment, 0x00008204, Integer32),
+    Classification => (Common, 0x000085B6, String),
+    ClassificationDescription => (Common, 0x000085B7, String),
+    ClassificationGuid => (Common, 0x000085B8, String),
+    ClassificationKeep => (Common, 0x000085BA, Boolean),
+    Classified => (Common, 0x000085B5, Boolean),
+    CleanGlobalObjectId => (Meeting, 0x00000023, Binary),
+    ClientIntent => (CalendarAssistant, 0x00000015, Integer32),
+    ClipEnd => (Appointment, 0x00008236, Time),
+    ClipStart => (Appointment, 0x00008235, Time),
+    CollaborateDoc => (Appointment, 0x00008247, String),
+    CommonEnd => (Common, 0x00008517, Time),
+    CommonStart => (Common, 0x00008516, Time),
+    Companies => (Common, 0x00008539, MultipleString),
+    ConferencingCheck => (Appointment, 0x00008240, Boolean),
+    ConferencingType => (Appointment, 0x00008241, Integer32),
+    ContactCharacterSet => (Address, 0x00008023, Integer32),
+    ContactItemData => (Address, 0x00008007, MultipleInteger32),
+    ContactLinkedGlobalAddressListEntryId => (Address, 0x000080E2, Binary),
+    ContactLinkEntry => (Common, 0x00008585, Binary),
+    ContactLinkGlobalAddressListLinkId => (Address, 0x000080E8, Guid),
+    ContactLinkGlobalAddressListLinkState => (Address, 0x000080E6, Integer32),
+    ContactLinkLinkRejectHistory => (Address, 0x000080E5, MultipleBinary),
+    ContactLinkName => (Common, 0x00008586, String),
+    ContactLinkSearchKey => (Common, 0x00008584, Binary),
+    ContactLinkSMTPAddressCache => (Address, 0x000080E3, MultipleString),
+    Contacts => (Common, 0x0000853A, MultipleString),
+    ContactUserField1 => (Address, 0x0000804F, String),
+    ContactUserField2 => (Address, 0x00008050, String),
+    ContactUserField3 => (Address, 0x00008051, String),
+    ContactUserField4 => (Address, 0x00008052, String),
+    ConversationActionLastAppliedTime => (Common, 0x000085CA, Time),
+    ConversationActionMaxDeliveryTime => (Common, 0x000085C8, Time),
+    ConversationActionMoveFolderEid => (Common, 0x000085C6, Binary),
+    ConversationActionMoveStoreEid => (Common, 0x000085C7, Binary),
+    ConversationActionVersion => (Common, 0x000085CB, Integer32),
+    ConversationProcessed => (Common, 0x000085C9, Integer32),
+    CurrentVersion => (Common, 0x00008552, Integer32),
+    CurrentVersionName => (Common, 0x00008554, String),
+    DayInterval => (Meeting, 0x00000011, Integer16),
+    DayOfMonth => (Common, 0x00001000, Integer32),
+    DelegateMail => (Meeting, 0x00000009, Boolean),
+    Department => (Address, 0x00008010, String),
+    Directory => (Appointment, 0x00008242, String),
+    DistributionListChecksum => (Address, 0x0000804C, Integer32),
+    DistributionListMembers => (Address, 0x00008055, MultipleBinary),
+    DistributionListName => (Address, 0x00008053, String),
+    DistributionListOneOffMembers => (Address, 0x00008054, MultipleBinary),
+    DistributionListStream => (Address, 0x00008064, Binary),
+    Email1AddressType => (Address, 0x00008082, String),
+    Email1DisplayName => (Address, 0x00008080, String),
+    Email1EmailAddress => (Address, 0x00008083, String),
+    Email1OriginalDisplayName => (Address, 0x00008084, String),
+    Email1OriginalEntryId => (Address, 0x00008085, Binary),
+    Email2AddressType => (Address, 0x00008092, String),
+    Email2DisplayName => (Address, 0x00008090, String),
+    Email2EmailAddress => (Address, 0x00008093, String),
+    Email2OriginalDisplayName => (Address, 0x00008094, String),
+    Email2OriginalEntryId => (Address, 0x00008095, Binary),
+    Email3AddressType => (Address, 0x000080A2, String),
+    Email3DisplayName => (Address, 0x000080A0, String),
+    Email3EmailAddress => (Address, 0x000080A3, String),
+    Email3OriginalDisplayName => (Address, 0x000080A4, String),
+    Email3OriginalEntryId => (Address, 0x000080A5, Binary),
+    EndRecurrenceDate => (Meeting, 0x0000000F, Integer32),
+    EndRecurrenceTime => (Meeting, 0x00000010, Integer32),
+    ExceptionReplaceTime => (Appointment, 0x00008228, Time),
+    Fax1AddressType => (Address, 0x000080B2, String),
+    Fax1EmailAddress => (Address, 0x000080B3, String),
+    Fax1OriginalDisplayName => (Address, 0x000080B4, String),
+    Fax1OriginalEntryId => (Address, 0x000080B5, Binary),
+    Fax2AddressType => (Address, 0x000080C2, String),
+    Fax2EmailAddress => (Address, 0x000080C3, String),
+    Fax2OriginalDisplayName => (Address, 0x000080C4, String),
+    Fax2OriginalEntryId => (Address, 0x000080C5, Binary),
+    Fax3AddressType => (Address, 0x000080D2, String),
+    Fax3EmailAddress => (Address, 0x000080D3, String),
+    Fax3OriginalDisplayName => (Address, 0x000080D4, String),
+    Fax3OriginalEntryId => (Address, 0x000080D5, Binary),
+    FExceptionalAttendees => (Appointment, 0x0000822B, Boolean),
+    FExceptionalBody => (Appointment, 0x00008206, Boolean),
+    FileUnder => (Address, 0x00008005, String),
+    FileUnderId => (Address, 0x00008006, Integer32),
+    FileUnderList => (Address, 0x00008026, MultipleInteger32),
+    FInvited => (Appointment, 0x00008229, Boolean),
+    FlagRequest => (Common, 0x00008530, String),
+    FlagString => (Common, 0x000085C0, Integer32),
+    ForwardInstance => (Appointment, 0x0000820A, Boolean),
+    ForwardNotificationRecipients => (Appointment, 0x00008261, Binary),
+    FOthersAppointment => (Appointment, 0x0000822F, Boolean),
+    FreeBusyLocation => (Address, 0x000080D8, String),
+    GlobalObjectId => (Meeting, 0x00000003, Binary),
+    HasPicture => (Address, 0x00008015, Boolean),
+    HomeAddress => (Address, 0x0000801A, String),
+    HomeAddressCountryCode => (Address, 0x000080DA, String),
+    Html => (Address, 0x0000802B, String),
+    ICalendarDayOfWeekMask => (Common, 0x00001001, Integer32),
+    InboundICalStream => (Appointment, 0x0000827A, Binary),
+    InfoPathFormName => (Common, 0x000085B1, String),
+    InstantMessagingAddress => (Address, 0x00008062, String),
+    IntendedBusyStatus => (Appointment, 0x00008224, Integer32),
+    InternetAccountName => (Common, 0x00008580, String),
+    InternetAccountStamp => (Common, 0x00008581, String),
+    IsContactLinked => (Address, 0x000080E0, Boolean),
+    IsException => (Meeting, 0x0000000A, Boolean),
+    IsRecurring => (Meeting, 0x00000005, Boolean),
+    IsSilent => (Meeting, 0x00000004, Boolean),
+    LinkedTaskItems => (Appointment, 0x0000820C, MultipleBinary),
+    Location => (Appointment, 0x00008208, String),
+    LogDocumentPosted => (Log, 0x00008711, Boolean),
+    LogDocumentPrinted => (Log, 0x0000870E, Boolean),
+    LogDocumentRouted => (Log, 0x00008710, Boolean),
+    LogDocumentSaved => (Log, 0x0000870F, Boolean),
+    LogDuration => (Log, 0x00008707, Integer32),
+    LogEnd => (Log, 0x00008708, Time),
+    LogFlags => (Log, 0x0000870C, Integer32),
+    LogStart => (Log, 0x00008706, Time),
+    LogType => (Log, 0x00008700, String),
+    LogTypeDesc => (Log, 0x00008712, String),
+    MeetingType => (Meeting, 0x00000026, Integer32),
+    MeetingWorkspaceUrl => (Appointment, 0x00008209, String),
+    MonthInterval => (Meeting, 0x00000013, Integer16),
+    MonthOfYear => (Common, 0x00001006, Integer32),
+    MonthOfYearMask => (Meeting, 0x00000017, Integer32),
+    NetShowUrl => (Appointment, 0x00008248, String),
+    NoEndDateFlag => (Common, 0x0000100B, Boolean),
+    NonSendableBcc => (Common, 0x00008538, String),
+    NonSendableCc => (Common, 0x00008537, String),
+    NonSendableTo => (Common, 0x00008536, String),
+    NonSendBccTrackStatus => (Common, 0x00008545, MultipleInteger32),
+    NonSendCcTrackStatus => (Common, 0x00008544, MultipleInteger32),
+    NonSendToTrackStatus => (Common, 0x00008543, MultipleInteger32),
+    NoteColor => (Note, 0x00008B00, Integer32),
+    NoteHeight => (Note, 0x00008B03, Integer32),
+    NoteWidth => (Note, 0x00008B02, Integer32),
+    NoteX => (Note, 0x00008B04, Integer32),
+    NoteY => (Note, 0x00008B05, Integer32),
+    Occurrences => (Common, 0x00001005, Integer32),
+    OldLocation => (Meeting, 0x00000028, String),
+    OldRecurrenceType => (Meeting, 0x00000018, Integer16),
+    OldWhenEndWhole => (Meeting, 0x0000002A, Time),
+    OldWhenStartWhole => (Meeting, 0x00000029, Time),
+    OnlinePassword => (Appointment, 0x00008249, String),
+    OptionalAttendees => (Meeting, 0x00000007, String),
+    OrganizerAlias => (Appointment, 0x00008243, String),
+    OriginalStoreEntryId => (Appointment, 0x00008237, Binary),
+    OtherAddress => (Address, 0x0000801C, String),
+    OtherAddressCountryCode => (Address, 0x000080DC, String),
+    OwnerCriticalChange => (Meeting, 0x0000001A, Time),
+    OwnerName => (Appointment, 0x0000822E, String),
+    PendingStateForSiteMailboxDocument => (Common, 0x000085E0, Integer32),
+    PercentComplete => (Task, 0x00008102, Floating64),
+    PostalAddressId => (Address, 0x00008022, Integer32),
+    PostRssChannel => (PostRss, 0x00008904, String),
+    PostRssChannelLink => (PostRss, 0x00008900, String),
+    PostRssItemGuid => (PostRss, 0x00008903, String),
+    PostRssItemHash => (PostRss, 0x00008902, Integer32),
+    PostRssItemLink => (PostRss, 0x00008901, String),
+    PostRssItemXml => (PostRss, 0x00008905, String),
+    PostRssSubscription => (PostRss, 0x00008906, String),
+    Private => (Common, 0x00008506, Boolean),
+    PromptSendUpdate => (Common, 0x00008045, Boolean),
+    RecurrenceDuration => (Common, 0x0000100D, Integer32),
+    RecurrencePattern => (Appointment, 0x00008232, String),
+    RecurrenceType => (Appointment, 0x00008231, Integer32),
+    Recurring => (Appointment, 0x00008223, Boolean),
+    ReferenceEntryId => (Common, 0x000085BD, Binary),
+    ReminderDelta => (Common, 0x00008501, Integer32),
+    ReminderFileParameter => (Common, 0x0000851F, String),
+    ReminderOverride => (Common, 0x0000851C, Boolean),
+    ReminderPlaySound => (Common, 0x0000851E, Boolean),
+    ReminderSet => (Common, 0x00008503, Boolean),
+    ReminderSignalTime => (Common, 0x00008560, Time),
+    ReminderTime => (Common, 0x00008502, Time),
+    ReminderTimeDate => (Common, 0x00008505, Time),
+    ReminderTimeTime => (Common, 0x00008504, Time),
+    ReminderType => (Common, 0x0000851D, Integer32),
+    RemoteStatus => (Common, 0x00008511, Integer32),
+    RequiredAttendees => (Meeting, 0x00000006, String),
+    ResourceAttendees => (Meeting, 0x00000008, String),
+    ResponseStatus => (Appointment, 0x00008218, Integer32),
+    ServerProcessed => (CalendarAssistant, 0x000085CC, Boolean),
+    ServerProcessingActions => (CalendarAssistant, 0x000085CD, Integer32),
+    SharingAnonymity => (Sharing, 0x00008A19, Integer32),
+    SharingBindingEntryId => (Sharing, 0x00008A2D, Binary),
+    SharingBrowseUrl => (Sharing, 0x00008A51, String),
+    SharingCapabilities => (Sharing, 0x00008A17, Integer32),
+    SharingConfigurationUrl => (Sharing, 0x00008A24, String),
+    SharingDataRangeEnd => (Sharing, 0x00008A45, Time),
+    SharingDataRangeStart => (Sharing, 0x00008A44, Time),
+    SharingDetail => (Sharing, 0x00008A2B, Integer32),
+    SharingExtensionXml => (Sharing, 0x00008A21, String),
+    SharingFilter => (Sharing, 0x00008A13, Binary),
+    SharingFlags => (Sharing, 0x00008A0A, Integer32),
+    SharingFlavor => (Sharing, 0x00008A18, Integer32),
+    SharingFolderEntryId => (Sharing, 0x00008A15, Binary),
+    SharingIndexEntryId => (Sharing, 0x00008A2E, Binary),
+    SharingInitiatorEntryId => (Sharing, 0x00008A09, Binary),
+    SharingInitiatorName => (Sharing, 0x00008A07, String),
+    SharingInitiatorSmtp => (Sharing, 0x00008A08, String),
+    SharingInstanceGuid => (Sharing, 0x00008A1C, Binary),
+    SharingLastAutoSyncTime => (Sharing, 0x00008A55, Time),
+    SharingLastSyncTime => (Sharing, 0x00008A1F, Time),
+    SharingLocalComment => (Sharing, 0x00008A4D, String),
+    SharingLocalLastModificationTime => (Sharing, 0x00008A23, Time),
+    SharingLocalName => (Sharing, 0x00008A0F, String),
+    SharingLocalPath => (Sharing, 0x00008A0E, String),
+    SharingLocalStoreUid => (Sharing, 0x00008A49, String),
+    SharingLocalType => (Sharing, 0x00008A14, String),
+    SharingLocalUid => (Sharing, 0x00008A10, String),
+    SharingOriginalMessageEntryId => (Sharing, 0x00008A29, Binary),
+    SharingParentBindingEntryId => (Sharing, 0x00008A5C, Binary),
+    SharingParticipants => (Sharing, 0x00008A1E, String),
+    SharingPermissions => (Sharing, 0x00008A1B, Integer32),
+    SharingProviderExtension => (Sharing, 0x00008A0B, String),
+    SharingProviderGuid => (Sharing, 0x00008A01, Binary),
+    SharingProviderName => (Sharing, 0x00008A02, String),
+    SharingProviderUrl => (Sharing, 0x00008A03, String),
+    SharingRangeEnd => (Sharing, 0x00008A47, Integer32),
+    SharingRangeStart => (Sharing, 0x00008A46, Integer32),
+    SharingReciprocation => (Sharing, 0x00008A1A, Integer32),
+    SharingRemoteByteSize => (Sharing, 0x00008A4B, Integer32),
+    SharingRemoteComment => (Sharing, 0x00008A2F, String),
+    SharingRemoteCrc => (Sharing, 0x00008A4C, Integer32),
+    SharingRemoteLastModificationTime => (Sharing, 0x00008A22, Time),
+    SharingRemoteMessageCount => (Sharing, 0x00008A4F, Integer32),
+    SharingRemoteName => (Sharing, 0x00008A05, String),
+    SharingRemotePass => (Sharing, 0x00008A0D, String),
+    SharingRemotePath => (Sharing, 0x00008A04, String),
+    SharingRemoteStoreUid => (Sharing, 0x00008A48, String),
+    SharingRemoteType => (Sharing, 0x00008A1D, String),
+    SharingRemoteUid => (Sharing, 0x00008A06, String),
+    SharingRemoteUser => (Sharing, 0x00008A0C, String),
+    SharingRemoteVersion => (Sharing, 0x00008A5B, String),
+    SharingResponseTime => (Sharing, 0x00008A28, Time),
+    SharingResponseType => (Sharing, 0x00008A27, Integer32),
+    SharingRoamLog => (Sharing, 0x00008A4E, Integer32),
+    SharingStart => (Sharing, 0x00008A25, Time),
+    SharingStatus => (Sharing, 0x00008A00, Integer32),
+    SharingStop => (Sharing, 0x00008A26, Time),
+    SharingSyncFlags => (Sharing, 0x00008A60, Integer32),
+    SharingSyncInterval => (Sharing, 0x00008A2A, Integer32),
+    SharingTimeToLive => (Sharing, 0x00008A2C, Integer32),
+    SharingTimeToLiveAuto => (Sharing, 0x00008A56, Integer32),
+    SharingWorkingHoursDays => (Sharing, 0x00008A42, Integer32),
+    SharingWorkingHoursEnd => (Sharing, 0x00008A41, Time),
+    SharingWorkingHoursStart => (Sharing, 0x00008A40, Time),
+    SharingWorkingHoursTimeZone => (Sharing, 0x00008A43, Binary),
+    SideEffects => (Common, 0x00008510, Integer32),
+    SingleBodyICal => (Appointment, 0x0000827B, Boolean),
+    SmartNoAttach => (Common, 0x00008514, Boolean),
+    SpamOriginalFolder => (Common, 0x0000859C, Binary),
+    StartRecurrenceDate => (Meeting, 0x0000000D, Integer32),
+    StartRecurrenceTime => (Meeting, 0x0000000E, Integer32),
+    TaskAcceptanceState => (Task, 0x0000812A, Integer32),
+    TaskAccepted => (Task, 0x00008108, Boolean),
+    TaskActualEffort => (Task, 0x00008110, Integer32),
+    TaskAssigner => (Task, 0x00008121, String),
+    TaskAssigners => (Task, 0x00008117, Binary),
+    TaskComplete => (Task, 0x0000811C, Boolean),
+    TaskCustomFlags => (Task, 0x00008139, Integer32),
+    TaskDateCompleted => (Task, 0x0000810F, Time),
+    TaskDeadOccurrence => (Task, 0x00008109, Boolean),
+    TaskDueDate => (Task, 0x00008105, Time),
+    TaskEstimatedEffort => (Task, 0x00008111, Integer32),
+    TaskFCreator => (Task, 0x0000811E, Boolean),
+    TaskFFixOffline => (Task, 0x0000812C, Boolean),
+    TaskFRecurring => (Task, 0x00008126, Boolean),
+    TaskGlobalId => (Common, 0x00008519, Binary),
+    TaskHistory => (Task, 0x0000811A, Integer32),
+    TaskLastDelegate => (Task, 0x00008125, String),
+    TaskLastUpdate => (Task, 0x00008115, Time),
+    TaskLastUser => (Task, 0x00008122, String),
+    TaskMode => (Common, 0x00008518, Integer32),
+    TaskMultipleRecipients => (Task, 0x00008120, Integer32),
+    TaskNoCompute => (Task, 0x00008124, Boolean),
+    TaskOrdinal => (Task, 0x00008123, Integer32),
+    TaskOwner => (Task, 0x0000811F, String),
+    TaskOwnership => (Task, 0x00008129, Integer32),
+    TaskRecurrence => (Task, 0x00008116, Binary),
+    TaskResetReminder => (Task, 0x00008107, Boolean),
+    TaskRole => (Task, 0x00008127, String),
+    TaskStartDate => (Task, 0x00008104, Time),
+    TaskState => (Task, 0x00008113, Integer32),
+    TaskStatus => (Task, 0x00008101, Integer32),
+    TaskStatusOnComplete => (Task, 0x00008119, Boolean),
+    TaskUpdates => (Task, 0x0000811B, Boolean),
+    TaskVersion => (Task, 0x00008112, Integer32),
+    TeamTask => (Task, 0x00008103, Boolean),
+    TimeZone => (Meeting, 0x0000000C, Integer32),
+    TimeZoneDescription => (Appointment, 0x00008234, String),
+    TimeZoneStruct => (Appointment, 0x00008233, Binary),
+    ToAttendeesString => (Appointment, 0x0000823B, String),
+    ToDoOrdinalDate => (Common, 0x000085A0, Time),
+    ToDoSubOrdinal => (Common, 0x000085A1, String),
+    ToDoTitle => (Common, 0x000085A4, String),
+    UseTnef => (Common, 0x00008582, Boolean),
+    ValidFlagStringProof => (Common, 0x000085BF, Time),
+    VerbResponse => (Common, 0x00008524, String),
+    VerbStream => (Common, 0x00008520, Binary),
+    WeddingAnniversaryLocal => (Address, 0x000080DF, Time),
+    WeekInterval => (Meeting, 0x00000012, Integer16),
+    Where => (Meeting, 0x00000002, String),
+    WorkAddress => (Address, 0x0000801B, String),
+    WorkAddressCity => (Address, 0x00008046, String),
+    WorkAddressCountry => (Address, 0x00008049, String),
+    WorkAddressCountryCode => (Address, 0x000080DB, String),
+    WorkAddressPostalCode => (Address, 0x00008048, String),
+    WorkAddressPostOfficeBox => (Address, 0x0000804A, String),
+    WorkAddressState => (Address, 0x00008047, String),
+    WorkAddressStreet => (Address, 0x00008045, String),
+    YearInterval => (Meeting, 0x00000014, Integer16),
+    YomiCompanyName => (Address, 0x0000802E, String),
+    YomiFirstName => (Address, 0x0000802C, String),
+    YomiLastName => (Address, 0x0000802D, String),
+}
+
+names! {
+    AcceptLanguage => (Headers, "Accept-Language", String),
+    ApplicationName => (PublicStrings, "AppName", String),
+    AttachmentMacContentType => (Attachment, "AttachmentMacContentType", String),
+    AttachmentMacInfo => (Attachment, "AttachmentMacInfo", Binary),
+    AttachmentOriginalPermissionType => (Attachment, "AttachmentOriginalPermissionType", Integer32),
+    AttachmentPermissionType => (Attachment, "AttachmentPermissionType", Integer32),
+    AttachmentProviderType => (Attachment, "AttachmentProviderType", String),
+    AudioNotes => (UnifiedMessaging, "UMAudioNotes", String),
+    Author => (PublicStrings, "Author", String),
+    AutomaticSpeechRecognitionData => (UnifiedMessaging, "AsrData", Binary),
+    BirthdayContactAttributionDisplayName => (Address, "BirthdayContactAttributionDisplayName", String),
+    BirthdayContactEntryId => (Address, "BirthdayContactEntryId", Binary),
+    BirthdayContactPersonGuid => (Address, "BirthdayContactPersonGuid", Binary),
+    ByteCount => (PublicStrings, "ByteCount", Integer32),
+    CalendarAttendeeRole => (PublicStrings, "urn:schemas:calendar:attendeerole", Integer32),
+    CalendarBusystatus => (PublicStrings, "urn:schemas:calendar:busystatus", String),
+    CalendarContact => (PublicStrings, "urn:schemas:calendar:contact", String),
+    CalendarContactUrl => (PublicStrings, "urn:schemas:calendar:contacturl", String),
+    CalendarCreated => (PublicStrings, "urn:schemas:calendar:created", Time),
+    CalendarDescriptionUrl => (PublicStrings, "urn:schemas:calendar:descriptionurl", String),
+    CalendarDuration => (PublicStrings, "urn:schemas:calendar:duration", Integer32),
+    CalendarExceptionDate => (PublicStrings, "urn:schemas:calendar:exdate", MultipleTime),
+    CalendarExceptionRule => (PublicStrings, "urn:schemas:calendar:exrule", MultipleString),
+    CalendarGeoLatitude => (PublicStrings, "urn:schemas:calendar:geolatitude", Floating64),
+    CalendarGeoLongitude => (PublicStrings, "urn:schemas:calendar:geolongitude", Floating64),
+    CalendarInstanceType => (PublicStrings, "urn:schemas:calendar:instancetype", Integer32),
+    CalendarIsOrganizer => (PublicStrings, "urn:schemas:calendar:isorganizer", Boolean),
+    CalendarLastModified => (PublicStrings, "urn:schemas:calendar:lastmodified", Time),
+    CalendarLocationUrl => (PublicStrings, "urn:schemas:calendar:locationurl", String),
+    CalendarMeetingStatus => (PublicStrings, "urn:schemas:calendar:meetingstatus", String),
+    CalendarMethod => (PublicStrings, "urn:schemas:calendar:method", String),
+    CalendarProductId => (PublicStrings, "urn:schemas:calendar:prodid", String),
+    CalendarRecurrenceIdRange => (PublicStrings, "urn:schemas:calendar:recurrenceidrange", String),
+    CalendarReminderOffset => (PublicStrings, "urn:schemas:calendar:reminderoffset", Integer32),
+    CalendarResources => (PublicStrings, "urn:schemas:calendar:resources", String),
+    CalendarRsvp => (PublicStrings, "urn:schemas:calendar:rsvp", Boolean),
+    CalendarSequence => (PublicStrings, "urn:schemas:calendar:sequence", Integer32),
+    CalendarTimeZone => (PublicStrings, "urn:schemas:calendar:timezone", String),
+    CalendarTimeZoneId => (PublicStrings, "urn:schemas:calendar:timezoneid", Integer32),
+    CalendarTransparent => (PublicStrings, "urn:schemas:calendar:transparent", String),
+    CalendarUid => (PublicStrings, "urn:schemas:calendar:uid", String),
+    CalendarVersion => (PublicStrings, "urn:schemas:calendar:version", String),
+    Category => (PublicStrings, "Category", String),
+    CharacterCount => (PublicStrings, "CharCount", Integer32),
+    Comments => (PublicStrings, "Comments", String),
+    Company => (PublicStrings, "Company", String),
+    ContentBase => (Headers, "Content-Base", String),
+    ContentClass => (Headers, "Content-Class", String),
+    ContentType => (Headers, "Content-Type", String),
+    CreateDateTimeReadOnly => (PublicStrings, "CreateDtmRo", Time),
+    CrossReference => (Headers, "Xref", String),
+    DavId => (PublicStrings, "DAV:id", String),
+    DavIsCollection => (PublicStrings, "DAV:iscollection", Boolean),
+    DavIsStructuredDocument => (PublicStrings, "DAV:isstructureddocument", Boolean),
+    DavParentName => (PublicStrings, "DAV:parentname", String),
+    DavUid => (PublicStrings, "DAV:uid", String),
+    DocumentParts => (PublicStrings, "DocParts", MultipleString),
+    EditTime => (PublicStrings, "EditTime", String),
+    ExchangeIntendedBusyStatus => (PublicStrings, "http://schemas.microsoft.com/exchange/intendedbusystatus", String),
+    ExchangeJunkEmailMoveStamp => (PublicStrings, "http://schemas.microsoft.com/exchange/junkemailmovestamp", Integer32),
+    ExchangeModifyExceptionStructure => (PublicStrings, "http://schemas.microsoft.com/exchange/modifyexceptionstruct", Binary),
+    ExchangeNoModifyExceptions => (PublicStrings, "http://schemas.microsoft.com/exchange/nomodifyexceptions", Boolean),
+    ExchangePatternEnd => (PublicStrings, "http://schemas.microsoft.com/exchange/patternend", Time),
+    ExchangePatternStart => (PublicStrings, "http://schemas.microsoft.com/exchange/patternstart", Time),
+    ExchangeReminderInterval => (PublicStrings, "http://schemas.microsoft.com/exchange/reminderinterval", Integer32),
+    ExchDatabaseSchema => (PublicStrings, "urn:schemas-microsoft-com:exch-data:baseschema", MultipleString),
+    ExchDataExpectedContentClass => (PublicStrings, "urn:schemas-microsoft-com:exch-data:expected-content-class", MultipleString),
+    ExchDataSchemaCollectionReference => (PublicStrings, "urn:schemas-microsoft-com:exch-data:schema-collection-ref", String),
+    ExtractedAddresses => (XmlExtrEntities, "XmlExtractedAddresses", String),
+    ExtractedContacts => (XmlExtrEntities, "XmlExtractedContacts", String),
+    ExtractedEmails => (XmlExtrEntities, "XmlExtractedEmails", String),
+    ExtractedMeetings => (XmlExtrEntities, "XmlExtractedMeetings", String),
+    ExtractedPhones => (XmlExtrEntities, "XmlExtractedPhones", String),
+    ExtractedTasks => (XmlExtrEntities, "XmlExtractedTasks", String),
+    ExtractedUrls => (XmlExtrEntities, "XmlExtractedUrls", String),
+    From => (Headers, "From", String),
+    HeadingPairs => (PublicStrings, "HeadingPairs", Binary),
+    HiddenCount => (PublicStrings, "HiddenCount", Integer32),
+    HttpmailCalendar => (PublicStrings, "urn:schemas:httpmail:calendar", String),
+    HttpmailHtmlDescription => (PublicStrings, "urn:schemas:httpmail:htmldescription", String),
+    HttpmailSendMessage => (PublicStrings, "urn:schemas:httpmail:sendmsg", String),
+    ICalendarRecurrenceDate => (PublicStrings, "urn:schemas:calendar:rdate", MultipleTime),
+    ICalendarRecurrenceRule => (PublicStrings, "urn:schemas:calendar:rrule", MultipleString),
+    InternetSubject => (Headers, "Subject", String),
+    IsBirthdayContactWritable => (Address, "IsBirthdayContactWritable", Boolean),
+    Keywords => (PublicStrings, "Keywords", MultipleString),
+    LastAuthor => (PublicStrings, "LastAuthor", String),
+    LastPrinted => (PublicStrings, "LastPrinted", Time),
+    LastSaveDateTime => (PublicStrings, "LastSaveDtm", Time),
+    LineCount => (PublicStrings, "LineCount", Integer32),
+    LinksDirty => (PublicStrings, "LinksDirty", Boolean),
+    LocationUrl => (PublicStrings, "urn:schemas:calendar:locationurl", String),
+    Manager => (PublicStrings, "Manager", String),
+    MeetingDoNotForward => (PublicStrings, "DoNotForward", Boolean),
+    MSIPLabels => (Headers, "msip_labels", String),
+    MultimediaClipCount => (PublicStrings, "MMClipCount", Integer32),
+    NoteCount => (PublicStrings, "NoteCount", Integer32),
+    OMSAccountGuid => (PublicStrings, "OMSAccountGuid", String),
+    OMSMobileModel => (PublicStrings, "OMSMobileModel", String),
+    OMSScheduleTime => (PublicStrings, "OMSScheduleTime", Time),
+    OMSServiceType => (PublicStrings, "OMSServiceType", Integer32),
+    OMSSourceType => (PublicStrings, "OMSSourceType", Integer32),
+    PageCount => (PublicStrings, "PageCount", Integer32),
+    ParagraphCount => (PublicStrings, "ParCount", Integer32),
+    PhishingStamp => (PublicStrings, "http://schemas.microsoft.com/outlook/phishingstamp", Integer32),
+    PresentationFormat => (PublicStrings, "PresFormat", String),
+    QuarantineOriginalSender => (PublicStrings, "quarantine-original-sender", String),
+    RevisionNumber => (PublicStrings, "RevNumber", String),
+    RightsManagementLicense => (PublicStrings, "DRMLicense", MultipleBinary),
+    Scale => (PublicStrings, "Scale", Boolean),
+    Security => (PublicStrings, "Security", Integer32),
+    SlideCount => (PublicStrings, "SlideCount", Integer32),
+    Subject => (PublicStrings, "Subject", String),
+    Template => (PublicStrings, "Template", String),
+    Thumbnail => (PublicStrings, "Thumbnail", Binary),
+    Title => (PublicStrings, "Title", String),
+    WordCount => (PublicStrings, "WordCount", Integer32),
+    XCallId => (Headers, "X-CallID", String),
+    XFaxNumberOfPages => (Headers, "X-FaxNumberOfPages", Integer16),
+    XRequireProtectedPlayOnPhone => (Headers, "X-RequireProtectedPlayOnPhone", Boolean),
+    XSenderTelephoneNumber => (Headers, "X-CallingTelephoneNumber", String),
+    XSharingBrowseUrl => (Headers, "X-Sharing-Browse-Url", String),
+    XSharingCapabilities => (Headers, "X-Sharing-Capabilities", String),
+    XSharingConfigUrl => (Headers, "X-Sharing-Config-Url", String),
+    XSharingExendedCaps => (Headers, "X-Sharing-Exended-Caps", String),
+    XSharingFlavor => (Headers, "X-Sharing-Flavor", String),
+    XSharingInstanceGuid => (Headers, "X-Sharing-Instance-Guid", String),
+    XSharingLocalType => (Headers, "X-Sharing-Local-Type", String),
+    XSharingProviderGuid => (Headers, "X-Sharing-Provider-Guid", String),
+    XSharingProviderName => (Headers, "X-Sharing-Provider-Name", String),
+    XSharingProviderUrl => (Headers, "X-Sharing-Provider-Url", String),
+    XSharingRemoteName => (Headers, "X-Sharing-Remote-Name", String),
+    XSharingRemotePath => (Headers, "X-Sharing-Remote-Path", String),
+    XSharingRemoteStoreUid => (Headers, "X-Sharing-Remote-Store-Uid", String),
+    XSharingRemoteType => (Headers, "X-Sharing-Remote-Type", String),
+    XSharingRemoteUid => (Headers, "X-Sharing-Remote-Uid", String),
+    XVoiceMessageAttachmentOrder => (Headers, "X-AttachmentOrder", String),
+    XVoiceMessageDuration => (Headers, "X-VoiceMessageDuration", Integer16),
+    XVoiceMessageSenderName => (Headers, "X-VoiceMessageSenderName", String),
+}
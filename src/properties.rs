@@ -0,0 +1,580 @@
+//! Reading every property of a storage, including the variable length and
+//! multiple-valued properties that are kept in their own streams.
+
+use std::{
+    cmp::Ordering,
+    collections::BTreeMap,
+    convert::TryInto,
+    io::{Read, Seek},
+};
+
+use cfb::Entry;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{
+    diagnostics::{Diagnostic, Diagnostics, ParseOptions},
+    entry_id::EntryId,
+    named::NamedProperties,
+    oxprops::property_ids::{lids::Lid, names::Name, tags::Tag, Pid},
+    parse_guid, read_stream, PType, PValue,
+};
+
+/// The kind of storage a property stream belongs to, which determines the
+/// size of the property stream header.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) enum StorageKind {
+    TopLevel,
+    EmbeddedMessage,
+    /// Recipient and attachment storages.
+    Other,
+}
+
+impl StorageKind {
+    fn header_len(self) -> usize {
+        match self {
+            Self::TopLevel => 32,
+            Self::EmbeddedMessage => 24,
+            Self::Other => 8,
+        }
+    }
+}
+
+/// The value of a property, with variable length values read from their
+/// streams.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum PropertyValue {
+    Integer16(i16),
+    Integer32(i32),
+    Floating32(f32),
+    Floating64(f64),
+    Currency(i64),
+    FloatingTime(f64),
+    ErrorCode(u32),
+    Boolean(bool),
+    Integer64(i64),
+    String(String),
+    Time(DateTime<Utc>),
+    Guid(Uuid),
+    Binary(Vec<u8>),
+    MultipleInteger16(Vec<i16>),
+    MultipleInteger32(Vec<i32>),
+    MultipleFloating32(Vec<f32>),
+    MultipleFloating64(Vec<f64>),
+    MultipleCurrency(Vec<i64>),
+    MultipleFloatingTime(Vec<f64>),
+    MultipleInteger64(Vec<i64>),
+    MultipleString(Vec<String>),
+    MultipleTime(Vec<DateTime<Utc>>),
+    MultipleGuid(Vec<Uuid>),
+    MultipleBinary(Vec<Vec<u8>>),
+    /// A storage, such as an embedded message.
+    Object,
+}
+
+impl PropertyValue {
+    /// The position of the variant, which orders values of different types.
+    fn rank(&self) -> u8 {
+        match self {
+            Self::Integer16(_) => 0,
+            Self::Integer32(_) => 1,
+            Self::Floating32(_) => 2,
+            Self::Floating64(_) => 3,
+            Self::Currency(_) => 4,
+            Self::FloatingTime(_) => 5,
+            Self::ErrorCode(_) => 6,
+            Self::Boolean(_) => 7,
+            Self::Integer64(_) => 8,
+            Self::String(_) => 9,
+            Self::Time(_) => 10,
+            Self::Guid(_) => 11,
+            Self::Binary(_) => 12,
+            Self::MultipleInteger16(_) => 13,
+            Self::MultipleInteger32(_) => 14,
+            Self::MultipleFloating32(_) => 15,
+            Self::MultipleFloating64(_) => 16,
+            Self::MultipleCurrency(_) => 17,
+            Self::MultipleFloatingTime(_) => 18,
+            Self::MultipleInteger64(_) => 19,
+            Self::MultipleString(_) => 20,
+            Self::MultipleTime(_) => 21,
+            Self::MultipleGuid(_) => 22,
+            Self::MultipleBinary(_) => 23,
+            Self::Object => 24,
+        }
+    }
+}
+
+/// Compares lists of floating point values element by element, with the same
+/// total order as `total_cmp`.
+fn cmp_floats<T>(a: &[T], b: &[T], cmp: impl Fn(&T, &T) -> Ordering) -> Ordering {
+    a.iter()
+        .zip(b)
+        .map(|(a, b)| cmp(a, b))
+        .find(|ordering| ordering.is_ne())
+        .unwrap_or_else(|| a.len().cmp(&b.len()))
+}
+
+// Floating point values are compared by `total_cmp`, so that NaN equals
+// itself and messages have a total order.
+impl Ord for PropertyValue {
+    fn cmp(&self, other: &Self) -> Ordering {
+        use PropertyValue::*;
+        match (self, other) {
+            (Integer16(a), Integer16(b)) => a.cmp(b),
+            (Integer32(a), Integer32(b)) => a.cmp(b),
+            (Floating32(a), Floating32(b)) => a.total_cmp(b),
+            (Floating64(a), Floating64(b)) | (FloatingTime(a), FloatingTime(b)) => a.total_cmp(b),
+            (Currency(a), Currency(b)) | (Integer64(a), Integer64(b)) => a.cmp(b),
+            (ErrorCode(a), ErrorCode(b)) => a.cmp(b),
+            (Boolean(a), Boolean(b)) => a.cmp(b),
+            (String(a), String(b)) => a.cmp(b),
+            (Time(a), Time(b)) => a.cmp(b),
+            (Guid(a), Guid(b)) => a.cmp(b),
+            (Binary(a), Binary(b)) => a.cmp(b),
+            (MultipleInteger16(a), MultipleInteger16(b)) => a.cmp(b),
+            (MultipleInteger32(a), MultipleInteger32(b)) => a.cmp(b),
+            (MultipleFloating32(a), MultipleFloating32(b)) => cmp_floats(a, b, f32::total_cmp),
+            (MultipleFloating64(a), MultipleFloating64(b))
+            | (MultipleFloatingTime(a), MultipleFloatingTime(b)) => {
+                cmp_floats(a, b, f64::total_cmp)
+            }
+            (MultipleCurrency(a), MultipleCurrency(b))
+            | (MultipleInteger64(a), MultipleInteger64(b)) => a.cmp(b),
+            (MultipleString(a), MultipleString(b)) => a.cmp(b),
+            (MultipleTime(a), MultipleTime(b)) => a.cmp(b),
+            (MultipleGuid(a), MultipleGuid(b)) => a.cmp(b),
+            (MultipleBinary(a), MultipleBinary(b)) => a.cmp(b),
+            _ => self.rank().cmp(&other.rank()),
+        }
+    }
+}
+
+impl PartialOrd for PropertyValue {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for PropertyValue {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for PropertyValue {}
+
+/// All of the properties of a message, attachment or recipient.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq)]
+#[serde(from = "Vec<(Pid, PropertyValue)>", into = "Vec<(Pid, PropertyValue)>")]
+pub struct PropertyBag {
+    properties: BTreeMap<Pid, PropertyValue>,
+}
+
+impl From<Vec<(Pid, PropertyValue)>> for PropertyBag {
+    fn from(properties: Vec<(Pid, PropertyValue)>) -> Self {
+        Self {
+            properties: properties.into_iter().collect(),
+        }
+    }
+}
+
+impl From<PropertyBag> for Vec<(Pid, PropertyValue)> {
+    fn from(bag: PropertyBag) -> Self {
+        bag.properties.into_iter().collect()
+    }
+}
+
+impl PropertyBag {
+    pub fn get(&self, pid: impl Into<Pid>) -> Option<&PropertyValue> {
+        self.properties.get(&pid.into())
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&Pid, &PropertyValue)> {
+        self.properties.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.properties.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.properties.is_empty()
+    }
+
+    pub fn string(&self, pid: impl Into<Pid>) -> Option<&str> {
+        match self.get(pid)? {
+            PropertyValue::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub fn integer32(&self, pid: impl Into<Pid>) -> Option<i32> {
+        match self.get(pid)? {
+            PropertyValue::Integer32(n) => Some(*n),
+            PropertyValue::Integer16(n) => Some(*n as i32),
+            _ => None,
+        }
+    }
+
+    pub fn floating64(&self, pid: impl Into<Pid>) -> Option<f64> {
+        match self.get(pid)? {
+            PropertyValue::Floating64(n) => Some(*n),
+            PropertyValue::Floating32(n) => Some(*n as f64),
+            _ => None,
+        }
+    }
+
+    pub fn boolean(&self, pid: impl Into<Pid>) -> Option<bool> {
+        match self.get(pid)? {
+            PropertyValue::Boolean(b) => Some(*b),
+            _ => None,
+        }
+    }
+
+    pub fn time(&self, pid: impl Into<Pid>) -> Option<DateTime<Utc>> {
+        match self.get(pid)? {
+            PropertyValue::Time(time) => Some(*time),
+            _ => None,
+        }
+    }
+
+    pub fn binary(&self, pid: impl Into<Pid>) -> Option<&[u8]> {
+        match self.get(pid)? {
+            PropertyValue::Binary(bytes) => Some(bytes),
+            _ => None,
+        }
+    }
+
+    /// A binary property decoded as an entry ID, such as PidTagSenderEntryId.
+    pub fn entry_id(&self, pid: impl Into<Pid>) -> Option<EntryId> {
+        EntryId::parse(self.binary(pid)?).ok()
+    }
+
+    pub fn strings(&self, pid: impl Into<Pid>) -> Option<&[String]> {
+        match self.get(pid)? {
+            PropertyValue::MultipleString(values) => Some(values),
+            _ => None,
+        }
+    }
+
+    pub fn binaries(&self, pid: impl Into<Pid>) -> Option<&[Vec<u8>]> {
+        match self.get(pid)? {
+            PropertyValue::MultipleBinary(values) => Some(values),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn insert(&mut self, pid: Pid, value: PropertyValue) {
+        self.properties.insert(pid, value);
+    }
+
+    pub(crate) fn remove(&mut self, pid: impl Into<Pid>) -> Option<PropertyValue> {
+        self.properties.remove(&pid.into())
+    }
+}
+
+impl From<Tag> for Pid {
+    fn from(tag: Tag) -> Self {
+        Self::Tag(tag)
+    }
+}
+
+impl From<Lid> for Pid {
+    fn from(lid: Lid) -> Self {
+        Self::Lid(lid)
+    }
+}
+
+impl From<Name> for Pid {
+    fn from(name: Name) -> Self {
+        Self::Name(name)
+    }
+}
+
+/// Read all of the properties in the storage at `storage_path`, which has a
+/// trailing separator. Named properties are looked up in `names`, and any
+/// that aren't known are left as [`Tag::Mapped`]. Properties with malformed
+/// values are recorded in `diagnostics` and left out.
+pub(crate) fn read_properties<F: Seek + Read>(
+    comp: &mut cfb::CompoundFile<F>,
+    storage_path: &str,
+    kind: StorageKind,
+    names: &NamedProperties,
+    options: &ParseOptions,
+    diagnostics: &mut Diagnostics,
+) -> Result<PropertyBag, Box<dyn std::error::Error>> {
+    read_properties_except(comp, storage_path, kind, names, &[], options, diagnostics)
+}
+
+/// As `read_properties`, but the value streams of the property IDs in `skip`
+/// are not read. This avoids loading large values such as attachment data.
+pub(crate) fn read_properties_except<F: Seek + Read>(
+    comp: &mut cfb::CompoundFile<F>,
+    storage_path: &str,
+    kind: StorageKind,
+    names: &NamedProperties,
+    skip: &[u16],
+    options: &ParseOptions,
+    diagnostics: &mut Diagnostics,
+) -> Result<PropertyBag, Box<dyn std::error::Error>> {
+    let mut bag = PropertyBag::default();
+    // Some writers leave out the property stream of recipients, which then
+    // only have the properties in value streams.
+    let data =
+        read_stream(comp, &format!("{storage_path}__properties_version1.0")).unwrap_or_default();
+    let entries = data.get(kind.header_len()..).unwrap_or_default();
+    for entry in entries.chunks_exact(16) {
+        let type_bits = u16::from_le_bytes([entry[0], entry[1]]);
+        let id = u16::from_le_bytes([entry[2], entry[3]]);
+        if skip.contains(&id) {
+            continue;
+        }
+        let p_type = PType::from_bits(type_bits);
+        let value = match p_type {
+            PType::Integer16
+            | PType::Integer32
+            | PType::Floating32
+            | PType::Floating64
+            | PType::Currency
+            | PType::FloatingTime
+            | PType::Boolean
+            | PType::Integer64
+            | PType::Time => {
+                from_fixed(PValue::from_bytes(p_type, entry[8..16].try_into().unwrap()))
+            }
+            PType::ErrorCode => Some(PropertyValue::ErrorCode(u32::from_le_bytes(
+                entry[8..12].try_into().unwrap(),
+            ))),
+            PType::Object => Some(PropertyValue::Object),
+            _ => read_variable_or_warn(
+                comp,
+                storage_path,
+                pid_of(id, names),
+                id,
+                type_bits,
+                options,
+                diagnostics,
+            )?,
+        };
+        if let Some(value) = value {
+            bag.insert(pid_of(id, names), value);
+        }
+    }
+    // Not every writer lists variable length properties in the property
+    // stream, so also pick up any value streams that were missed.
+    #[allow(clippy::needless_collect)]
+    let streams: Vec<Entry> = comp.read_storage(storage_path)?.collect();
+    for stream in streams {
+        let name = match stream.name().strip_prefix("__substg1.0_") {
+            Some(name) if stream.is_stream() && name.len() == 8 => name,
+            _ => continue,
+        };
+        let (id, type_bits) = match (
+            u16::from_str_radix(&name[0..4], 16),
+            u16::from_str_radix(&name[4..8], 16),
+        ) {
+            (Ok(id), Ok(type_bits)) => (id, type_bits),
+            _ => continue,
+        };
+        let pid = pid_of(id, names);
+        if !skip.contains(&id) && bag.get(pid).is_none() {
+            if let Some(value) =
+                read_variable_or_warn(comp, storage_path, pid, id, type_bits, options, diagnostics)?
+            {
+                bag.insert(pid, value);
+            }
+        }
+    }
+    Ok(bag)
+}
+
+/// As `read_variable`, but a malformed value is recorded and gives `None`,
+/// so that one bad property doesn't lose the rest.
+fn read_variable_or_warn<F: Seek + Read>(
+    comp: &mut cfb::CompoundFile<F>,
+    storage_path: &str,
+    pid: Pid,
+    id: u16,
+    type_bits: u16,
+    options: &ParseOptions,
+    diagnostics: &mut Diagnostics,
+) -> Result<Option<PropertyValue>, Box<dyn std::error::Error>> {
+    match read_variable(comp, storage_path, id, type_bits) {
+        Ok(value) => Ok(value),
+        Err(err) => {
+            let path = format!("{storage_path}__substg1.0_{id:04X}{type_bits:04X}");
+            diagnostics.warn(options, Diagnostic::new(path, Some(pid), err))?;
+            Ok(None)
+        }
+    }
+}
+
+fn pid_of(id: u16, names: &NamedProperties) -> Pid {
+    names
+        .get(id)
+        .and_then(|property| property.pid())
+        .unwrap_or_else(|| Pid::from_u16(id))
+}
+
+fn from_fixed(value: PValue) -> Option<PropertyValue> {
+    let value = match value {
+        PValue::Integer16(n) => PropertyValue::Integer16(n),
+        PValue::Integer32(n) => PropertyValue::Integer32(n),
+        PValue::Floating32(n) => PropertyValue::Floating32(n),
+        PValue::Floating64(n) => PropertyValue::Floating64(n),
+        PValue::Currency(n) => PropertyValue::Currency(n),
+        PValue::FloatingTime(n) => PropertyValue::FloatingTime(n),
+        PValue::Boolean(b) => PropertyValue::Boolean(b),
+        PValue::Integer64(n) => PropertyValue::Integer64(n),
+        PValue::Time(time) => PropertyValue::Time(time),
+        _ => return None,
+    };
+    Some(value)
+}
+
+/// Read a property which is stored in its own stream or streams.
+fn read_variable<F: Seek + Read>(
+    comp: &mut cfb::CompoundFile<F>,
+    storage_path: &str,
+    id: u16,
+    type_bits: u16,
+) -> Result<Option<PropertyValue>, Box<dyn std::error::Error>> {
+    let stream_name = format!("{storage_path}__substg1.0_{id:04X}{type_bits:04X}");
+    let data = match read_stream(comp, &stream_name) {
+        Some(data) => data,
+        None => return Ok(None),
+    };
+    let value = match PType::from_bits(type_bits) {
+        PType::String => PropertyValue::String(decode_string(&data)?),
+        PType::String8 => PropertyValue::String(decode_string8(&data)),
+        PType::Binary => PropertyValue::Binary(data),
+        PType::Guid if data.len() >= 16 => PropertyValue::Guid(parse_guid(&data)),
+        PType::MultipleInteger16 => PropertyValue::MultipleInteger16(
+            data.chunks_exact(2)
+                .map(|n| i16::from_le_bytes(n.try_into().unwrap()))
+                .collect(),
+        ),
+        PType::MultipleInteger32 => PropertyValue::MultipleInteger32(
+            data.chunks_exact(4)
+                .map(|n| i32::from_le_bytes(n.try_into().unwrap()))
+                .collect(),
+        ),
+        PType::MultipleFloating32 => PropertyValue::MultipleFloating32(
+            data.chunks_exact(4)
+                .map(|n| f32::from_le_bytes(n.try_into().unwrap()))
+                .collect(),
+        ),
+        PType::MultipleFloating64 => PropertyValue::MultipleFloating64(
+            data.chunks_exact(8)
+                .map(|n| f64::from_le_bytes(n.try_into().unwrap()))
+                .collect(),
+        ),
+        PType::MultipleFloatingTime => PropertyValue::MultipleFloatingTime(
+            data.chunks_exact(8)
+                .map(|n| f64::from_le_bytes(n.try_into().unwrap()))
+                .collect(),
+        ),
+        PType::MultipleCurrency => PropertyValue::MultipleCurrency(
+            data.chunks_exact(8)
+                .map(|n| i64::from_le_bytes(n.try_into().unwrap()))
+                .collect(),
+        ),
+        PType::MultipleInteger64 => PropertyValue::MultipleInteger64(
+            data.chunks_exact(8)
+                .map(|n| i64::from_le_bytes(n.try_into().unwrap()))
+                .collect(),
+        ),
+        PType::MultipleTime => PropertyValue::MultipleTime(
+            data.chunks_exact(8)
+                .filter_map(
+                    |n| match PValue::from_bytes(PType::Time, n.try_into().unwrap()) {
+                        PValue::Time(time) => Some(time),
+                        _ => None,
+                    },
+                )
+                .collect(),
+        ),
+        PType::MultipleGuid => {
+            PropertyValue::MultipleGuid(data.chunks_exact(16).map(parse_guid).collect())
+        }
+        // The stream holds the lengths of each value, and each value is
+        // stored in a separate stream with the index as a suffix.
+        p_type @ (PType::MultipleString | PType::MultipleString8 | PType::MultipleBinary) => {
+            let entry_len = if p_type == PType::MultipleBinary {
+                8
+            } else {
+                4
+            };
+            let mut values = Vec::new();
+            for i in 0..data.len() / entry_len {
+                let value =
+                    read_stream(comp, &format!("{stream_name}-{i:08X}")).unwrap_or_default();
+                values.push(value);
+            }
+            match p_type {
+                PType::MultipleString => PropertyValue::MultipleString(
+                    values
+                        .iter()
+                        .map(|value| decode_string(value))
+                        .collect::<Result<_, _>>()?,
+                ),
+                PType::MultipleString8 => PropertyValue::MultipleString(
+                    values.iter().map(|value| decode_string8(value)).collect(),
+                ),
+                _ => PropertyValue::MultipleBinary(values),
+            }
+        }
+        _ => return Ok(None),
+    };
+    Ok(Some(value))
+}
+
+fn decode_string(data: &[u8]) -> Result<String, &'static str> {
+    let mut s = crate::read(data)?;
+    while s.ends_with('\0') {
+        s.pop();
+    }
+    Ok(s)
+}
+
+/// 8-bit strings are in the message code page. UTF-8 is tried first and
+/// anything else is treated as Latin-1.
+fn decode_string8(data: &[u8]) -> String {
+    let data = match data.iter().position(|&b| b == 0) {
+        Some(end) => &data[..end],
+        None => data,
+    };
+    match std::str::from_utf8(data) {
+        Ok(s) => s.to_string(),
+        Err(_) => data.iter().copied().map(char::from).collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn floating_point_total_order() {
+        let nan = PropertyValue::Floating64(f64::NAN);
+        assert_eq!(nan, nan.clone());
+        assert_eq!(nan.cmp(&nan.clone()), Ordering::Equal);
+        assert_ne!(
+            PropertyValue::Floating32(0.0),
+            PropertyValue::Floating32(-0.0)
+        );
+        assert!(PropertyValue::Floating64(1.0) < nan);
+        assert!(
+            PropertyValue::MultipleFloating64(vec![1.0])
+                < PropertyValue::MultipleFloating64(vec![1.0, f64::NAN])
+        );
+        assert_eq!(
+            PropertyValue::MultipleFloating32(vec![f32::NAN]),
+            PropertyValue::MultipleFloating32(vec![f32::NAN])
+        );
+        // Values of different types are ordered by their type.
+        assert!(PropertyValue::Integer32(5) < PropertyValue::Floating32(0.0));
+    }
+}
@@ -0,0 +1,147 @@
+//! Lazy access to a message, for when attachments are too large to read
+//! into memory all at once.
+
+use std::{
+    fs::File,
+    io::{Read, Seek},
+    path::Path,
+};
+
+use crate::{
+    attachments::{AttachMethod, Attachment, DATA_STREAM, EMBEDDED_STORAGE},
+    diagnostics::{Diagnostic, Diagnostics, ParseOptions},
+    named::NamedProperties,
+    sub_storages, EmailMessage, ATTACHMENT_PREFIX,
+};
+
+/// An attachment whose metadata has been read, but not its data.
+#[derive(Clone, Debug)]
+pub struct AttachmentEntry {
+    /// The path of the attachment storage within the file.
+    pub path: String,
+    /// The attachment metadata. `data` and `embedded_message` are always
+    /// `None`; use [`MessageReader::attachment_data`] and
+    /// [`MessageReader::embedded_attachments`] instead.
+    pub attachment: Attachment,
+    /// The length of the attachment data, if it has any.
+    pub data_len: Option<u64>,
+    /// Whether the attachment holds an embedded message.
+    pub is_embedded_message: bool,
+}
+
+/// Reads a message from any seekable source, only reading attachment data
+/// when it is asked for.
+pub struct MessageReader<F> {
+    comp: cfb::CompoundFile<F>,
+    names: NamedProperties,
+    /// Why the named property mapping couldn't be read, which is reported
+    /// when the message is read.
+    names_error: Option<Diagnostic>,
+}
+
+impl MessageReader<File> {
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, Box<dyn std::error::Error>> {
+        Self::new(File::open(path)?)
+    }
+}
+
+impl<F: Read + Seek> MessageReader<F> {
+    pub fn new(inner: F) -> Result<Self, Box<dyn std::error::Error>> {
+        let mut comp = cfb::CompoundFile::open(inner)?;
+        // Without the mapping, named properties are left unresolved.
+        let (names, names_error) = match NamedProperties::read(&mut comp) {
+            Ok(names) => (names, None),
+            Err(err) => (
+                NamedProperties::default(),
+                Some(Diagnostic::new("/__nameid_version1.0", None, err)),
+            ),
+        };
+        Ok(Self {
+            comp,
+            names,
+            names_error,
+        })
+    }
+
+    /// Read the entire message, including all attachment data.
+    pub fn message(&mut self) -> Result<EmailMessage, Box<dyn std::error::Error>> {
+        Ok(self.message_with_options(&ParseOptions::default())?.0)
+    }
+
+    /// As [`Self::message`], also returning any problems that were skipped.
+    pub fn message_with_options(
+        &mut self,
+        options: &ParseOptions,
+    ) -> Result<(EmailMessage, Diagnostics), Box<dyn std::error::Error>> {
+        let mut diagnostics = Diagnostics::default();
+        if let Some(diagnostic) = &self.names_error {
+            diagnostics.warn(options, diagnostic.clone())?;
+        }
+        let message =
+            EmailMessage::from_cfb(&mut self.comp, "/", &self.names, options, &mut diagnostics)?;
+        Ok((message, diagnostics))
+    }
+
+    /// The attachments of the message, without their data. Malformed
+    /// properties are left out, as when reading the message leniently.
+    pub fn attachments(&mut self) -> Result<Vec<AttachmentEntry>, Box<dyn std::error::Error>> {
+        self.attachments_in("/")
+    }
+
+    /// The attachments of a message embedded in `entry`, without their data.
+    pub fn embedded_attachments(
+        &mut self,
+        entry: &AttachmentEntry,
+    ) -> Result<Vec<AttachmentEntry>, Box<dyn std::error::Error>> {
+        if !entry.is_embedded_message {
+            return Err("attachment is not an embedded message".into());
+        }
+        self.attachments_in(&format!("{}/{EMBEDDED_STORAGE}/", entry.path))
+    }
+
+    /// A reader over the data of a single attachment.
+    pub fn attachment_data(
+        &mut self,
+        entry: &AttachmentEntry,
+    ) -> Result<impl Read + Seek + '_, Box<dyn std::error::Error>> {
+        Ok(self
+            .comp
+            .open_stream(format!("{}/{DATA_STREAM}", entry.path))?)
+    }
+
+    pub fn into_inner(self) -> F {
+        self.comp.into_inner()
+    }
+
+    fn attachments_in(
+        &mut self,
+        storage_path: &str,
+    ) -> Result<Vec<AttachmentEntry>, Box<dyn std::error::Error>> {
+        let mut entries = Vec::new();
+        for name in sub_storages(&self.comp, storage_path, ATTACHMENT_PREFIX)? {
+            let path = format!("{storage_path}{name}");
+            let attachment = Attachment::metadata_from_cfb(
+                &mut self.comp,
+                storage_path,
+                &name,
+                &self.names,
+                &ParseOptions::default(),
+                &mut Diagnostics::default(),
+            )?;
+            let data_len = self
+                .comp
+                .entry(format!("{path}/{DATA_STREAM}"))
+                .ok()
+                .map(|entry| entry.len());
+            let is_embedded_message = attachment.method == AttachMethod::EmbeddedMessage
+                && self.comp.is_storage(format!("{path}/{EMBEDDED_STORAGE}"));
+            entries.push(AttachmentEntry {
+                path,
+                attachment,
+                data_len,
+                is_embedded_message,
+            });
+        }
+        Ok(entries)
+    }
+}
@@ -2,68 +2,110 @@ use std::io::{Read, Seek};
 
 use serde::{Deserialize, Serialize};
 
-use crate::{read, PValue};
+use crate::{
+    diagnostics::{Diagnostics, ParseOptions},
+    named::NamedProperties,
+    properties::{read_properties, PropertyBag, StorageKind},
+    Tag,
+};
+
+/// PidTagRecipientType. On meeting objects, `To`, `Cc` and `Bcc` are used
+/// for required attendees, optional attendees and resources respectively.
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum RecipientType {
+    To,
+    Cc,
+    Bcc,
+    Other(i32),
+}
+
+impl RecipientType {
+    pub fn from_i32(n: i32) -> Self {
+        // The high bits flag resent and already sent recipients.
+        match n & 0x0FFF_FFFF {
+            0x1 => Self::To,
+            0x2 => Self::Cc,
+            0x3 => Self::Bcc,
+            _ => Self::Other(n),
+        }
+    }
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq)]
 pub struct Recipient {
     pub cfb_name: String,
     pub address: String,
-    // data: Vec<u8>,
+    /// PidTagDisplayName.
+    pub name: Option<String>,
+    pub recipient_type: Option<RecipientType>,
+    /// All properties of the recipient.
+    pub properties: PropertyBag,
 }
 
 impl Recipient {
+    /// `storage_path` is the path of the storage containing the recipient,
+    /// with a trailing separator.
     pub fn from_cfb<F: Seek + Read>(
         comp: &mut cfb::CompoundFile<F>,
+        storage_path: &str,
         cfb_name: String,
     ) -> Result<Self, Box<dyn std::error::Error>> {
-        // TODO work out how to deal with properties.
-        let properties_path = format!("/{cfb_name}\\");
-        // let properties = crate::parse_property_stream_other(comp, &properties_path);
-        // println!("RecipProps {properties:#?}");
-        // for property in properties.properties.iter() {
-        //     println!("RecipProperty: {property:?}");
-        // }
-        // "/__recip_version1.0_#00000000\\__properties_version1.0"
-        let address = {
-            let name_path = format!("{}\\__substg1.0_39FE001F", cfb_name);
-            if let Ok(mut name_stream) = comp.open_stream(&name_path) {
-                let buffer = {
-                    let mut buffer = Vec::new();
-                    name_stream.read_to_end(&mut buffer)?;
-                    buffer
-                };
-                Some(read(&buffer)?)
-            } else {
-                None
-            }
-        };
-        let address = if let Some(address) = address {
-            address
-        } else {
-            let name_path = format!("{}\\__substg1.0_3003001F", cfb_name);
-            let mut name_stream = comp.open_stream(&name_path)?;
-            let buffer = {
-                let mut buffer = Vec::new();
-                name_stream.read_to_end(&mut buffer)?;
-                buffer
-            };
-            read(&buffer)?
-        };
-        // println!("RecipAddress: {address}");
-        // let data = {
-        //     let name_path = format!("{}\\__substg1.0_37010102", cfb_name);
-        //     let mut name_stream = comp.open_stream(&name_path)?;
-        //     let buffer = {
-        //         let mut buffer = Vec::new();
-        //         name_stream.read_to_end(&mut buffer)?;
-        //         buffer
-        //     };
-        //     buffer
-        // };
+        // Without the mapping, named properties are left unresolved, as in
+        // `MessageReader`.
+        let names = NamedProperties::read(comp).unwrap_or_default();
+        Self::from_cfb_with_options(
+            comp,
+            storage_path,
+            cfb_name,
+            &names,
+            &ParseOptions::default(),
+            &mut Diagnostics::default(),
+        )
+    }
+
+    /// Read the recipient, recording any malformed properties in
+    /// `diagnostics`.
+    pub(crate) fn from_cfb_with_options<F: Seek + Read>(
+        comp: &mut cfb::CompoundFile<F>,
+        storage_path: &str,
+        cfb_name: String,
+        names: &NamedProperties,
+        options: &ParseOptions,
+        diagnostics: &mut Diagnostics,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        let recipient_path = format!("{storage_path}{cfb_name}/");
+        let properties = read_properties(
+            comp,
+            &recipient_path,
+            StorageKind::Other,
+            names,
+            options,
+            diagnostics,
+        )?;
+        let address = properties
+            .string(Tag::SmtpAddress)
+            .or_else(|| properties.string(Tag::EmailAddress))
+            .ok_or("recipient has no address")?
+            .to_string();
+        let name = properties.string(Tag::DisplayName).map(str::to_string);
+        let recipient_type = properties
+            .integer32(Tag::RecipientType)
+            .map(RecipientType::from_i32);
         Ok(Self {
             cfb_name,
             address,
-            // data,
+            name,
+            recipient_type,
+            properties,
         })
     }
+
+    /// Whether this recipient is the organizer of a meeting
+    /// (`recipOrganizer` in PidTagRecipientFlags).
+    pub fn is_organizer(&self) -> bool {
+        self.properties
+            .integer32(Tag::RecipientFlags)
+            .map(|flags| flags & 0x0002 != 0)
+            .unwrap_or(false)
+    }
 }
@@ -0,0 +1,606 @@
+//! The recurrence of a recurring appointment, from the binary
+//! PidLidAppointmentRecur property (MS-OXOCAL 2.2.1.44).
+//!
+//! All dates and times here are in the appointment's own time zone.
+
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    appointment::BusyStatus,
+    binary::{minutes_to_datetime, ByteReader},
+};
+
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum RecurFrequency {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+    Other(u16),
+}
+
+impl RecurFrequency {
+    fn from_u16(n: u16) -> Self {
+        match n {
+            0x200A => Self::Daily,
+            0x200B => Self::Weekly,
+            0x200C => Self::Monthly,
+            0x200D => Self::Yearly,
+            n => Self::Other(n),
+        }
+    }
+}
+
+bitflags::bitflags! {
+    #[derive(Serialize, Deserialize)]
+    pub struct DaysOfWeek: u32 {
+        const SUNDAY = 0x01;
+        const MONDAY = 0x02;
+        const TUESDAY = 0x04;
+        const WEDNESDAY = 0x08;
+        const THURSDAY = 0x10;
+        const FRIDAY = 0x20;
+        const SATURDAY = 0x40;
+    }
+}
+
+impl DaysOfWeek {
+    pub fn contains_day(self, date: NaiveDate) -> bool {
+        self.bits() & (1 << date.weekday().num_days_from_sunday()) != 0
+    }
+}
+
+/// When in each period the appointment recurs.
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum PatternType {
+    /// Every `period` days.
+    Day,
+    /// On the given days of every `period` weeks.
+    Week {
+        days: DaysOfWeek,
+    },
+    /// On the given day of every `period` months, or the last day of shorter
+    /// months.
+    Month {
+        day: u32,
+    },
+    /// On the `n`th of the given days of every `period` months, where an `n`
+    /// of 5 means the last.
+    MonthNth {
+        days: DaysOfWeek,
+        n: u32,
+    },
+    /// On the last day of every `period` months.
+    MonthEnd,
+    /// As `Month`, but in the Hijri calendar.
+    HjMonth {
+        day: u32,
+    },
+    /// As `MonthNth`, but in the Hijri calendar.
+    HjMonthNth {
+        days: DaysOfWeek,
+        n: u32,
+    },
+    /// As `MonthEnd`, but in the Hijri calendar.
+    HjMonthEnd,
+    Other(u16),
+}
+
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum EndType {
+    AfterDate,
+    AfterOccurrences,
+    Never,
+}
+
+/// The RecurrencePattern structure, which is shared with tasks.
+#[derive(Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq)]
+pub struct RecurrencePattern {
+    pub frequency: RecurFrequency,
+    pub pattern_type: PatternType,
+    /// 0 for the Gregorian calendar, otherwise a CAL_ value.
+    pub calendar_type: u16,
+    /// The interval between occurrences: minutes for daily patterns, weeks
+    /// for weekly patterns and months for monthly and yearly patterns.
+    pub period: u32,
+    /// Whether the next occurrence is scheduled from when the previous one
+    /// was completed, which only applies to tasks.
+    pub sliding: bool,
+    pub end_type: EndType,
+    pub occurrence_count: u32,
+    /// 0 for Sunday to 6 for Saturday.
+    pub first_day_of_week: u32,
+    /// The original dates of deleted occurrences, including those that were
+    /// modified.
+    pub deleted_instance_dates: Vec<NaiveDate>,
+    /// The dates modified occurrences were moved to.
+    pub modified_instance_dates: Vec<NaiveDate>,
+    pub start_date: NaiveDate,
+    /// The date of the last occurrence. Patterns that never end have an end
+    /// date of 31 December 4500.
+    pub end_date: NaiveDate,
+}
+
+impl RecurrencePattern {
+    fn read(reader: &mut ByteReader) -> Result<Self, &'static str> {
+        let _reader_version = reader.u16()?;
+        let _writer_version = reader.u16()?;
+        let frequency = RecurFrequency::from_u16(reader.u16()?);
+        let pattern_type = reader.u16()?;
+        let calendar_type = reader.u16()?;
+        let _first_date_time = reader.u32()?;
+        let period = reader.u32()?;
+        let sliding = reader.u32()? != 0;
+        let pattern_type = match pattern_type {
+            0x0000 => PatternType::Day,
+            0x0001 => PatternType::Week {
+                days: DaysOfWeek::from_bits_truncate(reader.u32()?),
+            },
+            0x0002 => PatternType::Month { day: reader.u32()? },
+            0x0003 => PatternType::MonthNth {
+                days: DaysOfWeek::from_bits_truncate(reader.u32()?),
+                n: reader.u32()?,
+            },
+            0x0004 => {
+                reader.u32()?;
+                PatternType::MonthEnd
+            }
+            0x000A => PatternType::HjMonth { day: reader.u32()? },
+            0x000B => PatternType::HjMonthNth {
+                days: DaysOfWeek::from_bits_truncate(reader.u32()?),
+                n: reader.u32()?,
+            },
+            0x000C => {
+                reader.u32()?;
+                PatternType::HjMonthEnd
+            }
+            _ => return Err("unknown recurrence pattern type"),
+        };
+        let end_type = match reader.u32()? {
+            0x2021 => EndType::AfterDate,
+            0x2022 => EndType::AfterOccurrences,
+            _ => EndType::Never,
+        };
+        let occurrence_count = reader.u32()?;
+        let first_day_of_week = reader.u32()?;
+        let deleted_instance_dates = read_dates(reader)?;
+        let modified_instance_dates = read_dates(reader)?;
+        let start_date = minutes_to_datetime(reader.u32()?).date();
+        let end_date = minutes_to_datetime(reader.u32()?).date();
+        Ok(Self {
+            frequency,
+            pattern_type,
+            calendar_type,
+            period,
+            sliding,
+            end_type,
+            occurrence_count,
+            first_day_of_week,
+            deleted_instance_dates,
+            modified_instance_dates,
+            start_date,
+            end_date,
+        })
+    }
+
+    /// Whether the pattern has an occurrence on `date`, ignoring the start
+    /// and end dates and any deleted occurrences. Hijri patterns aren't
+    /// supported and never match.
+    pub fn matches(&self, date: NaiveDate) -> bool {
+        let period = self.period.max(1) as i64;
+        let months = |date: NaiveDate| date.year() as i64 * 12 + date.month0() as i64;
+        let month_matches = (months(date) - months(self.start_date)).rem_euclid(period) == 0;
+        match self.pattern_type {
+            PatternType::Day => {
+                let days = (period / (24 * 60)).max(1);
+                (date - self.start_date).num_days().rem_euclid(days) == 0
+            }
+            PatternType::Week { days } => {
+                let week_start = |date: NaiveDate| {
+                    let offset = (date.weekday().num_days_from_sunday() as i64
+                        - self.first_day_of_week as i64)
+                        .rem_euclid(7);
+                    date - Duration::days(offset)
+                };
+                let weeks = (week_start(date) - week_start(self.start_date)).num_days() / 7;
+                days.contains_day(date) && weeks.rem_euclid(period) == 0
+            }
+            PatternType::Month { day } => {
+                month_matches && date.day() == day.min(days_in_month(date))
+            }
+            PatternType::MonthNth { days, n } => {
+                if !month_matches || !days.contains_day(date) {
+                    return false;
+                }
+                if n >= 5 {
+                    // The last matching day in the month.
+                    (date.day() + 1..=days_in_month(date))
+                        .filter_map(|day| date.with_day(day))
+                        .all(|later| !days.contains_day(later))
+                } else {
+                    let nth = (1..=date.day())
+                        .filter_map(|day| date.with_day(day))
+                        .filter(|earlier| days.contains_day(*earlier))
+                        .count();
+                    nth == n as usize
+                }
+            }
+            PatternType::MonthEnd => month_matches && date.day() == days_in_month(date),
+            PatternType::HjMonth { .. }
+            | PatternType::HjMonthNth { .. }
+            | PatternType::HjMonthEnd
+            | PatternType::Other(_) => false,
+        }
+    }
+}
+
+fn read_dates(reader: &mut ByteReader) -> Result<Vec<NaiveDate>, &'static str> {
+    let count = reader.u32()?;
+    (0..count)
+        .map(|_| Ok(minutes_to_datetime(reader.u32()?).date()))
+        .collect()
+}
+
+fn days_in_month(date: NaiveDate) -> u32 {
+    let (year, month) = if date.month() == 12 {
+        (date.year() + 1, 1)
+    } else {
+        (date.year(), date.month() + 1)
+    };
+    NaiveDate::from_ymd_opt(year, month, 1)
+        .unwrap()
+        .pred_opt()
+        .unwrap()
+        .day()
+}
+
+/// A single occurrence of a recurring appointment that differs from the
+/// pattern. Fields that weren't changed are `None`.
+#[derive(Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq)]
+pub struct Exception {
+    pub start: NaiveDateTime,
+    pub end: NaiveDateTime,
+    /// When the occurrence would have started had it not been changed.
+    pub original_start: NaiveDateTime,
+    pub subject: Option<String>,
+    pub location: Option<String>,
+    pub meeting_type: Option<u32>,
+    pub reminder_minutes_before: Option<u32>,
+    pub reminder_set: Option<bool>,
+    pub busy_status: Option<BusyStatus>,
+    pub has_attachment: Option<bool>,
+    pub all_day: Option<bool>,
+    pub color: Option<u32>,
+}
+
+const ARO_SUBJECT: u16 = 0x0001;
+const ARO_MEETINGTYPE: u16 = 0x0002;
+const ARO_REMINDERDELTA: u16 = 0x0004;
+const ARO_REMINDER: u16 = 0x0008;
+const ARO_LOCATION: u16 = 0x0010;
+const ARO_BUSYSTATUS: u16 = 0x0020;
+const ARO_ATTACHMENT: u16 = 0x0040;
+const ARO_SUBTYPE: u16 = 0x0080;
+const ARO_APPTCOLOR: u16 = 0x0100;
+
+/// The AppointmentRecurrencePattern structure.
+#[derive(Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq)]
+pub struct AppointmentRecurrence {
+    pub pattern: RecurrencePattern,
+    /// Minutes from midnight to the start of each occurrence.
+    pub start_time_offset: u32,
+    /// Minutes from midnight to the end of each occurrence, which may be
+    /// more than a day.
+    pub end_time_offset: u32,
+    pub exceptions: Vec<Exception>,
+}
+
+impl AppointmentRecurrence {
+    pub fn parse(data: &[u8]) -> Result<Self, &'static str> {
+        let mut reader = ByteReader::new(data);
+        let pattern = RecurrencePattern::read(&mut reader)?;
+        let _reader_version = reader.u32()?;
+        let writer_version = reader.u32()?;
+        let start_time_offset = reader.u32()?;
+        let end_time_offset = reader.u32()?;
+        let exception_count = reader.u16()?;
+        let mut exceptions = Vec::with_capacity(exception_count as usize);
+        let mut override_flags = Vec::with_capacity(exception_count as usize);
+        for _ in 0..exception_count {
+            let start = minutes_to_datetime(reader.u32()?);
+            let end = minutes_to_datetime(reader.u32()?);
+            let original_start = minutes_to_datetime(reader.u32()?);
+            let flags = reader.u16()?;
+            let mut exception = Exception {
+                start,
+                end,
+                original_start,
+                subject: None,
+                location: None,
+                meeting_type: None,
+                reminder_minutes_before: None,
+                reminder_set: None,
+                busy_status: None,
+                has_attachment: None,
+                all_day: None,
+                color: None,
+            };
+            if flags & ARO_SUBJECT != 0 {
+                exception.subject = Some(read_string8(&mut reader)?);
+            }
+            if flags & ARO_MEETINGTYPE != 0 {
+                exception.meeting_type = Some(reader.u32()?);
+            }
+            if flags & ARO_REMINDERDELTA != 0 {
+                exception.reminder_minutes_before = Some(reader.u32()?);
+            }
+            if flags & ARO_REMINDER != 0 {
+                exception.reminder_set = Some(reader.u32()? != 0);
+            }
+            if flags & ARO_LOCATION != 0 {
+                exception.location = Some(read_string8(&mut reader)?);
+            }
+            if flags & ARO_BUSYSTATUS != 0 {
+                exception.busy_status = Some(BusyStatus::from_i32(reader.u32()? as i32));
+            }
+            if flags & ARO_ATTACHMENT != 0 {
+                exception.has_attachment = Some(reader.u32()? != 0);
+            }
+            if flags & ARO_SUBTYPE != 0 {
+                exception.all_day = Some(reader.u32()? != 0);
+            }
+            if flags & ARO_APPTCOLOR != 0 {
+                exception.color = Some(reader.u32()?);
+            }
+            exceptions.push(exception);
+            override_flags.push(flags);
+        }
+        reader.skip_sized_block()?;
+        // The extended exceptions hold Unicode versions of the subject and
+        // location, which are preferred over the 8-bit ones above.
+        for (exception, flags) in exceptions.iter_mut().zip(override_flags) {
+            if writer_version >= 0x3009 {
+                reader.skip_sized_block()?;
+            }
+            reader.skip_sized_block()?;
+            if flags & (ARO_SUBJECT | ARO_LOCATION) != 0 {
+                // The start, end and original start again.
+                reader.bytes(12)?;
+                if flags & ARO_SUBJECT != 0 {
+                    exception.subject = Some(read_string16(&mut reader)?);
+                }
+                if flags & ARO_LOCATION != 0 {
+                    exception.location = Some(read_string16(&mut reader)?);
+                }
+                reader.skip_sized_block()?;
+            }
+        }
+        Ok(Self {
+            pattern,
+            start_time_offset,
+            end_time_offset,
+            exceptions,
+        })
+    }
+
+    /// The occurrences that start within `from..to`, in order of their
+    /// original start times, with modified occurrences moved and deleted
+    /// ones left out. Modified occurrences are only found if their original
+    /// date is also within the range.
+    pub fn occurrences(&self, from: NaiveDateTime, to: NaiveDateTime) -> Occurrences<'_> {
+        Occurrences {
+            recurrence: self,
+            date: self.pattern.start_date.max(from.date()),
+            last: self.pattern.end_date.min(to.date()),
+            from,
+            to,
+        }
+    }
+}
+
+/// The 8-bit strings are in the code page of the writer, which isn't
+/// recorded, so are read as Latin-1.
+fn read_string8(reader: &mut ByteReader) -> Result<String, &'static str> {
+    let _length = reader.u16()?;
+    let length = reader.u16()?;
+    Ok(reader
+        .bytes(length as usize)?
+        .iter()
+        .map(|b| *b as char)
+        .collect())
+}
+
+fn read_string16(reader: &mut ByteReader) -> Result<String, &'static str> {
+    let length = reader.u16()?;
+    crate::read(reader.bytes(length as usize * 2)?)
+}
+
+#[derive(Clone, Debug, Serialize, Ord, PartialOrd, Eq, PartialEq)]
+pub struct Occurrence<'a> {
+    pub start: NaiveDateTime,
+    pub end: NaiveDateTime,
+    /// The changes made to this occurrence, if it was modified.
+    pub exception: Option<&'a Exception>,
+}
+
+/// An iterator over the occurrences of a recurring appointment within a date
+/// range. See [`AppointmentRecurrence::occurrences`].
+pub struct Occurrences<'a> {
+    recurrence: &'a AppointmentRecurrence,
+    date: NaiveDate,
+    last: NaiveDate,
+    from: NaiveDateTime,
+    to: NaiveDateTime,
+}
+
+impl<'a> Iterator for Occurrences<'a> {
+    type Item = Occurrence<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let recurrence = self.recurrence;
+        while self.date <= self.last {
+            let date = self.date;
+            self.date = date.succ_opt()?;
+            if !recurrence.pattern.matches(date) {
+                continue;
+            }
+            let exception = recurrence
+                .exceptions
+                .iter()
+                .find(|exception| exception.original_start.date() == date);
+            let occurrence = match exception {
+                Some(exception) => Occurrence {
+                    start: exception.start,
+                    end: exception.end,
+                    exception: Some(exception),
+                },
+                None if recurrence.pattern.deleted_instance_dates.contains(&date) => continue,
+                None => {
+                    let midnight = date.and_hms_opt(0, 0, 0).unwrap();
+                    Occurrence {
+                        start: midnight + Duration::minutes(recurrence.start_time_offset as i64),
+                        end: midnight + Duration::minutes(recurrence.end_time_offset as i64),
+                        exception: None,
+                    }
+                }
+            };
+            if occurrence.start >= self.from && occurrence.start < self.to {
+                return Some(occurrence);
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    fn minutes(date: NaiveDateTime) -> [u8; 4] {
+        let origin = NaiveDate::from_ymd_opt(1601, 1, 1)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap();
+        ((date - origin).num_minutes() as u32).to_le_bytes()
+    }
+
+    pub(crate) fn at(day: u32, hour: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2023, 5, day)
+            .unwrap()
+            .and_hms_opt(hour, 0, 0)
+            .unwrap()
+    }
+
+    fn string16(value: &str) -> Vec<u8> {
+        let mut data = (value.encode_utf16().count() as u16).to_le_bytes().to_vec();
+        data.extend(value.encode_utf16().flat_map(u16::to_le_bytes));
+        data
+    }
+
+    /// Every Monday and Wednesday for six occurrences from 1 May 2023, 9:00
+    /// to 10:00, with the 3 May occurrence deleted and the 8 May one moved.
+    pub(crate) fn weekly() -> Vec<u8> {
+        let mut data = Vec::new();
+        data.extend(0x3004_u16.to_le_bytes());
+        data.extend(0x3004_u16.to_le_bytes());
+        data.extend(0x200B_u16.to_le_bytes());
+        data.extend(0x0001_u16.to_le_bytes());
+        data.extend(0_u16.to_le_bytes());
+        data.extend(0_u32.to_le_bytes());
+        data.extend(1_u32.to_le_bytes());
+        data.extend(0_u32.to_le_bytes());
+        data.extend(
+            (DaysOfWeek::MONDAY | DaysOfWeek::WEDNESDAY)
+                .bits()
+                .to_le_bytes(),
+        );
+        data.extend(0x2022_u32.to_le_bytes());
+        data.extend(6_u32.to_le_bytes());
+        data.extend(1_u32.to_le_bytes());
+        data.extend(2_u32.to_le_bytes());
+        data.extend(minutes(at(3, 0)));
+        data.extend(minutes(at(8, 0)));
+        data.extend(1_u32.to_le_bytes());
+        data.extend(minutes(at(9, 0)));
+        data.extend(minutes(at(1, 0)));
+        data.extend(minutes(at(17, 0)));
+        data.extend(0x3006_u32.to_le_bytes());
+        data.extend(0x3009_u32.to_le_bytes());
+        data.extend(540_u32.to_le_bytes());
+        data.extend(600_u32.to_le_bytes());
+        data.extend(1_u16.to_le_bytes());
+        data.extend(minutes(at(9, 10)));
+        data.extend(minutes(at(9, 11)));
+        data.extend(minutes(at(8, 9)));
+        data.extend((ARO_SUBJECT | ARO_BUSYSTATUS).to_le_bytes());
+        data.extend(6_u16.to_le_bytes());
+        data.extend(5_u16.to_le_bytes());
+        data.extend(b"Moved");
+        data.extend(3_u32.to_le_bytes());
+        data.extend(0_u32.to_le_bytes());
+        // Extended exception.
+        data.extend(4_u32.to_le_bytes());
+        data.extend(0_u32.to_le_bytes());
+        data.extend(0_u32.to_le_bytes());
+        data.extend(minutes(at(9, 10)));
+        data.extend(minutes(at(9, 11)));
+        data.extend(minutes(at(8, 9)));
+        data.extend(string16("Moved \u{2192}"));
+        data.extend(0_u32.to_le_bytes());
+        data.extend(0_u32.to_le_bytes());
+        data
+    }
+
+    #[test]
+    fn weekly_with_exceptions() {
+        let recurrence = AppointmentRecurrence::parse(&weekly()).unwrap();
+        assert_eq!(recurrence.pattern.frequency, RecurFrequency::Weekly);
+        assert_eq!(recurrence.pattern.end_type, EndType::AfterOccurrences);
+        assert_eq!(recurrence.pattern.occurrence_count, 6);
+        let exception = &recurrence.exceptions[0];
+        assert_eq!(exception.subject.as_deref(), Some("Moved \u{2192}"));
+        assert_eq!(exception.busy_status, Some(BusyStatus::OutOfOffice));
+        assert_eq!(exception.location, None);
+
+        let occurrences: Vec<_> = recurrence
+            .occurrences(at(1, 0), at(31, 0))
+            .map(|occurrence| (occurrence.start, occurrence.exception.is_some()))
+            .collect();
+        assert_eq!(
+            occurrences,
+            [
+                (at(1, 9), false),
+                (at(9, 10), true),
+                (at(10, 9), false),
+                (at(15, 9), false),
+                (at(17, 9), false),
+            ]
+        );
+        assert_eq!(recurrence.occurrences(at(10, 12), at(16, 0)).count(), 1);
+    }
+
+    #[test]
+    fn last_weekday_of_month() {
+        let pattern = RecurrencePattern {
+            frequency: RecurFrequency::Monthly,
+            pattern_type: PatternType::MonthNth {
+                days: DaysOfWeek::FRIDAY,
+                n: 5,
+            },
+            calendar_type: 0,
+            period: 1,
+            sliding: false,
+            end_type: EndType::Never,
+            occurrence_count: 0,
+            first_day_of_week: 0,
+            deleted_instance_dates: Vec::new(),
+            modified_instance_dates: Vec::new(),
+            start_date: at(1, 0).date(),
+            end_date: NaiveDate::from_ymd_opt(4500, 12, 31).unwrap(),
+        };
+        assert!(pattern.matches(at(26, 0).date()));
+        assert!(!pattern.matches(at(19, 0).date()));
+        assert!(pattern.matches(NaiveDate::from_ymd_opt(2023, 6, 30).unwrap()));
+    }
+}
@@ -0,0 +1,244 @@
+//! Delivery, non-delivery, read and not-read reports (`REPORT.*.DR`,
+//! `.NDR`, `.IPNRN` and `.IPNNRN`), from the properties in MS-OXOMSG.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{recipients::Recipient, EmailMessage, Tag};
+
+#[derive(Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum ReportKind {
+    /// A non-delivery report (NDR).
+    NonDelivery,
+    /// A delivery report (DR).
+    Delivery,
+    /// A read notification (RN).
+    Read,
+    /// A not-read notification (NRN).
+    NotRead,
+    Other(String),
+}
+
+impl ReportKind {
+    /// The kind is the last segment of the message class, such as `NDR` in
+    /// `REPORT.IPM.Note.NDR`.
+    pub fn from_class(class: &str) -> Self {
+        let kind = class.rsplit('.').next().unwrap_or_default();
+        match kind.to_ascii_lowercase().as_str() {
+            "ndr" => Self::NonDelivery,
+            "dr" => Self::Delivery,
+            "ipnrn" => Self::Read,
+            "ipnnrn" => Self::NotRead,
+            _ => Self::Other(kind.to_string()),
+        }
+    }
+}
+
+/// A recipient of the original message that the report is about.
+#[derive(Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq)]
+pub struct ReportRecipient {
+    pub name: Option<String>,
+    pub address: String,
+    /// The DSN status code (RFC 3463), such as `5.1.1`, where the
+    /// diagnostic text includes one.
+    pub status: Option<String>,
+    /// The diagnostic text from the remote server (PidTagSupplementaryInfo).
+    pub diagnostic: Option<String>,
+    /// PidTagNonDeliveryReportReasonCode, which is only set on recipients
+    /// the message could not be delivered to.
+    pub reason_code: Option<i32>,
+    /// PidTagNonDeliveryReportDiagCode.
+    pub diag_code: Option<i32>,
+    /// PidTagNonDeliveryReportStatusCode.
+    pub status_code: Option<i32>,
+    /// When the message was delivered to, or failed for, the recipient.
+    pub report_time: Option<DateTime<Utc>>,
+}
+
+impl ReportRecipient {
+    fn from_recipient(recipient: &Recipient) -> Self {
+        let properties = &recipient.properties;
+        let diagnostic = properties
+            .string(Tag::SupplementaryInfo)
+            .map(str::to_string);
+        Self {
+            name: recipient.name.clone(),
+            address: recipient.address.clone(),
+            status: diagnostic.as_deref().and_then(dsn_status),
+            diagnostic,
+            reason_code: properties.integer32(Tag::NonDeliveryReportReasonCode),
+            diag_code: properties.integer32(Tag::NonDeliveryReportDiagCode),
+            status_code: properties.integer32(Tag::NonDeliveryReportStatusCode),
+            report_time: properties.time(Tag::ReportTime),
+        }
+    }
+
+    /// Whether the message could not be delivered to the recipient.
+    pub fn failed(&self) -> bool {
+        self.reason_code.is_some()
+            || matches!(self.status.as_deref(), Some(status) if !status.starts_with('2'))
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq)]
+pub struct Report {
+    pub kind: ReportKind,
+    /// The subject of the original message.
+    pub original_subject: Option<String>,
+    pub original_sender_name: Option<String>,
+    pub original_sender_address: Option<String>,
+    pub original_submit_time: Option<DateTime<Utc>>,
+    /// The Message-ID of the original message, for finding it when it isn't
+    /// embedded in the report.
+    pub original_message_id: Option<String>,
+    /// The text of the report (PidTagReportText).
+    pub text: Option<String>,
+    /// For read and not-read notifications, how the message was disposed of
+    /// (PidTagReportDisposition), such as `displayed` or `deleted`.
+    pub disposition: Option<String>,
+    /// The server that generated the report.
+    pub reporting_server: Option<String>,
+    pub recipients: Vec<ReportRecipient>,
+    /// The original message, where the report includes it.
+    pub original_message: Option<Box<EmailMessage>>,
+}
+
+impl Report {
+    pub fn from_message(message: &EmailMessage) -> Self {
+        let properties = &message.properties;
+        let string = |tag: Tag| properties.string(tag).map(str::to_string);
+        let original_message = message
+            .attachments
+            .iter()
+            .find_map(|attachment| attachment.embedded_message.clone());
+        Self {
+            kind: ReportKind::from_class(message.message_class.as_deref().unwrap_or_default()),
+            original_subject: string(Tag::OriginalSubject).or_else(|| {
+                original_message
+                    .as_ref()
+                    .map(|original| original.subject.clone())
+            }),
+            original_sender_name: string(Tag::OriginalSenderName),
+            original_sender_address: string(Tag::OriginalSenderEmailAddress),
+            original_submit_time: properties.time(Tag::OriginalSubmitTime),
+            original_message_id: string(Tag::OriginalMessageId),
+            text: string(Tag::ReportText),
+            disposition: string(Tag::ReportDisposition),
+            reporting_server: string(Tag::ReportingMessageTransferAgent)
+                .or_else(|| string(Tag::RemoteMessageTransferAgent)),
+            recipients: message
+                .recipients
+                .iter()
+                .map(ReportRecipient::from_recipient)
+                .collect(),
+            original_message,
+        }
+    }
+
+    /// The recipients the original message could not be delivered to.
+    pub fn failed_recipients(&self) -> impl Iterator<Item = &ReportRecipient> {
+        self.recipients
+            .iter()
+            .filter(|recipient| recipient.failed())
+    }
+}
+
+/// Find a DSN status code, such as `5.1.1` in `#550 5.1.1 User unknown ##`.
+fn dsn_status(text: &str) -> Option<String> {
+    text.split(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .find(|word| {
+            let parts: Vec<&str> = word.split('.').collect();
+            parts.len() == 3
+                && matches!(parts[0], "2" | "4" | "5")
+                && parts[1..].iter().all(|part| (1..=3).contains(&part.len()))
+        })
+        .map(str::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        items::Item,
+        test_support::{integer32, MsgBuilder},
+    };
+
+    #[test]
+    fn status_codes() {
+        assert_eq!(
+            dsn_status("#550 5.1.1 RESOLVER.ADR.RecipNotFound; not found ##").as_deref(),
+            Some("5.1.1")
+        );
+        assert_eq!(dsn_status("smtp;554 5.7.1").as_deref(), Some("5.7.1"));
+        assert_eq!(dsn_status("version 1.2.3 of 10.0.0.1"), None);
+    }
+
+    #[test]
+    fn non_delivery_report() {
+        let mut builder = MsgBuilder::new();
+        builder.string("/", 0x001A, "REPORT.IPM.Note.NDR");
+        builder.string("/", 0x0049, "Lunch");
+        builder.string("/", 0x005A, "Alice");
+        builder.string("/", 0x0067, "alice@example.com");
+        for (i, address) in ["nobody@example.com", "bob@example.com"].iter().enumerate() {
+            let recipient = format!("/__recip_version1.0_#{i:08X}/");
+            builder.storage(&recipient);
+            builder.string(&recipient, 0x39FE, address);
+            if i == 0 {
+                builder.properties(&recipient, &[(0x0003, 0x0C04, integer32(1))]);
+                builder.string(
+                    &recipient,
+                    0x0C1B,
+                    "#550 5.1.1 RESOLVER.ADR.RecipNotFound; not found ##",
+                );
+            } else {
+                builder.properties(&recipient, &[]);
+            }
+        }
+        let msg = EmailMessage::from_bytes(&builder.build()).unwrap();
+
+        let report = match msg.item() {
+            Item::Report(report) => report,
+            item => panic!("not a report: {:?}", item),
+        };
+        assert_eq!(report.kind, ReportKind::NonDelivery);
+        assert_eq!(report.original_subject.as_deref(), Some("Lunch"));
+        assert_eq!(report.original_sender_name.as_deref(), Some("Alice"));
+        let failed: Vec<_> = report.failed_recipients().collect();
+        assert_eq!(failed.len(), 1);
+        assert_eq!(failed[0].address, "nobody@example.com");
+        assert_eq!(failed[0].status.as_deref(), Some("5.1.1"));
+        assert_eq!(failed[0].reason_code, Some(1));
+    }
+
+    #[test]
+    fn sparse_report() {
+        let mut builder = MsgBuilder::new();
+        builder.string("/", 0x001A, "REPORT.IPM.Note.IPNRN");
+        // A recipient without a property stream, whose diagnostic text has
+        // no status code.
+        let recipient = "/__recip_version1.0_#00000000/";
+        builder.storage(recipient);
+        builder.string(recipient, 0x39FE, "bob@example.com");
+        builder.string(recipient, 0x0C1B, "Delivered");
+        let attachment = "/__attach_version1.0_#00000000/";
+        builder.storage(attachment);
+        builder.properties(attachment, &[(0x0003, 0x3705, integer32(5))]);
+        builder.string(attachment, 0x3001, "Lunch");
+        let original = format!("{attachment}__substg1.0_3701000D/");
+        builder.storage(&original);
+        builder.message(&original, "Lunch", "alice@example.com", "");
+        let msg = EmailMessage::from_bytes(&builder.build()).unwrap();
+
+        let report = Report::from_message(&msg);
+        assert_eq!(report.kind, ReportKind::Read);
+        assert_eq!(report.original_subject.as_deref(), Some("Lunch"));
+        assert_eq!(report.original_sender_address, None);
+        assert_eq!(report.recipients[0].status, None);
+        assert_eq!(report.failed_recipients().count(), 0);
+        assert_eq!(
+            ReportKind::from_class("REPORT.IPM.Note"),
+            ReportKind::Other("Note".to_string())
+        );
+    }
+}
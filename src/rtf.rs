@@ -0,0 +1,249 @@
+//! Compressed RTF as described in MS-OXRTFCP, plus just enough RTF parsing to
+//! pull hyperlink fields back out of the decompressed text.
+
+use std::convert::TryInto;
+
+/// The dictionary is pre-loaded with this string before decompression begins.
+const INITIAL_DICTIONARY: &[u8] = b"{\\rtf1\\ansi\\mac\\deff0\\deftab720{\\fonttbl;}{\\f0\\fnil \\froman \\fswiss \\fmodern \\fscript \\fdecor MS Sans SerifSymbolArialTimes New RomanCourier{\\colortbl\\red0\\green0\\blue0\r\n\\par \\pard\\plain\\f0\\fs20\\b\\i\\u\\tab\\tx";
+const DICTIONARY_SIZE: usize = 4096;
+const COMPRESSED: u32 = 0x75465A4C;
+const UNCOMPRESSED: u32 = 0x414C454D;
+
+/// Decompress the contents of a PidTagRtfCompressed stream.
+pub fn decompress(data: &[u8]) -> Result<Vec<u8>, &'static str> {
+    if data.len() < 16 {
+        return Err("compressed RTF header is truncated");
+    }
+    let raw_size = u32::from_le_bytes(data[4..8].try_into().unwrap()) as usize;
+    let comp_type = u32::from_le_bytes(data[8..12].try_into().unwrap());
+    let body = &data[16..];
+    match comp_type {
+        UNCOMPRESSED => Ok(body[..raw_size.min(body.len())].to_vec()),
+        COMPRESSED => {
+            let mut dictionary = [0_u8; DICTIONARY_SIZE];
+            dictionary[..INITIAL_DICTIONARY.len()].copy_from_slice(INITIAL_DICTIONARY);
+            let mut write_offset = INITIAL_DICTIONARY.len();
+            // Each two-byte reference expands to at most 17 bytes, so the
+            // output can't be more than eight times the input, whatever the
+            // header claims.
+            let mut output = Vec::with_capacity(raw_size.min(body.len() * 8));
+            let mut input = body.iter().copied();
+            'outer: while let Some(control) = input.next() {
+                for bit in 0..8 {
+                    if control & (1 << bit) == 0 {
+                        let byte = match input.next() {
+                            Some(byte) => byte,
+                            None => break 'outer,
+                        };
+                        output.push(byte);
+                        dictionary[write_offset] = byte;
+                        write_offset = (write_offset + 1) % DICTIONARY_SIZE;
+                    } else {
+                        let reference = match (input.next(), input.next()) {
+                            (Some(high), Some(low)) => u16::from_be_bytes([high, low]),
+                            _ => break 'outer,
+                        };
+                        let offset = (reference >> 4) as usize;
+                        let length = (reference & 0xF) as usize + 2;
+                        if offset == write_offset {
+                            break 'outer;
+                        }
+                        for i in 0..length {
+                            let byte = dictionary[(offset + i) % DICTIONARY_SIZE];
+                            output.push(byte);
+                            dictionary[write_offset] = byte;
+                            write_offset = (write_offset + 1) % DICTIONARY_SIZE;
+                        }
+                    }
+                }
+            }
+            output.truncate(raw_size);
+            Ok(output)
+        }
+        _ => Err("unknown compressed RTF type"),
+    }
+}
+
+/// A HYPERLINK field found in RTF text.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct RtfHyperlink {
+    pub url: String,
+    pub text: Option<String>,
+    /// Byte offset of the URL within the RTF text.
+    pub position: usize,
+}
+
+/// Find all `{\field{\*\fldinst HYPERLINK "..."}{\fldrslt ...}}` fields.
+pub(crate) fn hyperlinks(rtf: &str) -> Vec<RtfHyperlink> {
+    let mut links = Vec::new();
+    let mut search_from = 0;
+    while let Some(found) = rtf[search_from..].find("HYPERLINK") {
+        let start = search_from + found + "HYPERLINK".len();
+        search_from = start;
+        let rest = &rtf[start..];
+        // Switches such as \l may precede the quoted target.
+        let open = match rest.find('"') {
+            Some(open) if !rest[..open].contains('}') => open,
+            _ => continue,
+        };
+        let close = match rest[open + 1..].find('"') {
+            Some(close) => open + 1 + close,
+            None => continue,
+        };
+        let url = plain_text(&rest[open + 1..close]).trim().to_string();
+        if url.is_empty() {
+            continue;
+        }
+        let position = start + open + 1;
+        let text = rest[close..].find("\\fldrslt").and_then(|result| {
+            let result_start = close + result + "\\fldrslt".len();
+            let group = &rest[result_start..];
+            let end = group_end(group);
+            let text = plain_text(&group[..end]);
+            let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+            if text.is_empty() {
+                None
+            } else {
+                Some(text)
+            }
+        });
+        links.push(RtfHyperlink {
+            url,
+            text,
+            position,
+        });
+    }
+    links
+}
+
+/// Returns the index of the brace that closes the group we are currently in.
+fn group_end(rtf: &str) -> usize {
+    let mut depth = 0_usize;
+    let mut chars = rtf.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\\' => {
+                chars.next();
+            }
+            '{' => depth += 1,
+            '}' => {
+                if depth == 0 {
+                    return i;
+                }
+                depth -= 1;
+            }
+            _ => (),
+        }
+    }
+    rtf.len()
+}
+
+/// Strip control words and groups from a fragment of RTF, leaving the text.
+fn plain_text(rtf: &str) -> String {
+    let mut text = String::new();
+    let mut chars = rtf.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' | '}' | '\r' | '\n' => (),
+            '\\' => match chars.peek().copied() {
+                Some(escaped @ ('\\' | '{' | '}')) => {
+                    chars.next();
+                    text.push(escaped);
+                }
+                Some('\'') => {
+                    chars.next();
+                    let hex: String = chars.by_ref().take(2).collect();
+                    if let Ok(byte) = u8::from_str_radix(&hex, 16) {
+                        text.push(byte as char);
+                    }
+                }
+                Some(c) if c.is_ascii_alphabetic() => {
+                    let mut word = String::new();
+                    while let Some(c) = chars.peek().copied() {
+                        if c.is_ascii_alphabetic() {
+                            word.push(c);
+                            chars.next();
+                        } else {
+                            break;
+                        }
+                    }
+                    let mut parameter = String::new();
+                    while let Some(c) = chars.peek().copied() {
+                        if c.is_ascii_digit() || (c == '-' && parameter.is_empty()) {
+                            parameter.push(c);
+                            chars.next();
+                        } else {
+                            break;
+                        }
+                    }
+                    if chars.peek() == Some(&' ') {
+                        chars.next();
+                    }
+                    match word.as_str() {
+                        "u" => {
+                            if let Ok(n) = parameter.parse::<i32>() {
+                                let n = if n < 0 { n + 0x10000 } else { n };
+                                if let Some(c) = std::char::from_u32(n as u32) {
+                                    text.push(c);
+                                }
+                                // Skip the ANSI fallback character.
+                                if chars.peek().is_some() {
+                                    chars.next();
+                                }
+                            }
+                        }
+                        "tab" => text.push('\t'),
+                        "par" | "line" => text.push('\n'),
+                        _ => (),
+                    }
+                }
+                Some(_) => {
+                    chars.next();
+                }
+                None => (),
+            },
+            c => text.push(c),
+        }
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decompress_spec_example() {
+        assert_eq!(INITIAL_DICTIONARY.len(), 207);
+        let compressed = [
+            0x2d, 0x00, 0x00, 0x00, 0x2b, 0x00, 0x00, 0x00, 0x4c, 0x5a, 0x46, 0x75, 0xf1, 0xc5,
+            0xc7, 0xa7, 0x03, 0x00, 0x0a, 0x00, 0x72, 0x63, 0x70, 0x67, 0x31, 0x32, 0x35, 0x42,
+            0x32, 0x0a, 0xf3, 0x20, 0x68, 0x65, 0x6c, 0x09, 0x00, 0x20, 0x62, 0x77, 0x05, 0xb0,
+            0x6c, 0x64, 0x7d, 0x0a, 0x80, 0x0f, 0xa0,
+        ];
+        let rtf = decompress(&compressed).unwrap();
+        assert_eq!(
+            String::from_utf8(rtf).unwrap(),
+            "{\\rtf1\\ansi\\ansicpg1252\\pard hello world}\r\n"
+        );
+    }
+
+    #[test]
+    fn decompress_oversized_raw_size() {
+        // The raw size is far larger than the data could expand to.
+        let mut compressed = vec![0x13, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff];
+        compressed.extend_from_slice(&COMPRESSED.to_le_bytes());
+        compressed.extend_from_slice(&[0, 0, 0, 0, 0x00, b'{', b'}']);
+        assert_eq!(decompress(&compressed).unwrap(), b"{}");
+    }
+
+    #[test]
+    fn hyperlink_fields() {
+        let rtf = r#"{\rtf1 see {\field{\*\fldinst{HYPERLINK "https://example.com/a?b=1"}}{\fldrslt{\ul\cf1 Example \'e9}}} done}"#;
+        let links = hyperlinks(rtf);
+        assert_eq!(links.len(), 1);
+        assert_eq!(links[0].url, "https://example.com/a?b=1");
+        assert_eq!(links[0].text.as_deref(), Some("Example é"));
+        assert_eq!(&rtf[links[0].position..][..8], "https://");
+    }
+}
@@ -0,0 +1,422 @@
+//! Typed values of the common message properties that describe its state:
+//! importance, sensitivity, flags, follow-up status, icon and locale.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{note::NoteColor, EmailMessage, Lid, Tag};
+
+/// PidTagImportance.
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum Importance {
+    Low,
+    Normal,
+    High,
+    Other(i32),
+}
+
+impl Importance {
+    pub fn from_i32(n: i32) -> Self {
+        match n {
+            0 => Self::Low,
+            1 => Self::Normal,
+            2 => Self::High,
+            n => Self::Other(n),
+        }
+    }
+}
+
+/// PidTagPriority.
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum Priority {
+    NonUrgent,
+    Normal,
+    Urgent,
+    Other(i32),
+}
+
+impl Priority {
+    pub fn from_i32(n: i32) -> Self {
+        match n {
+            -1 => Self::NonUrgent,
+            0 => Self::Normal,
+            1 => Self::Urgent,
+            n => Self::Other(n),
+        }
+    }
+}
+
+/// PidTagSensitivity.
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum Sensitivity {
+    Normal,
+    Personal,
+    Private,
+    Confidential,
+    Other(i32),
+}
+
+impl Sensitivity {
+    pub fn from_i32(n: i32) -> Self {
+        match n {
+            0 => Self::Normal,
+            1 => Self::Personal,
+            2 => Self::Private,
+            3 => Self::Confidential,
+            n => Self::Other(n),
+        }
+    }
+}
+
+bitflags::bitflags! {
+    /// PidTagMessageFlags.
+    #[derive(Serialize, Deserialize)]
+    pub struct MessageFlags: u32 {
+        const READ = 0x0001;
+        const UNMODIFIED = 0x0002;
+        /// The message has been submitted for sending.
+        const SUBMIT = 0x0004;
+        /// The message is a draft.
+        const UNSENT = 0x0008;
+        const HAS_ATTACH = 0x0010;
+        /// The user who sent the message is the mailbox owner.
+        const FROM_ME = 0x0020;
+        /// The message is a folder associated information item, such as a
+        /// view or rule, rather than a normal message.
+        const ASSOCIATED = 0x0040;
+        const RESEND = 0x0080;
+        /// A read receipt will be sent when the message is read.
+        const NOTIFY_READ = 0x0100;
+        /// A not-read receipt will be sent if the message is deleted unread.
+        const NOTIFY_UNREAD = 0x0200;
+        /// The message has been read at some time.
+        const EVER_READ = 0x0400;
+        /// The message came from the internet.
+        const ORIGIN_INTERNET = 0x2000;
+        const UNTRUSTED = 0x8000;
+    }
+}
+
+/// PidTagFlagStatus.
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum FlagStatus {
+    Unflagged,
+    Complete,
+    Flagged,
+    Other(i32),
+}
+
+impl FlagStatus {
+    pub fn from_i32(n: i32) -> Self {
+        match n {
+            0 => Self::Unflagged,
+            1 => Self::Complete,
+            2 => Self::Flagged,
+            n => Self::Other(n),
+        }
+    }
+}
+
+/// PidTagIconIndex, the icon Outlook shows for the message in a folder.
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum IconIndex {
+    NewMail,
+    Post,
+    ReadMail,
+    UnreadMail,
+    SubmittedMail,
+    UnsentMail,
+    ReceiptMail,
+    RepliedMail,
+    ForwardedMail,
+    RemoteMail,
+    DeliveryReceipt,
+    ReadReceipt,
+    NonDeliveryReport,
+    NotReadReceipt,
+    RecallSuccess,
+    RecallFailure,
+    Tracking,
+    Contact,
+    DistList,
+    StickyNote(NoteColor),
+    Appointment,
+    RecurringAppointment,
+    /// A meeting with a single instance, as opposed to a request for one.
+    Meeting,
+    RecurringMeeting,
+    /// A meeting request or full update.
+    MeetingRequest,
+    MeetingAccepted,
+    MeetingDeclined,
+    MeetingTentative,
+    MeetingCancellation,
+    MeetingUpdate,
+    Task,
+    RecurringTask,
+    /// The assignee's copy of an assigned task.
+    AssigneeTask,
+    /// The assigner's copy of an assigned task.
+    AssignerTask,
+    TaskRequest,
+    TaskAccepted,
+    TaskDeclined,
+    Journal,
+    Other(i32),
+}
+
+impl IconIndex {
+    pub fn from_i32(n: i32) -> Self {
+        match n {
+            -1 => Self::NewMail,
+            0x0003 => Self::Post,
+            0x0100 => Self::ReadMail,
+            0x0101 => Self::UnreadMail,
+            0x0102 => Self::SubmittedMail,
+            0x0103 => Self::UnsentMail,
+            0x0104 => Self::ReceiptMail,
+            0x0105 => Self::RepliedMail,
+            0x0106 => Self::ForwardedMail,
+            0x0107 => Self::RemoteMail,
+            0x0108 => Self::DeliveryReceipt,
+            0x0109 => Self::ReadReceipt,
+            0x010A => Self::NonDeliveryReport,
+            0x010B => Self::NotReadReceipt,
+            0x010C => Self::RecallSuccess,
+            0x010D => Self::RecallFailure,
+            0x010E => Self::Tracking,
+            0x0200 => Self::Contact,
+            0x0202 => Self::DistList,
+            0x0300..=0x0304 => Self::StickyNote(NoteColor::from_i32(n - 0x0300)),
+            0x0400 => Self::Appointment,
+            0x0401 => Self::RecurringAppointment,
+            0x0402 => Self::Meeting,
+            0x0403 => Self::RecurringMeeting,
+            0x0404 => Self::MeetingRequest,
+            0x0405 => Self::MeetingAccepted,
+            0x0406 => Self::MeetingDeclined,
+            0x0407 => Self::MeetingTentative,
+            0x0408 => Self::MeetingCancellation,
+            0x0409 => Self::MeetingUpdate,
+            0x0500 => Self::Task,
+            0x0501 => Self::RecurringTask,
+            0x0502 => Self::AssigneeTask,
+            0x0503 => Self::AssignerTask,
+            0x0504 => Self::TaskRequest,
+            0x0505 => Self::TaskAccepted,
+            0x0506 => Self::TaskDeclined,
+            0x0601 => Self::Journal,
+            n => Self::Other(n),
+        }
+    }
+}
+
+/// The BCP 47 language tag for a Windows locale ID, for the most common
+/// locales.
+pub fn language_tag(locale_id: u32) -> Option<&'static str> {
+    let tag = match locale_id {
+        0x0401 => "ar-SA",
+        0x0402 => "bg-BG",
+        0x0403 => "ca-ES",
+        0x0404 => "zh-TW",
+        0x0405 => "cs-CZ",
+        0x0406 => "da-DK",
+        0x0407 => "de-DE",
+        0x0408 => "el-GR",
+        0x0409 => "en-US",
+        0x040B => "fi-FI",
+        0x040C => "fr-FR",
+        0x040D => "he-IL",
+        0x040E => "hu-HU",
+        0x040F => "is-IS",
+        0x0410 => "it-IT",
+        0x0411 => "ja-JP",
+        0x0412 => "ko-KR",
+        0x0413 => "nl-NL",
+        0x0414 => "nb-NO",
+        0x0415 => "pl-PL",
+        0x0416 => "pt-BR",
+        0x0418 => "ro-RO",
+        0x0419 => "ru-RU",
+        0x041A => "hr-HR",
+        0x041B => "sk-SK",
+        0x041D => "sv-SE",
+        0x041E => "th-TH",
+        0x041F => "tr-TR",
+        0x0421 => "id-ID",
+        0x0422 => "uk-UA",
+        0x0424 => "sl-SI",
+        0x0425 => "et-EE",
+        0x0426 => "lv-LV",
+        0x0427 => "lt-LT",
+        0x0429 => "fa-IR",
+        0x042A => "vi-VN",
+        0x0439 => "hi-IN",
+        0x043E => "ms-MY",
+        0x0804 => "zh-CN",
+        0x0807 => "de-CH",
+        0x0809 => "en-GB",
+        0x080A => "es-MX",
+        0x080C => "fr-BE",
+        0x0810 => "it-CH",
+        0x0813 => "nl-BE",
+        0x0814 => "nn-NO",
+        0x0816 => "pt-PT",
+        0x081A => "sr-Latn-CS",
+        0x0C04 => "zh-HK",
+        0x0C07 => "de-AT",
+        0x0C09 => "en-AU",
+        0x0C0A => "es-ES",
+        0x0C0C => "fr-CA",
+        0x1004 => "zh-SG",
+        0x1009 => "en-CA",
+        0x100C => "fr-CH",
+        0x1409 => "en-NZ",
+        0x1809 => "en-IE",
+        0x1C09 => "en-ZA",
+        0x4009 => "en-IN",
+        _ => return None,
+    };
+    Some(tag)
+}
+
+impl EmailMessage {
+    pub fn importance(&self) -> Option<Importance> {
+        self.properties
+            .integer32(Tag::Importance)
+            .map(Importance::from_i32)
+    }
+
+    pub fn priority(&self) -> Option<Priority> {
+        self.properties
+            .integer32(Tag::Priority)
+            .map(Priority::from_i32)
+    }
+
+    pub fn sensitivity(&self) -> Option<Sensitivity> {
+        self.properties
+            .integer32(Tag::Sensitivity)
+            .map(Sensitivity::from_i32)
+    }
+
+    pub fn message_flags(&self) -> MessageFlags {
+        self.properties
+            .integer32(Tag::MessageFlags)
+            .map(|flags| MessageFlags::from_bits_truncate(flags as u32))
+            .unwrap_or_else(MessageFlags::empty)
+    }
+
+    pub fn flag_status(&self) -> Option<FlagStatus> {
+        self.properties
+            .integer32(Tag::FlagStatus)
+            .map(FlagStatus::from_i32)
+    }
+
+    /// When the follow-up flag was marked complete.
+    pub fn flag_complete_time(&self) -> Option<DateTime<Utc>> {
+        self.properties.time(Tag::FlagCompleteTime)
+    }
+
+    /// The follow-up action, such as `Follow up` or `Reply to All`
+    /// (PidLidFlagRequest).
+    pub fn flag_request(&self) -> Option<&str> {
+        self.properties.string(Lid::FlagRequest)
+    }
+
+    pub fn icon_index(&self) -> Option<IconIndex> {
+        self.properties
+            .integer32(Tag::IconIndex)
+            .map(IconIndex::from_i32)
+    }
+
+    /// Whether the sender asked for a read receipt.
+    pub fn read_receipt_requested(&self) -> bool {
+        self.properties
+            .boolean(Tag::ReadReceiptRequested)
+            .unwrap_or(false)
+    }
+
+    /// Whether the sender asked for a delivery report.
+    pub fn delivery_report_requested(&self) -> bool {
+        self.properties
+            .boolean(Tag::OriginatorDeliveryReportRequested)
+            .unwrap_or(false)
+    }
+
+    /// PidTagMessageLocaleId, a Windows locale ID.
+    pub fn locale_id(&self) -> Option<u32> {
+        self.properties
+            .integer32(Tag::MessageLocaleId)
+            .map(|id| id as u32)
+    }
+
+    /// The locale of the message as a BCP 47 language tag, such as `en-US`.
+    pub fn language_tag(&self) -> Option<&'static str> {
+        language_tag(self.locale_id()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        named::PropertyName,
+        test_support::{boolean, integer32, MsgBuilder},
+        PropertySet,
+    };
+
+    #[test]
+    fn message_state() {
+        let msg = EmailMessage::from_bytes(&MsgBuilder::new().build()).unwrap();
+        assert_eq!(msg.importance(), None);
+        assert!(msg.message_flags().is_empty());
+        assert_eq!(msg.language_tag(), None);
+
+        let mut builder = MsgBuilder::new();
+        builder.named(&[(PropertySet::Common, PropertyName::Id(0x8530))]);
+        builder.string("/", 0x8000, "Reply to All");
+        builder.properties(
+            "/",
+            &[
+                (0x0003, 0x0017, integer32(2)),
+                (0x0003, 0x0026, integer32(-1)),
+                (0x0003, 0x0036, integer32(3)),
+                (0x0003, 0x0E07, integer32(0x0031)),
+                (0x0003, 0x1090, integer32(2)),
+                (0x0003, 0x1080, integer32(0x0105)),
+                (0x000B, 0x0029, boolean(true)),
+                (0x0003, 0x3FF1, integer32(0x0809)),
+            ],
+        );
+        let msg = EmailMessage::from_bytes(&builder.build()).unwrap();
+        assert_eq!(msg.importance(), Some(Importance::High));
+        assert_eq!(msg.priority(), Some(Priority::NonUrgent));
+        assert_eq!(msg.sensitivity(), Some(Sensitivity::Confidential));
+        assert_eq!(
+            msg.message_flags(),
+            MessageFlags::READ | MessageFlags::HAS_ATTACH | MessageFlags::FROM_ME
+        );
+        assert_eq!(msg.flag_status(), Some(FlagStatus::Flagged));
+        assert_eq!(msg.flag_request(), Some("Reply to All"));
+        assert_eq!(msg.icon_index(), Some(IconIndex::RepliedMail));
+        assert!(msg.read_receipt_requested());
+        assert!(!msg.delivery_report_requested());
+        assert_eq!(msg.language_tag(), Some("en-GB"));
+    }
+
+    #[test]
+    fn icon_index() {
+        for (n, icon) in [
+            (0x0300, IconIndex::StickyNote(NoteColor::Blue)),
+            (0x0304, IconIndex::StickyNote(NoteColor::White)),
+            (0x0402, IconIndex::Meeting),
+            (0x0404, IconIndex::MeetingRequest),
+            (0x0502, IconIndex::AssigneeTask),
+            (0x0503, IconIndex::AssignerTask),
+            (0x0504, IconIndex::TaskRequest),
+            (0x0305, IconIndex::Other(0x0305)),
+        ] {
+            assert_eq!(IconIndex::from_i32(n), icon);
+        }
+    }
+}
@@ -0,0 +1,414 @@
+//! Tasks (`IPM.Task`) and the requests that assign them to other people
+//! (`IPM.TaskRequest`), from the properties in MS-OXOTASK.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    appointment::{Attendee, ResponseStatus},
+    ical::{self, ContentWriter},
+    items::MessageKind,
+    EmailMessage, Lid, Tag,
+};
+
+/// PidLidTaskStatus.
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum TaskStatus {
+    NotStarted,
+    InProgress,
+    Complete,
+    /// Waiting on someone else.
+    Waiting,
+    Deferred,
+    Other(i32),
+}
+
+impl TaskStatus {
+    pub fn from_i32(n: i32) -> Self {
+        match n {
+            0 => Self::NotStarted,
+            1 => Self::InProgress,
+            2 => Self::Complete,
+            3 => Self::Waiting,
+            4 => Self::Deferred,
+            n => Self::Other(n),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq)]
+pub struct Task {
+    pub subject: String,
+    pub description: Option<String>,
+    pub status: Option<TaskStatus>,
+    /// From 0 to 100 (PidLidPercentComplete).
+    pub percent_complete: Option<u8>,
+    pub complete: bool,
+    /// The start and due dates are dates in the owner's time zone
+    /// (PidLidTaskStartDate and PidLidTaskDueDate).
+    pub start_date: Option<NaiveDate>,
+    pub due_date: Option<NaiveDate>,
+    pub date_completed: Option<NaiveDate>,
+    /// The name of the person the task is assigned to (PidLidTaskOwner).
+    pub owner: Option<String>,
+    /// The name of the person who assigned the task (PidLidTaskAssigner).
+    pub assigner: Option<String>,
+    /// PidLidTaskEstimatedEffort, in minutes.
+    pub estimated_effort_minutes: Option<i32>,
+    /// PidLidTaskActualEffort, in minutes.
+    pub actual_effort_minutes: Option<i32>,
+    pub recurring: bool,
+    /// When the reminder is due, if one is set.
+    pub reminder_time: Option<DateTime<Utc>>,
+    /// PidLidTaskGlobalId in hex, which is shared by every copy of an
+    /// assigned task, or else PidTagSearchKey or PidTagEntryId in hex.
+    pub uid: Option<String>,
+    /// PidTagLastModificationTime, or PidTagCreationTime if it has not been
+    /// modified.
+    pub last_modified: Option<DateTime<Utc>>,
+}
+
+impl Task {
+    pub fn from_message(message: &EmailMessage) -> Self {
+        let properties = &message.properties;
+        let date = |lid: Lid| properties.time(lid).map(|time| time.date_naive());
+        Self {
+            subject: message.subject.clone(),
+            description: message.body.clone().filter(|body| !body.is_empty()),
+            status: properties
+                .integer32(Lid::TaskStatus)
+                .map(TaskStatus::from_i32),
+            percent_complete: properties
+                .floating64(Lid::PercentComplete)
+                .map(|percent| (percent * 100.0).round().clamp(0.0, 100.0) as u8),
+            complete: properties.boolean(Lid::TaskComplete).unwrap_or(false),
+            start_date: date(Lid::TaskStartDate),
+            due_date: date(Lid::TaskDueDate),
+            date_completed: date(Lid::TaskDateCompleted),
+            owner: properties.string(Lid::TaskOwner).map(str::to_string),
+            assigner: properties.string(Lid::TaskAssigner).map(str::to_string),
+            estimated_effort_minutes: properties.integer32(Lid::TaskEstimatedEffort),
+            actual_effort_minutes: properties.integer32(Lid::TaskActualEffort),
+            recurring: properties.boolean(Lid::TaskFRecurring).unwrap_or(false),
+            reminder_time: if properties.boolean(Lid::ReminderSet).unwrap_or(false) {
+                properties.time(Lid::ReminderTime)
+            } else {
+                None
+            },
+            uid: properties
+                .binary(Lid::TaskGlobalId)
+                .or_else(|| properties.binary(Tag::SearchKey))
+                .or_else(|| properties.binary(Tag::EntryId))
+                .filter(|id| !id.is_empty())
+                .map(|id| id.iter().map(|byte| format!("{byte:02X}")).collect()),
+            last_modified: properties
+                .time(Tag::LastModificationTime)
+                .or_else(|| properties.time(Tag::CreationTime)),
+        }
+    }
+
+    /// The task as an iCalendar (RFC 5545) object with a single VTODO.
+    pub fn to_ical(&self) -> String {
+        self.write_ical("PUBLISH", None)
+    }
+
+    /// Writes the task with the given METHOD. Requests and replies need
+    /// the organizer and the attendee the task is assigned to.
+    fn write_ical(&self, method: &str, participants: Option<(&Attendee, &Attendee)>) -> String {
+        let mut writer = ContentWriter::new();
+        writer.begin("VCALENDAR");
+        writer.line("VERSION", "2.0");
+        writer.line("PRODID", "-//ms-oxmsg//EN");
+        writer.line("METHOD", method);
+        writer.begin("VTODO");
+        let dtstamp = ical::format_utc(self.last_modified.unwrap_or_else(Utc::now));
+        // Every VTODO needs a UID, even when the message has no ID.
+        let uid = self
+            .uid
+            .clone()
+            .unwrap_or_else(|| ical::fallback_uid(&self.subject, self.last_modified));
+        writer.text("UID", &uid);
+        writer.line("DTSTAMP", &dtstamp);
+        if let Some((organizer, attendee)) = participants {
+            organizer.write_ical(&mut writer, "ORGANIZER", "");
+            let partstat = attendee
+                .status
+                .map_or("NEEDS-ACTION", ResponseStatus::partstat);
+            attendee.write_ical(&mut writer, "ATTENDEE", &format!(";PARTSTAT={partstat}"));
+        }
+        writer.text("SUMMARY", &self.subject);
+        if let Some(description) = &self.description {
+            writer.text("DESCRIPTION", description);
+        }
+        if let Some(start) = self.start_date {
+            writer.line("DTSTART;VALUE=DATE", &ical::format_date(start));
+        }
+        if let Some(due) = self.due_date {
+            writer.line("DUE;VALUE=DATE", &ical::format_date(due));
+        }
+        let status = match self.status {
+            _ if self.complete => "COMPLETED",
+            Some(TaskStatus::Complete) => "COMPLETED",
+            Some(TaskStatus::InProgress) | Some(TaskStatus::Waiting) => "IN-PROCESS",
+            _ => "NEEDS-ACTION",
+        };
+        writer.line("STATUS", status);
+        if let Some(completed) = self.date_completed {
+            let completed = completed.and_hms_opt(0, 0, 0).unwrap().and_utc();
+            writer.line("COMPLETED", &ical::format_utc(completed));
+        }
+        if let Some(percent) = self.percent_complete {
+            writer.line("PERCENT-COMPLETE", &percent.to_string());
+        }
+        if let Some(reminder_time) = self.reminder_time {
+            writer.begin("VALARM");
+            writer.line("ACTION", "DISPLAY");
+            writer.text("DESCRIPTION", "Reminder");
+            writer.line("TRIGGER;VALUE=DATE-TIME", &ical::format_utc(reminder_time));
+            writer.end("VALARM");
+        }
+        writer.end("VTODO");
+        writer.end("VCALENDAR");
+        writer.finish()
+    }
+}
+
+/// What a task request message is doing.
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum TaskRequestKind {
+    /// Assigning the task (`IPM.TaskRequest`).
+    Request,
+    Accept,
+    Decline,
+    /// An update to the status of the task.
+    Update,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq)]
+pub struct TaskRequest {
+    pub kind: TaskRequestKind,
+    /// The task, from the message embedded in the request.
+    pub task: Option<Task>,
+    /// Who assigned the task: the sender of a request, or the recipient of
+    /// a response or update.
+    pub assigner: Option<Attendee>,
+    /// Who the task is assigned to: the recipient of a request, or the
+    /// sender of a response or update.
+    pub assignee: Option<Attendee>,
+}
+
+impl TaskRequest {
+    pub fn from_message(message: &EmailMessage) -> Self {
+        let class = message
+            .message_class
+            .as_deref()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        let kind = match class.strip_prefix("ipm.taskrequest.") {
+            Some(kind) if kind.starts_with("accept") => TaskRequestKind::Accept,
+            Some(kind) if kind.starts_with("decline") => TaskRequestKind::Decline,
+            Some(kind) if kind.starts_with("update") => TaskRequestKind::Update,
+            _ => TaskRequestKind::Request,
+        };
+        let recipient = message.recipients.first().map(Attendee::from);
+        let (assigner, assignee) = match kind {
+            TaskRequestKind::Request => (Attendee::sender(message, None), recipient),
+            _ => {
+                let status = match kind {
+                    TaskRequestKind::Accept => Some(ResponseStatus::Accepted),
+                    TaskRequestKind::Decline => Some(ResponseStatus::Declined),
+                    _ => None,
+                };
+                (recipient, Attendee::sender(message, status))
+            }
+        };
+        Self {
+            kind,
+            task: message.task_attachment().map(Task::from_message),
+            assigner,
+            assignee,
+        }
+    }
+
+    /// The task as an iCalendar (RFC 5545) object with a single VTODO, with
+    /// its METHOD set according to the kind of request. Requests and
+    /// replies without both an assigner and an assignee are published
+    /// instead, since iTIP needs an ORGANIZER and ATTENDEE for them.
+    pub fn to_ical(&self) -> Option<String> {
+        let task = self.task.as_ref()?;
+        let participants = self.assigner.as_ref().zip(self.assignee.as_ref());
+        let method = match self.kind {
+            _ if participants.is_none() => "PUBLISH",
+            TaskRequestKind::Request => "REQUEST",
+            TaskRequestKind::Accept | TaskRequestKind::Decline => "REPLY",
+            TaskRequestKind::Update => "PUBLISH",
+        };
+        Some(task.write_ical(method, participants))
+    }
+}
+
+impl EmailMessage {
+    /// The task embedded in a task request, accept, decline or update.
+    pub fn task_attachment(&self) -> Option<&EmailMessage> {
+        self.attachments
+            .iter()
+            .filter_map(|attachment| attachment.embedded_message.as_deref())
+            .find(|message| message.kind() == MessageKind::Task)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+    use crate::{
+        items::Item,
+        named::PropertyName,
+        test_support::{boolean, filetime, integer32, MsgBuilder},
+        PropertySet,
+    };
+
+    /// A task request from the manager with the task embedded in it.
+    fn request_builder() -> MsgBuilder {
+        let due = Utc.with_ymd_and_hms(2023, 5, 12, 0, 0, 0).unwrap();
+        let mut builder = MsgBuilder::new();
+        builder.string("/", 0x001A, "IPM.TaskRequest");
+        builder.string("/", 0x0C1F, "manager@example.com");
+        builder.string("/", 0x0042, "Manager");
+        builder.named(&[
+            (PropertySet::Task, PropertyName::Id(0x8101)),
+            (PropertySet::Task, PropertyName::Id(0x8102)),
+            (PropertySet::Task, PropertyName::Id(0x8105)),
+            (PropertySet::Task, PropertyName::Id(0x8111)),
+            (PropertySet::Task, PropertyName::Id(0x8121)),
+            (PropertySet::Task, PropertyName::Id(0x811C)),
+        ]);
+        let attachment = "/__attach_version1.0_#00000000/";
+        builder.storage(attachment);
+        builder.properties(attachment, &[(0x0003, 0x3705, integer32(5))]);
+        builder.string(attachment, 0x3001, "Write report");
+        let task = format!("{attachment}__substg1.0_3701000D/");
+        builder.storage(&task);
+        builder.message(&task, "Write report", "", "Quarterly numbers");
+        builder.string(&task, 0x001A, "IPM.Task");
+        let mut percent = [0; 8];
+        percent.copy_from_slice(&0.25_f64.to_le_bytes());
+        builder.properties(
+            &task,
+            &[
+                (0x0003, 0x8000, integer32(1)),
+                (0x0005, 0x8001, percent),
+                (0x0040, 0x8002, filetime(due)),
+                (0x0003, 0x8003, integer32(120)),
+                (0x000B, 0x8005, boolean(false)),
+            ],
+        );
+        builder.string(&task, 0x8004, "Manager");
+        builder
+    }
+
+    #[test]
+    fn task_request() {
+        let mut builder = request_builder();
+        let recipient = "/__recip_version1.0_#00000000/";
+        builder.storage(recipient);
+        builder.properties(recipient, &[(0x0003, 0x0C15, integer32(1))]);
+        builder.string(recipient, 0x3001, "Worker");
+        builder.string(recipient, 0x39FE, "worker@example.com");
+        let msg = EmailMessage::from_bytes(&builder.build()).unwrap();
+
+        // Tasks, even when embedded in a request, have no delivery time.
+        let embedded = msg.task_attachment().unwrap();
+        assert_eq!(embedded.delivery_time, None);
+        let request = match msg.item() {
+            Item::TaskRequest(request) => request,
+            item => panic!("not a task request: {:?}", item),
+        };
+        assert_eq!(request.kind, TaskRequestKind::Request);
+        let task = request.task.as_ref().unwrap();
+        assert_eq!(task.subject, "Write report");
+        assert_eq!(task.status, Some(TaskStatus::InProgress));
+        assert_eq!(task.percent_complete, Some(25));
+        assert_eq!(task.due_date, NaiveDate::from_ymd_opt(2023, 5, 12));
+        assert_eq!(task.estimated_effort_minutes, Some(120));
+        assert_eq!(task.assigner.as_deref(), Some("Manager"));
+
+        let ical = request.to_ical().unwrap();
+        for expected in [
+            "PRODID:-//ms-oxmsg//EN",
+            "METHOD:REQUEST",
+            "BEGIN:VTODO",
+            "ORGANIZER;CN=Manager:mailto:manager@example.com",
+            "ATTENDEE;PARTSTAT=NEEDS-ACTION;CN=Worker:mailto:worker@example.com",
+            "SUMMARY:Write report",
+            "DESCRIPTION:Quarterly numbers",
+            "DUE;VALUE=DATE:20230512",
+            "STATUS:IN-PROCESS",
+            "PERCENT-COMPLETE:25",
+        ]
+        .iter()
+        {
+            assert!(
+                ical.split("\r\n").any(|line| line == *expected),
+                "missing {:?} in {}",
+                expected,
+                ical
+            );
+        }
+    }
+
+    #[test]
+    fn task_request_without_assignee() {
+        let mut builder = request_builder();
+        let task = "/__attach_version1.0_#00000000/__substg1.0_3701000D/";
+        builder.binary(task, 0x300B, &[0xAB, 0xCD]);
+        let msg = EmailMessage::from_bytes(&builder.build()).unwrap();
+        let request = TaskRequest::from_message(&msg);
+        assert_eq!(
+            request.assigner.as_ref().unwrap().address,
+            "manager@example.com"
+        );
+        assert!(request.assignee.is_none());
+
+        let ical = request.to_ical().unwrap();
+        let lines: Vec<&str> = ical.split("\r\n").collect();
+        assert!(lines.contains(&"METHOD:PUBLISH"), "{}", ical);
+        assert!(lines.contains(&"UID:ABCD"), "{}", ical);
+        assert!(!lines.iter().any(|line| line.starts_with("ORGANIZER")));
+    }
+
+    #[test]
+    fn task_uid_fallback() {
+        let mut builder = MsgBuilder::new();
+        builder.message("/", "Water plants", "", "");
+        builder.string("/", 0x001A, "IPM.Task");
+        let created = Utc.with_ymd_and_hms(2023, 5, 1, 8, 0, 0).unwrap();
+        builder.properties("/", &[(0x0040, 0x3007, filetime(created))]);
+        let msg = EmailMessage::from_bytes(&builder.build()).unwrap();
+        let task = Task::from_message(&msg);
+        assert!(task.uid.is_none());
+        assert_eq!(task.last_modified, Some(created));
+
+        // The UID is made up, but the same each time.
+        let uid = |ical: &str| {
+            ical.split("\r\n")
+                .find(|line| line.starts_with("UID:"))
+                .map(str::to_string)
+        };
+        let first = uid(&task.to_ical());
+        assert!(first.is_some());
+        assert_eq!(first, uid(&task.to_ical()));
+
+        // Without any times, it comes from the subject alone rather than the
+        // time the task is written out.
+        let untimed = Task {
+            last_modified: None,
+            ..task
+        };
+        assert_eq!(
+            uid(&untimed.to_ical()),
+            Some(format!("UID:{}", ical::fallback_uid("Water plants", None)))
+        );
+    }
+}
@@ -0,0 +1,154 @@
+//! Builds small .msg files in memory for tests.
+
+use std::io::{Cursor, Read, Write};
+
+use chrono::{DateTime, TimeZone, Utc};
+
+use crate::{named::PropertyName, PropertySet};
+
+pub(crate) struct MsgBuilder {
+    comp: cfb::CompoundFile<Cursor<Vec<u8>>>,
+}
+
+impl MsgBuilder {
+    /// A message with the properties that `EmailMessage` requires.
+    pub(crate) fn new() -> Self {
+        let comp = cfb::CompoundFile::create(Cursor::new(Vec::new())).unwrap();
+        let mut builder = Self { comp };
+        builder.message("/", "Subject", "sender@example.com", "Body");
+        builder
+    }
+
+    /// Add the basic properties of a message to the storage at `path`.
+    pub(crate) fn message(&mut self, path: &str, subject: &str, sender: &str, body: &str) {
+        self.string(path, 0x0037, subject);
+        self.string(path, 0x0C1F, sender);
+        self.string(path, 0x1000, body);
+        self.properties(path, &[]);
+    }
+
+    pub(crate) fn storage(&mut self, path: &str) {
+        self.comp.create_storage(path).unwrap();
+    }
+
+    pub(crate) fn stream(&mut self, path: &str, data: &[u8]) {
+        let mut stream = self.comp.create_stream(path).unwrap();
+        stream.write_all(data).unwrap();
+    }
+
+    pub(crate) fn string(&mut self, storage: &str, id: u16, value: &str) {
+        let data: Vec<u8> = value.encode_utf16().flat_map(u16::to_le_bytes).collect();
+        self.stream(&format!("{storage}__substg1.0_{id:04X}001F"), &data);
+    }
+
+    pub(crate) fn binary(&mut self, storage: &str, id: u16, value: &[u8]) {
+        self.stream(&format!("{storage}__substg1.0_{id:04X}0102"), value);
+    }
+
+    /// Write the property stream for a storage. The header is sized according
+    /// to whether the storage is the root, an embedded message or otherwise.
+    pub(crate) fn properties(&mut self, storage: &str, entries: &[(u16, u16, [u8; 8])]) {
+        let header_len = if storage == "/" {
+            32
+        } else if storage.ends_with("__substg1.0_3701000D/") {
+            24
+        } else {
+            8
+        };
+        let mut data = vec![0; header_len];
+        for (p_type, id, value) in entries {
+            data.extend(p_type.to_le_bytes());
+            data.extend(id.to_le_bytes());
+            data.extend(6_u32.to_le_bytes());
+            data.extend(value);
+        }
+        self.stream(&format!("{storage}__properties_version1.0"), &data);
+    }
+
+    /// Set the recipient and attachment counts in the header of a message's
+    /// property stream, which must already have been written.
+    pub(crate) fn counts(&mut self, storage: &str, recipients: u32, attachments: u32) {
+        let path = format!("{storage}__properties_version1.0");
+        let mut data = Vec::new();
+        self.comp
+            .open_stream(&path)
+            .unwrap()
+            .read_to_end(&mut data)
+            .unwrap();
+        data[16..20].copy_from_slice(&recipients.to_le_bytes());
+        data[20..24].copy_from_slice(&attachments.to_le_bytes());
+        self.stream(&path, &data);
+    }
+
+    /// Write the named property mapping, giving the properties IDs from
+    /// 0x8000 in order.
+    pub(crate) fn named(&mut self, names: &[(PropertySet, PropertyName)]) {
+        let mut guids = Vec::new();
+        let mut entries = Vec::new();
+        let mut strings = Vec::new();
+        for (index, (property_set, name)) in names.iter().enumerate() {
+            let guid_index = match property_set {
+                PropertySet::PsMapi => 1,
+                PropertySet::PublicStrings => 2,
+                property_set => {
+                    guids.extend(property_set.to_uuid().to_bytes_le());
+                    guids.len() / 16 + 2
+                }
+            };
+            let (name_or_offset, kind) = match name {
+                PropertyName::Id(id) => (*id, 0),
+                PropertyName::String(name) => {
+                    let offset = strings.len() as u32;
+                    let data: Vec<u8> = name.encode_utf16().flat_map(u16::to_le_bytes).collect();
+                    strings.extend((data.len() as u32).to_le_bytes());
+                    strings.extend(&data);
+                    strings.resize(strings.len().div_ceil(4) * 4, 0);
+                    (offset, 1)
+                }
+            };
+            entries.extend(name_or_offset.to_le_bytes());
+            entries.extend(((index as u32) << 16 | (guid_index as u32) << 1 | kind).to_le_bytes());
+        }
+        self.storage("/__nameid_version1.0");
+        self.stream("/__nameid_version1.0/__substg1.0_00020102", &guids);
+        self.stream("/__nameid_version1.0/__substg1.0_00030102", &entries);
+        self.stream("/__nameid_version1.0/__substg1.0_00040102", &strings);
+    }
+
+    pub(crate) fn build(mut self) -> Vec<u8> {
+        self.comp.flush().unwrap();
+        self.comp.into_inner().into_inner()
+    }
+}
+
+pub(crate) fn filetime(time: DateTime<Utc>) -> [u8; 8] {
+    let origin = Utc.with_ymd_and_hms(1601, 1, 1, 0, 0, 0).unwrap();
+    ((time - origin).num_microseconds().unwrap() * 10).to_le_bytes()
+}
+
+pub(crate) fn boolean(value: bool) -> [u8; 8] {
+    integer32(value as i32)
+}
+
+pub(crate) fn integer32(value: i32) -> [u8; 8] {
+    let mut data = [0; 8];
+    data[..4].copy_from_slice(&value.to_le_bytes());
+    data
+}
+
+/// A PidLidGlobalObjectId for an occurrence on `date`, created on 20 April
+/// 2023.
+pub(crate) fn global_object_id(date: (u16, u8, u8), data: &[u8]) -> Vec<u8> {
+    let created = Utc.with_ymd_and_hms(2023, 4, 20, 8, 0, 0).unwrap();
+    let mut id = vec![
+        0x04, 0x00, 0x00, 0x00, 0x82, 0x00, 0xE0, 0x00, 0x74, 0xC5, 0xB7, 0x10, 0x1A, 0x82, 0xE0,
+        0x08,
+    ];
+    id.extend(date.0.to_be_bytes());
+    id.extend([date.1, date.2]);
+    id.extend(filetime(created));
+    id.extend([0; 8]);
+    id.extend((data.len() as u32).to_le_bytes());
+    id.extend(data);
+    id
+}
@@ -0,0 +1,376 @@
+//! Time zones of calendar items, from the binary PidLidTimeZoneStruct
+//! (MS-OXOCAL 2.2.1.39) and the TZDEFINITION structures in
+//! PidLidAppointmentTimeZoneDefinitionStartDisplay, EndDisplay and Recur
+//! (MS-OXOCAL 2.2.1.41).
+
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::binary::ByteReader;
+
+const TZDEFINITION_FLAG_VALID_GUID: u16 = 0x0001;
+const TZDEFINITION_FLAG_VALID_KEYNAME: u16 = 0x0002;
+const TZRULE_FLAG_EFFECTIVE_TZREG: u16 = 0x0002;
+
+/// When a time zone switches between standard and daylight time, as stored
+/// in a SYSTEMTIME.
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct TransitionDate {
+    /// Zero for a rule repeating every year, otherwise the only year the
+    /// transition happens.
+    pub year: u16,
+    /// Zero if the time zone does not observe daylight time.
+    pub month: u16,
+    /// The day of the week, from 0 for Sunday.
+    pub day_of_week: u16,
+    /// For yearly rules, which `day_of_week` of the month, from 1 to 5 where
+    /// 5 is the last. Otherwise the day of the month.
+    pub day: u16,
+    pub hour: u16,
+    pub minute: u16,
+    pub second: u16,
+}
+
+impl TransitionDate {
+    fn read(reader: &mut ByteReader) -> Result<Self, &'static str> {
+        let year = reader.u16()?;
+        let month = reader.u16()?;
+        let day_of_week = reader.u16()?;
+        let day = reader.u16()?;
+        let hour = reader.u16()?;
+        let minute = reader.u16()?;
+        let second = reader.u16()?;
+        reader.u16()?;
+        Ok(Self {
+            year,
+            month,
+            day_of_week,
+            day,
+            hour,
+            minute,
+            second,
+        })
+    }
+
+    /// The local time of the transition in `year`, if there is one.
+    pub fn in_year(&self, year: i32) -> Option<NaiveDateTime> {
+        if self.month == 0 {
+            return None;
+        }
+        let date = if self.year == 0 {
+            let first = NaiveDate::from_ymd_opt(year, self.month as u32, 1)?;
+            let offset = (self.day_of_week as i64 - first.weekday().num_days_from_sunday() as i64)
+                .rem_euclid(7);
+            let mut date = first + Duration::days(offset + 7 * (self.day.max(1) as i64 - 1));
+            while date.month() != first.month() {
+                date -= Duration::days(7);
+            }
+            date
+        } else if self.year as i32 == year {
+            NaiveDate::from_ymd_opt(year, self.month as u32, self.day as u32)?
+        } else {
+            return None;
+        };
+        date.and_hms_opt(self.hour as u32, self.minute as u32, self.second as u32)
+    }
+}
+
+/// The offsets of a time zone from UTC and when it switches between
+/// standard and daylight time.
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct TimeZoneRule {
+    /// The first year the rule applies to.
+    pub start_year: u16,
+    /// Whether this is the rule for the time zone as it is now
+    /// (TZRULE_FLAG_EFFECTIVE_TZREG).
+    pub effective: bool,
+    /// Minutes to add to local time to get UTC.
+    pub bias: i32,
+    /// Minutes added to `bias` during standard time.
+    pub standard_bias: i32,
+    /// Minutes added to `bias` during daylight time.
+    pub daylight_bias: i32,
+    /// When daylight time ends.
+    pub standard_date: TransitionDate,
+    /// When daylight time starts.
+    pub daylight_date: TransitionDate,
+}
+
+impl TimeZoneRule {
+    /// Read the TZREG part of a rule, which has the biases and transitions.
+    fn read_tzreg(
+        reader: &mut ByteReader,
+        start_year: u16,
+        effective: bool,
+    ) -> Result<Self, &'static str> {
+        let bias = reader.i32()?;
+        let standard_bias = reader.i32()?;
+        let daylight_bias = reader.i32()?;
+        let standard_date = TransitionDate::read(reader)?;
+        let daylight_date = TransitionDate::read(reader)?;
+        Ok(Self {
+            start_year,
+            effective,
+            bias,
+            standard_bias,
+            daylight_bias,
+            standard_date,
+            daylight_date,
+        })
+    }
+
+    pub fn observes_daylight_time(&self) -> bool {
+        self.standard_date.month != 0 && self.daylight_date.month != 0
+    }
+
+    /// When daylight time starts and ends in `year`, in UTC.
+    fn daylight_period(&self, year: i32) -> Option<(NaiveDateTime, NaiveDateTime)> {
+        if !self.observes_daylight_time() {
+            return None;
+        }
+        // Daylight time starts at a standard time and ends at a daylight time.
+        let start = self.daylight_date.in_year(year)?
+            + Duration::minutes((self.bias + self.standard_bias) as i64);
+        let end = self.standard_date.in_year(year)?
+            + Duration::minutes((self.bias + self.daylight_bias) as i64);
+        Some((start, end))
+    }
+
+    /// The offset from UTC to local time at a time given in UTC.
+    pub fn offset_at(&self, time: NaiveDateTime) -> Duration {
+        let daylight = self
+            .daylight_period(time.year())
+            .map(|(start, end)| {
+                if start < end {
+                    start <= time && time < end
+                } else {
+                    // Southern hemisphere time zones are in daylight time
+                    // across the new year.
+                    time < end || start <= time
+                }
+            })
+            .unwrap_or(false);
+        let bias = if daylight {
+            self.daylight_bias
+        } else {
+            self.standard_bias
+        };
+        -Duration::minutes((self.bias + bias) as i64)
+    }
+}
+
+/// A time zone and how its rules have changed over the years.
+#[derive(Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq)]
+pub struct TimeZoneDefinition {
+    pub guid: Option<Uuid>,
+    /// The name of the time zone in the Windows registry, such as
+    /// "Pacific Standard Time".
+    pub key_name: Option<String>,
+    /// The rules in order of the year they start.
+    pub rules: Vec<TimeZoneRule>,
+}
+
+impl TimeZoneDefinition {
+    /// Decode a TZDEFINITION structure.
+    pub fn parse(data: &[u8]) -> Result<Self, &'static str> {
+        let mut reader = ByteReader::new(data);
+        if reader.u8()? != 0x02 {
+            return Err("unsupported time zone definition version");
+        }
+        reader.u8()?;
+        let header_size = reader.u16()? as usize;
+        let mut header = ByteReader::new(reader.bytes(header_size)?);
+        let flags = header.u16()?;
+        let guid = if flags & TZDEFINITION_FLAG_VALID_GUID != 0 {
+            Some(header.guid()?)
+        } else {
+            None
+        };
+        let key_name = if flags & TZDEFINITION_FLAG_VALID_KEYNAME != 0 {
+            let length = header.u16()? as usize;
+            Some(crate::read(header.bytes(length * 2)?)?)
+        } else {
+            None
+        };
+        let rule_count = header.u16()?;
+        let mut rules = Vec::with_capacity(rule_count as usize);
+        for _ in 0..rule_count {
+            reader.u8()?;
+            reader.u8()?;
+            let rule_size = reader.u16()? as usize;
+            let mut rule = ByteReader::new(reader.bytes(rule_size)?);
+            let flags = rule.u16()?;
+            let start = TransitionDate::read(&mut rule)?;
+            rules.push(TimeZoneRule::read_tzreg(
+                &mut rule,
+                start.year,
+                flags & TZRULE_FLAG_EFFECTIVE_TZREG != 0,
+            )?);
+        }
+        rules.sort_by_key(|rule| rule.start_year);
+        Ok(Self {
+            guid,
+            key_name,
+            rules,
+        })
+    }
+
+    /// Decode the older PidLidTimeZoneStruct, which has a single rule.
+    pub fn parse_time_zone_struct(data: &[u8]) -> Result<Self, &'static str> {
+        let mut reader = ByteReader::new(data);
+        let bias = reader.i32()?;
+        let standard_bias = reader.i32()?;
+        let daylight_bias = reader.i32()?;
+        let start_year = reader.u16()?;
+        let standard_date = TransitionDate::read(&mut reader)?;
+        reader.u16()?;
+        let daylight_date = TransitionDate::read(&mut reader)?;
+        Ok(Self {
+            guid: None,
+            key_name: None,
+            rules: vec![TimeZoneRule {
+                start_year,
+                effective: true,
+                bias,
+                standard_bias,
+                daylight_bias,
+                standard_date,
+                daylight_date,
+            }],
+        })
+    }
+
+    /// The rule in force during `year`.
+    pub fn rule_for(&self, year: i32) -> Option<&TimeZoneRule> {
+        self.rules
+            .iter()
+            .rev()
+            .find(|rule| rule.start_year as i32 <= year)
+            .or_else(|| self.rules.first())
+    }
+
+    /// The offset from UTC to local time at `time`.
+    pub fn offset_at(&self, time: DateTime<Utc>) -> Duration {
+        self.rule_for(time.year())
+            .map(|rule| rule.offset_at(time.naive_utc()))
+            .unwrap_or_else(Duration::zero)
+    }
+
+    /// Convert a time to the local time of this time zone.
+    pub fn to_local(&self, time: DateTime<Utc>) -> NaiveDateTime {
+        time.naive_utc() + self.offset_at(time)
+    }
+
+    /// Convert a local time in this time zone to UTC. Times repeated when
+    /// the clocks go back are taken as daylight time.
+    pub fn to_utc(&self, local: NaiveDateTime) -> DateTime<Utc> {
+        let rule = match self.rule_for(local.year()) {
+            Some(rule) => rule,
+            None => return Utc.from_utc_datetime(&local),
+        };
+        let daylight_offset = Duration::minutes((rule.bias + rule.daylight_bias) as i64);
+        let daylight = local + daylight_offset;
+        if rule.observes_daylight_time() && rule.offset_at(daylight) == -daylight_offset {
+            return Utc.from_utc_datetime(&daylight);
+        }
+        Utc.from_utc_datetime(&(local + Duration::minutes((rule.bias + rule.standard_bias) as i64)))
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    fn system_time(month: u16, day_of_week: u16, day: u16, hour: u16) -> Vec<u8> {
+        [0, month, day_of_week, day, hour, 0, 0, 0]
+            .iter()
+            .flat_map(|n: &u16| n.to_le_bytes())
+            .collect()
+    }
+
+    /// Pacific time: UTC-8, with daylight time from the second Sunday in
+    /// March to the first Sunday in November since 2007.
+    pub(crate) fn pacific() -> Vec<u8> {
+        let key_name = "Pacific Standard Time";
+        let mut header = Vec::new();
+        header.extend(TZDEFINITION_FLAG_VALID_KEYNAME.to_le_bytes());
+        header.extend((key_name.len() as u16).to_le_bytes());
+        header.extend(key_name.encode_utf16().flat_map(u16::to_le_bytes));
+        header.extend(2_u16.to_le_bytes());
+        let mut data = vec![0x02, 0x01];
+        data.extend((header.len() as u16).to_le_bytes());
+        data.extend(header);
+        for (year, standard, daylight) in [(1601, (10, 5), (4, 1)), (2007, (11, 1), (3, 2))].iter()
+        {
+            data.extend([0x02, 0x01]);
+            data.extend(0x003E_u16.to_le_bytes());
+            let flags = if *year == 2007 {
+                TZRULE_FLAG_EFFECTIVE_TZREG
+            } else {
+                0
+            };
+            data.extend(flags.to_le_bytes());
+            data.extend((*year as u16).to_le_bytes());
+            data.extend([1, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+            data.extend(480_i32.to_le_bytes());
+            data.extend(0_i32.to_le_bytes());
+            data.extend((-60_i32).to_le_bytes());
+            data.extend(system_time(standard.0, 0, standard.1, 2));
+            data.extend(system_time(daylight.0, 0, daylight.1, 2));
+        }
+        data
+    }
+
+    fn local(year: i32, month: u32, day: u32, hour: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(year, month, day)
+            .unwrap()
+            .and_hms_opt(hour, 0, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn definition() {
+        let zone = TimeZoneDefinition::parse(&pacific()).unwrap();
+        assert_eq!(zone.key_name.as_deref(), Some("Pacific Standard Time"));
+        assert_eq!(zone.rules.len(), 2);
+        assert!(zone.rules[1].effective);
+
+        let utc =
+            |year, month, day, hour| Utc.with_ymd_and_hms(year, month, day, hour, 0, 0).unwrap();
+        // Daylight time started on 12 March 2023 and 2 April 2006.
+        assert_eq!(zone.to_local(utc(2023, 3, 12, 9)), local(2023, 3, 12, 1));
+        assert_eq!(zone.to_local(utc(2023, 3, 12, 10)), local(2023, 3, 12, 3));
+        assert_eq!(zone.to_local(utc(2006, 3, 12, 10)), local(2006, 3, 12, 2));
+        assert_eq!(zone.to_local(utc(2023, 7, 1, 19)), local(2023, 7, 1, 12));
+        assert_eq!(zone.to_local(utc(2023, 12, 1, 20)), local(2023, 12, 1, 12));
+
+        assert_eq!(zone.to_utc(local(2023, 7, 1, 12)), utc(2023, 7, 1, 19));
+        assert_eq!(zone.to_utc(local(2023, 12, 1, 12)), utc(2023, 12, 1, 20));
+    }
+
+    #[test]
+    fn time_zone_struct() {
+        let mut data = Vec::new();
+        data.extend((-60_i32).to_le_bytes());
+        data.extend(0_i32.to_le_bytes());
+        data.extend((-60_i32).to_le_bytes());
+        data.extend(0_u16.to_le_bytes());
+        data.extend(system_time(10, 0, 5, 3));
+        data.extend(0_u16.to_le_bytes());
+        data.extend(system_time(3, 0, 5, 2));
+        let zone = TimeZoneDefinition::parse_time_zone_struct(&data).unwrap();
+        // Central European time: the last Sundays of March and October.
+        let rule = zone.rule_for(2023).unwrap();
+        assert_eq!(
+            rule.daylight_date.in_year(2023),
+            Some(local(2023, 3, 26, 2))
+        );
+        assert_eq!(
+            rule.standard_date.in_year(2023),
+            Some(local(2023, 10, 29, 3))
+        );
+        let summer = Utc.with_ymd_and_hms(2023, 6, 1, 12, 0, 0).unwrap();
+        assert_eq!(zone.offset_at(summer), Duration::hours(2));
+    }
+}
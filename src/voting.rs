@@ -0,0 +1,270 @@
+//! Voting buttons, from PidLidVerbStream and PidLidVerbResponse
+//! (MS-OXOMSG 2.2.1.73 and 2.2.1.74).
+
+use serde::{Deserialize, Serialize};
+
+use crate::{binary::ByteReader, EmailMessage, Lid};
+
+/// The verb type of voting options, as opposed to the reply and forward
+/// verbs that can also be in the stream.
+const VERB_TYPE_VOTING: u32 = 4;
+
+/// What happens when the recipient chooses an option (the SendBehavior
+/// field).
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum SendBehavior {
+    /// Ask whether to send the response straight away or edit it first.
+    Prompt,
+    SendImmediately,
+    /// Open the response for editing.
+    Edit,
+    Other(u32),
+}
+
+impl SendBehavior {
+    pub fn from_u32(n: u32) -> Self {
+        match n {
+            0x0000_0000 => Self::Prompt,
+            0x0000_0001 => Self::SendImmediately,
+            0x0000_0002 => Self::Edit,
+            n => Self::Other(n),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct VoteOption {
+    /// The number the option is sent back as in responses.
+    pub id: u32,
+    pub display_name: String,
+    /// 4 for voting options, and 1 or 2 for Outlook's reply and forward
+    /// verbs.
+    pub verb_type: u32,
+    /// The message class of the response, usually `IPM.Note`.
+    pub message_class: String,
+    /// Whether the response should include the original message's headers
+    /// in US format.
+    pub use_us_headers: bool,
+    pub send_behavior: SendBehavior,
+}
+
+impl VoteOption {
+    pub fn is_voting(&self) -> bool {
+        self.verb_type == VERB_TYPE_VOTING
+    }
+}
+
+/// Read a string preceded by its length in bytes, in the code page.
+fn counted_string(reader: &mut ByteReader) -> Result<String, &'static str> {
+    let len = reader.u8()? as usize;
+    Ok(reader.bytes(len)?.iter().map(|&c| c as char).collect())
+}
+
+/// Read a string preceded by its length in UTF-16 code units.
+fn counted_wide_string(reader: &mut ByteReader) -> Result<String, &'static str> {
+    let len = reader.u8()? as usize;
+    crate::read(reader.bytes(len * 2)?)
+}
+
+/// Read the options in PidLidVerbStream, including the reply and forward
+/// verbs. The display names are taken from the Unicode copies at the end of
+/// the stream where present.
+pub fn parse_verb_stream(data: &[u8]) -> Result<Vec<VoteOption>, &'static str> {
+    let mut reader = ByteReader::new(data);
+    reader.u16()?;
+    let count = reader.u32()?;
+    let mut options = Vec::new();
+    for _ in 0..count {
+        let verb_type = reader.u32()?;
+        let display_name = counted_string(&mut reader)?;
+        let message_class = counted_string(&mut reader)?;
+        counted_string(&mut reader)?;
+        counted_string(&mut reader)?;
+        reader.u32()?;
+        reader.u8()?;
+        let use_us_headers = reader.u32()? != 0;
+        reader.u32()?;
+        let send_behavior = SendBehavior::from_u32(reader.u32()?);
+        reader.u32()?;
+        let id = reader.u32()?;
+        reader.u32()?;
+        options.push(VoteOption {
+            id,
+            display_name,
+            verb_type,
+            message_class,
+            use_us_headers,
+            send_behavior,
+        });
+    }
+    // Older versions of Outlook don't write the Unicode names.
+    if reader.u16().is_ok() {
+        for option in options.iter_mut() {
+            match counted_wide_string(&mut reader) {
+                Ok(name) => option.display_name = name,
+                Err(_) => break,
+            }
+            if counted_wide_string(&mut reader).is_err() {
+                break;
+            }
+        }
+    }
+    Ok(options)
+}
+
+impl EmailMessage {
+    /// The voting buttons on the message.
+    pub fn voting_options(&self) -> Vec<VoteOption> {
+        self.properties
+            .binary(Lid::VerbStream)
+            .and_then(|data| parse_verb_stream(data).ok())
+            .unwrap_or_default()
+            .into_iter()
+            .filter(VoteOption::is_voting)
+            .collect()
+    }
+
+    /// The option chosen in a response to a message with voting buttons.
+    pub fn verb_response(&self) -> Option<&str> {
+        self.properties
+            .string(Lid::VerbResponse)
+            .filter(|response| !response.is_empty())
+    }
+}
+
+/// Count the responses to a message with voting buttons. Every option is
+/// included, in order, followed by any other responses in the order they
+/// were first seen.
+pub fn tally_votes<'a>(
+    options: &[VoteOption],
+    replies: impl IntoIterator<Item = &'a EmailMessage>,
+) -> Vec<(String, usize)> {
+    let mut tally: Vec<(String, usize)> = options
+        .iter()
+        .map(|option| (option.display_name.clone(), 0))
+        .collect();
+    for response in replies.into_iter().filter_map(EmailMessage::verb_response) {
+        match tally.iter_mut().find(|(name, _)| name == response) {
+            Some((_, count)) => *count += 1,
+            None => tally.push((response.to_string(), 1)),
+        }
+    }
+    tally
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{named::PropertyName, test_support::MsgBuilder, PropertySet};
+
+    fn verb_stream(options: &[(u32, &str, u32)]) -> Vec<u8> {
+        let mut data = 0x0102_u16.to_le_bytes().to_vec();
+        data.extend((options.len() as u32).to_le_bytes());
+        for (verb_type, name, id) in options.iter() {
+            data.extend(verb_type.to_le_bytes());
+            for value in [*name, "IPM.Note", "", *name].iter() {
+                data.push(value.len() as u8);
+                data.extend(value.bytes());
+            }
+            data.extend(0_u32.to_le_bytes());
+            data.push(0);
+            data.extend(1_u32.to_le_bytes());
+            data.extend(1_u32.to_le_bytes());
+            data.extend(1_u32.to_le_bytes());
+            data.extend(2_u32.to_le_bytes());
+            data.extend(id.to_le_bytes());
+            data.extend((-1_i32).to_le_bytes());
+        }
+        data.extend(0x0104_u16.to_le_bytes());
+        for (_, name, _) in options.iter() {
+            for _ in 0..2 {
+                data.push(name.encode_utf16().count() as u8);
+                data.extend(name.encode_utf16().flat_map(u16::to_le_bytes));
+            }
+        }
+        data
+    }
+
+    fn message(stream: Option<&[u8]>, response: Option<&str>) -> EmailMessage {
+        let mut builder = MsgBuilder::new();
+        builder.named(&[
+            (PropertySet::Common, PropertyName::Id(0x8520)),
+            (PropertySet::Common, PropertyName::Id(0x8524)),
+        ]);
+        if let Some(stream) = stream {
+            builder.binary("/", 0x8000, stream);
+        }
+        if let Some(response) = response {
+            builder.string("/", 0x8001, response);
+        }
+        EmailMessage::from_bytes(&builder.build()).unwrap()
+    }
+
+    #[test]
+    fn voting() {
+        let stream = verb_stream(&[
+            (1, "Reply", 102),
+            (4, "Approve", 1),
+            (4, "Reject", 2),
+            (4, "Ça dépend", 3),
+        ]);
+        let request = message(Some(&stream), None);
+        let options = request.voting_options();
+        let names: Vec<&str> = options
+            .iter()
+            .map(|option| option.display_name.as_str())
+            .collect();
+        assert_eq!(names, ["Approve", "Reject", "Ça dépend"]);
+        assert_eq!(options[1].id, 2);
+        assert_eq!(options[0].send_behavior, SendBehavior::SendImmediately);
+
+        let replies = [
+            message(None, Some("Approve")),
+            message(None, Some("Approve")),
+            message(None, Some("Maybe")),
+            message(None, None),
+        ];
+        assert_eq!(replies[0].verb_response(), Some("Approve"));
+        assert_eq!(
+            tally_votes(&options, &replies),
+            [
+                ("Approve".to_string(), 2),
+                ("Reject".to_string(), 0),
+                ("Ça dépend".to_string(), 0),
+                ("Maybe".to_string(), 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn malformed_verb_stream() {
+        let stream = verb_stream(&[(4, "Approve", 1), (4, "Reject", 2)]);
+        // Without the Unicode names, the names in the code page are used.
+        let names_len: usize = ["Approve", "Reject"]
+            .iter()
+            .map(|name| 2 * (1 + 2 * name.len()))
+            .sum();
+        let end = stream.len() - 2 - names_len;
+        let options = parse_verb_stream(&stream[..end]).unwrap();
+        assert_eq!(options[1].display_name, "Reject");
+
+        // The options are kept when the Unicode names are cut short.
+        let options = parse_verb_stream(&stream[..stream.len() - 1]).unwrap();
+        let names: Vec<&str> = options
+            .iter()
+            .map(|option| option.display_name.as_str())
+            .collect();
+        assert_eq!(names, ["Approve", "Reject"]);
+
+        for data in [
+            &stream[..1],
+            &stream[..40],
+            &[0x02, 0x01, 0xFF, 0xFF, 0xFF, 0xFF][..],
+        ] {
+            assert!(parse_verb_stream(data).is_err());
+        }
+        assert!(message(Some(&stream[..40]), None)
+            .voting_options()
+            .is_empty());
+    }
+}